@@ -1,15 +1,26 @@
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::atomic::AtomicUsize;
+
 use serde::Deserialize;
 use serde::Serialize;
 
+use super::external_sort::Sorter;
+use super::mapped::Mapped;
+use super::space::OutOfBounds;
 use super::space::Position;
 use super::space::Shape;
 use super::space::Space;
+use super::space_db::nearest;
 use super::space_db::SpaceDB;
+use super::space_db::SpaceDBV1;
 use super::space_index::SpaceSetObject;
 use super::DataBase;
 use super::IterObjects;
 use super::IterPositions;
 use super::ResultSet;
+use crate::Error;
 
 /// Query Parameters.
 pub struct CoreQueryParameters<'a> {
@@ -25,9 +36,19 @@ pub struct CoreQueryParameters<'a> {
     pub view_port: &'a Option<(Vec<f64>, Vec<f64>)>,
     /// Index resolution to use.
     pub resolution: &'a Option<Vec<u32>>,
+    /// Handling of the positions falling outside of the reference space
+    /// they are converted into, whether a searched space or the output
+    /// space. Search volumes are always clipped to the reference spaces.
+    ///
+    /// The positions are converted before the results are returned, in
+    /// order to report the errors of the positions which are not
+    /// dropped.
+    pub out_of_bounds: OutOfBounds,
+    /// Counter of the positions skipped with `OutOfBounds::Drop`.
+    pub dropped: Option<&'a AtomicUsize>,
 }
 
-impl CoreQueryParameters<'_> {
+impl<'a> CoreQueryParameters<'a> {
     /// Build a minimum bounding box out of the provided viewport, and
     /// rebase it in the target space.
     ///
@@ -39,16 +60,50 @@ impl CoreQueryParameters<'_> {
     pub fn view_port(&self, space: &Space) -> Option<Shape> {
         if let Some((low, high)) = self.view_port {
             let view_port = Shape::BoundingBox(low.into(), high.into());
-            match view_port.rebase(Space::universe(), space) {
+            match self.db.conversion(Space::universe(), space) {
                 Err(_) => None,
-                Ok(view) => Some(view),
+                Ok(conversion) => match view_port.convert(&conversion) {
+                    Err(_) => None,
+                    Ok(view) => Some(view),
+                },
             }
         } else {
             None
         }
     }
+
+    // Apply `f` to each element of `list`, skipping the elements on which
+    // it fails with an `OutOfRange` error under `OutOfBounds::Drop`. Any
+    // other error is reported as is, including the ones of positions
+    // which could not be clipped.
+    fn bounded<'b, I, U, F>(&self, list: I, f: F) -> Result<Box<dyn Iterator<Item = U> + 'b>, Error>
+    where
+        I: Iterator,
+        U: 'b,
+        F: Fn(I::Item) -> Result<U, Error>,
+    {
+        // Errors can only be reported before returning the results.
+        let mut converted = vec![];
+        for v in list {
+            match f(v) {
+                Ok(u) => converted.push(u),
+                Err(Error::OutOfRange { .. }) if self.out_of_bounds == OutOfBounds::Drop => {
+                    if let Some(dropped) = self.dropped {
+                        dropped.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                    }
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(Box::new(converted.into_iter()))
+    }
 }
 
+/// Arbitrary metadata attached to an *identifier*, such as a colour or
+/// an annotation, by name.
+pub type Attributes = BTreeMap<String, String>;
+
 /// Definition of the volumetric objects identifiers.
 ///
 /// We have two parts to it, first the *kind* and the actual, *id* used
@@ -60,6 +115,9 @@ pub enum Properties {
     Feature(String),
     /// Unoptimized arbitrary kind of *identifiers*.
     Unknown(String, String),
+    /// *Identifiers* with attributes, see
+    /// [with_attributes](#method.with_attributes).
+    Annotated(Box<Properties>, Attributes),
 }
 
 impl Properties {
@@ -68,6 +126,7 @@ impl Properties {
         match self {
             Properties::Feature(id) => id,
             Properties::Unknown(id, _) => id,
+            Properties::Annotated(properties, _) => properties.id(),
         }
     }
 
@@ -76,6 +135,36 @@ impl Properties {
         match self {
             Properties::Feature(_) => "Feature",
             Properties::Unknown(_, type_name) => type_name,
+            Properties::Annotated(properties, _) => properties.type_name(),
+        }
+    }
+
+    /// Extract the attributes of this spatial object, if any.
+    pub fn attributes(&self) -> Option<&Attributes> {
+        match self {
+            Properties::Annotated(_, attributes) => Some(attributes),
+            _ => None,
+        }
+    }
+
+    /// Attach attributes to the *identifier*, replacing any previous
+    /// ones.
+    ///
+    /// # Parameters
+    ///
+    ///  * `attributes`:
+    ///      The attributes to attach, nothing is attached when it is
+    ///      empty.
+    pub fn with_attributes(self, attributes: Attributes) -> Properties {
+        let properties = match self {
+            Properties::Annotated(properties, _) => *properties,
+            properties => properties,
+        };
+
+        if attributes.is_empty() {
+            properties
+        } else {
+            Properties::Annotated(Box::new(properties), attributes)
         }
     }
 
@@ -117,10 +206,31 @@ impl Properties {
 pub struct Core {
     title: String,
     version: String,
-    properties: Vec<Properties>,
+    // Decoded on first use, when loaded from an index file.
+    properties: Mapped<Vec<Properties>>,
     space_db: Vec<SpaceDB>,
 }
 
+// Layout of `Core` in index files of format 1 and older.
+#[derive(Deserialize)]
+pub struct CoreV1 {
+    title: String,
+    version: String,
+    properties: Vec<Properties>,
+    space_db: Vec<SpaceDBV1>,
+}
+
+impl From<CoreV1> for Core {
+    fn from(v1: CoreV1) -> Self {
+        Core {
+            title: v1.title,
+            version: v1.version,
+            properties: Mapped::new(v1.properties),
+            space_db: v1.space_db.into_iter().map(|s| s.into()).collect(),
+        }
+    }
+}
+
 impl Core {
     /// Instantiate a new index for a dataset.
     ///
@@ -146,7 +256,7 @@ impl Core {
     ///  * `scales`:
     ///     A list of resolutions for which to build indices. Each value
     ///     represent the number of bits of precision to **remove** from
-    ///     the coordinates to build the index.
+    ///     the coordinates to build the index, for each axis.
     ///
     ///  * `max_elements`:
     ///     The minimum number of positions to use as a stopping
@@ -167,7 +277,7 @@ impl Core {
         space_objects: Vec<SpaceSetObject>,
         scales: Option<Vec<Vec<u32>>>,
         max_elements: Option<usize>,
-    ) -> Result<Self, String>
+    ) -> Result<Self, Error>
     where
         S: Into<String>,
     {
@@ -192,17 +302,377 @@ impl Core {
                 object.set_position(space.encode(&position)?);
             }
 
-            space_dbs.push(SpaceDB::new(space, filtered, scales.clone(), max_elements))
+            space_dbs.push(SpaceDB::new(space, filtered, scales.clone(), max_elements)?)
+        }
+
+        Ok(Core {
+            title: title.into(),
+            version: version.into(),
+            properties: Mapped::new(properties),
+            space_db: space_dbs,
+        })
+    }
+
+    /// Instantiate a new index, using a bounded amount of memory.
+    ///
+    /// This builds the same index as [`new`](#method.new), but the
+    /// objects are consumed as they are produced, and the positions
+    /// are sorted on disk, in chunks, to compute every resolution.
+    ///
+    /// Only the *identifiers* and the final index are kept in memory,
+    /// the later still has to fit in memory to be used or stored.
+    ///
+    /// # Parameters
+    ///
+    ///  * `title`:
+    ///     Name of the dataset.
+    ///
+    ///  * `version`:
+    ///     Revision of the dataset.
+    ///
+    ///  * `spaces`:
+    ///     A list of the reference spaces. Only objects whose reference
+    ///     space is known will be indexed.
+    ///
+    ///  * `objects`:
+    ///     A stream of *identifiers*, each with its list of volumetric
+    ///     positions. The value stored in each position object is
+    ///     ignored. The stream is interrupted at the first error.
+    ///
+    ///  * `scales`:
+    ///     A list of resolutions for which to build indices. Each value
+    ///     represent the number of bits of precision to **remove** from
+    ///     the coordinates to build the index, for each axis.
+    ///
+    ///  * `max_elements`:
+    ///     The minimum number of positions to use as a stopping
+    ///     condition while building automatically multiple resolutions
+    ///     of the index. See [`new`](#method.new).
+    ///
+    ///  * `directory`:
+    ///     Where to store the temporary files. They are removed once
+    ///     the index is built.
+    ///
+    ///  * `chunk_size`:
+    ///     Maximum number of positions to sort at once in memory.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_external<S, I>(
+        title: S,
+        version: S,
+        spaces: &[Space],
+        objects: I,
+        scales: Option<Vec<Vec<u32>>>,
+        max_elements: Option<usize>,
+        directory: &Path,
+        chunk_size: usize,
+    ) -> Result<Self, Error>
+    where
+        S: Into<String>,
+        I: IntoIterator<Item = Result<(Properties, Vec<SpaceSetObject>), Error>>,
+    {
+        let mut sorters = spaces
+            .iter()
+            .map(|_| Sorter::new(directory, chunk_size))
+            .collect::<Vec<_>>();
+
+        // Properties are numbered in order of appearance, until we know
+        // all of them and their sorted order.
+        let mut properties = vec![];
+        let mut properties_hm = HashMap::new();
+
+        for object in objects {
+            let (property, positions) = object?;
+
+            let value = match properties_hm.get(property.id()) {
+                Some(value) => *value,
+                None => {
+                    properties_hm.insert(property.id().to_string(), properties.len());
+                    properties.push(property);
+                    properties.len() - 1
+                }
+            };
+
+            // Encode the points of known spaces, as we go.
+            for object in positions {
+                if let Some(s) = spaces.iter().position(|s| s.name() == object.space_id()) {
+                    let position: Vec<f64> = object.position().into();
+                    let position = spaces[s].encode(&position)?;
+                    let key = (0..position.dimensions()).map(|k| position[k].u64());
+
+                    sorters[s].push((key.collect(), value))?;
+                }
+            }
+        }
+
+        // Sort the properties, and compute the new offset of each value.
+        let mut order = (0..properties.len()).collect::<Vec<_>>();
+        order.sort_unstable_by(|a, b| properties[*a].id().cmp(properties[*b].id()));
+
+        let mut remap = vec![0; order.len()];
+        for (offset, value) in order.iter().enumerate() {
+            remap[*value] = offset;
+        }
+
+        let mut properties = properties.into_iter().map(Some).collect::<Vec<_>>();
+        let properties = order
+            .iter()
+            .filter_map(|value| properties[*value].take())
+            .collect::<Vec<_>>();
+
+        // We cannot return less that the total number of individual Ids stored
+        // in the index for a full-volume query.
+        let max_elements = max_elements.map(|elem| elem.max(properties.len()));
+
+        let mut space_dbs = vec![];
+        for (space, sorter) in spaces.iter().zip(sorters) {
+            space_dbs.push(SpaceDB::new_external(
+                space,
+                sorter,
+                &remap,
+                scales.clone(),
+                max_elements,
+                directory,
+                chunk_size,
+            )?);
         }
 
         Ok(Core {
             title: title.into(),
             version: version.into(),
-            properties,
+            properties: Mapped::new(properties),
             space_db: space_dbs,
         })
     }
 
+    /// Add new objects to an existing index.
+    ///
+    /// The list of *identifiers* is kept sorted, and every resolution
+    /// of the index is updated, without re-encoding the objects already
+    /// stored. The set of resolutions is the one computed when the
+    /// index was built, new reference spaces are indexed only at full
+    /// resolution.
+    ///
+    /// # Parameters
+    ///
+    ///  * `spaces`:
+    ///     The list of reference spaces used within the dataset. It
+    ///     must contain at least all the reference spaces already
+    ///     indexed.
+    ///
+    ///  * `properties`:
+    ///     The *identifiers* referenced by the `space_objects` by offset
+    ///     within this list. They may already be known by this index.
+    ///
+    ///  * `space_objects`:
+    ///     A list of links between volumetric positions and
+    ///     identifiers.
+    pub fn insert(
+        &mut self,
+        spaces: &[Space],
+        properties: Vec<Properties>,
+        space_objects: Vec<SpaceSetObject>,
+    ) -> Result<(), Error> {
+        let current = self.properties.get()?;
+
+        // Merge the new identifiers with the current ones, keeping the
+        // list sorted and free of duplicates.
+        let mut merged = current.clone();
+        merged.extend(
+            properties
+                .iter()
+                .filter(|p| {
+                    current
+                        .binary_search_by_key(&p.id(), |properties| properties.id())
+                        .is_err()
+                })
+                .cloned(),
+        );
+
+        // sort_by_key triggers borrow checker errors here as well.
+        #[allow(clippy::unnecessary_sort_by)]
+        merged.sort_unstable_by(|a, b| a.id().cmp(b.id()));
+        merged.dedup_by(|a, b| a.id() == b.id());
+
+        let offset = |p: &Properties| {
+            merged
+                .binary_search_by_key(&p.id(), |properties| properties.id())
+                .unwrap()
+        };
+
+        // New offsets of the values currently stored, and of the values
+        // referenced by the new objects.
+        let remap = current.iter().map(|p| Some(offset(p))).collect::<Vec<_>>();
+        let values = properties.iter().map(offset).collect::<Vec<_>>();
+
+        let space_objects = space_objects
+            .into_iter()
+            .map(|mut object| match values.get(object.value()) {
+                Some(value) => {
+                    object.set_value(*value);
+                    Ok(object)
+                }
+                None => Err(Error::InvalidData(format!(
+                    "Object references identifier {}, out of {} provided",
+                    object.value(),
+                    values.len()
+                ))),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        self.update(spaces, &remap, space_objects)?;
+        self.properties = Mapped::new(merged);
+
+        Ok(())
+    }
+
+    /// Remove an *identifier* and all the positions linked to it from
+    /// the index.
+    ///
+    /// # Parameters
+    ///
+    ///  * `spaces`:
+    ///     The list of reference spaces used within the dataset. It
+    ///     must contain at least all the reference spaces already
+    ///     indexed.
+    ///
+    ///  * `id`:
+    ///     Identifier to remove.
+    pub fn remove(&mut self, spaces: &[Space], id: &str) -> Result<(), Error> {
+        let offset = self.offset(id)?;
+
+        // Every value after the removed one moves down by one.
+        let remap = (0..self.properties.get()?.len())
+            .map(|value| match value {
+                _ if value < offset => Some(value),
+                _ if value > offset => Some(value - 1),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        self.update(spaces, &remap, vec![])?;
+        self.properties.get_mut()?.remove(offset);
+
+        Ok(())
+    }
+
+    /// Replace an *identifier* and all the positions linked to it.
+    ///
+    /// The positions currently registered for the *identifier* are
+    /// removed from every reference space and resolution, and replaced
+    /// by the new ones. Its kind and attributes are replaced as well.
+    ///
+    /// # Parameters
+    ///
+    ///  * `spaces`:
+    ///     The list of reference spaces used within the dataset. It
+    ///     must contain at least all the reference spaces already
+    ///     indexed.
+    ///
+    ///  * `properties`:
+    ///     The new definition of the *identifier*, which must already
+    ///     be registered.
+    ///
+    ///  * `space_objects`:
+    ///     The new positions of the *identifier*. Their values are
+    ///     ignored.
+    pub fn replace(
+        &mut self,
+        spaces: &[Space],
+        properties: Properties,
+        space_objects: Vec<SpaceSetObject>,
+    ) -> Result<(), Error> {
+        let offset = self.offset(properties.id())?;
+
+        let remap = (0..self.properties.get()?.len())
+            .map(|value| if value == offset { None } else { Some(value) })
+            .collect::<Vec<_>>();
+
+        let space_objects = space_objects
+            .into_iter()
+            .map(|mut object| {
+                object.set_value(offset);
+                object
+            })
+            .collect::<Vec<_>>();
+
+        self.update(spaces, &remap, space_objects)?;
+        self.properties.get_mut()?[offset] = properties;
+
+        Ok(())
+    }
+
+    fn offset(&self, id: &str) -> Result<usize, Error> {
+        match self
+            .properties
+            .get()?
+            .binary_search_by_key(&id, |properties| properties.id())
+        {
+            Ok(offset) => Ok(offset),
+            Err(_) => Err(Error::UnknownId {
+                core: self.title.clone(),
+                id: id.to_string(),
+            }),
+        }
+    }
+
+    // Apply changes to the positions stored in every reference space.
+    //
+    // `remap` provides for each value currently stored its new offset, or
+    // `None` to drop the positions linked to it. The values of the
+    // `space_objects` must be offsets within the updated properties.
+    fn update(
+        &mut self,
+        spaces: &[Space],
+        remap: &[Option<usize>],
+        space_objects: Vec<SpaceSetObject>,
+    ) -> Result<(), Error> {
+        let moved = remap.iter().enumerate().any(|(from, to)| Some(from) != *to);
+
+        // Encode everything first, so that we do not leave the index in an
+        // inconsistent state on errors.
+        let mut updates = vec![];
+        for space in spaces {
+            let mut filtered = space_objects
+                .iter()
+                .filter(|object| object.space_id() == space.name())
+                .cloned()
+                .collect::<Vec<_>>();
+
+            for object in filtered.iter_mut() {
+                let position: Vec<f64> = object.position().into();
+                object.set_position(space.encode(&position)?);
+            }
+
+            updates.push((space, filtered));
+        }
+
+        for s in &self.space_db {
+            if !spaces.iter().any(|space| space.name() == s.name()) {
+                return Err(Error::UnknownSpace(s.name().clone()));
+            }
+
+            s.load()?;
+        }
+
+        for (space, objects) in updates {
+            match self.space_db.iter_mut().find(|s| s.name() == space.name()) {
+                Some(s) => {
+                    if moved || !objects.is_empty() {
+                        s.update(space, &objects, remap)?;
+                    }
+                }
+                None => {
+                    if !objects.is_empty() {
+                        self.space_db
+                            .push(SpaceDB::new(space, objects, None, None)?);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Title of the dataset.
     pub fn name(&self) -> &String {
         &self.title
@@ -214,29 +684,35 @@ impl Core {
     }
 
     /// List of *identifiers* contained in this dataset.
-    pub fn keys(&self) -> &Vec<Properties> {
-        &self.properties
+    ///
+    /// When the dataset is loaded from an index file, the list is
+    /// decoded on first use, which fails with `Error::Corrupted` or
+    /// `Error::Deserialization` if that part of the file is damaged.
+    pub fn keys(&self) -> Result<&Vec<Properties>, Error> {
+        self.properties.get()
     }
 
     fn decode_positions<'b>(
         list: IterObjects<'b>,
         space: &'b Space,
-        db: &'b DataBase,
-        output_space: &Option<&str>,
-    ) -> Result<IterObjects<'b>, String> {
+        parameters: &'b CoreQueryParameters,
+    ) -> Result<IterObjects<'b>, Error> {
+        let CoreQueryParameters {
+            db, output_space, ..
+        } = parameters;
+
         let b: IterObjects = if let Some(unified_id) = *output_space {
             let unified = db.space(unified_id)?;
+            let conversion = db
+                .conversion(space, unified)?
+                .with_bounds(parameters.out_of_bounds);
 
             // Rebase the point to the requested output space before decoding.
-            Box::new(list.filter_map(move |(position, properties)| {
-                match Space::change_base(&position, space, unified) {
-                    Err(_) => None,
-                    Ok(rebased) => match unified.decode(&rebased) {
-                        Err(_) => None,
-                        Ok(decoded) => Some((decoded.into(), properties)),
-                    },
-                }
-            }))
+            parameters.bounded(list, move |(position, properties)| {
+                let rebased = conversion.position(&position)?;
+
+                Ok((unified.decode(&rebased)?.into(), properties))
+            })?
         } else {
             // Decode the positions into f64 values, which are defined in their
             // respective reference space.
@@ -272,15 +748,17 @@ impl Core {
         positions: Vec<Position>,
         space_id: &'d str,
     ) -> ResultSet<'d> {
-        let CoreQueryParameters {
-            db, output_space, ..
-        } = parameters;
+        let CoreQueryParameters { db, .. } = parameters;
 
         let mut results = vec![];
         let from = db.space(space_id)?;
+        let properties = self.properties.get()?;
 
         for s in &self.space_db {
             let to = db.space(s.name())?;
+            let conversion = db
+                .conversion(from, to)?
+                .with_bounds(parameters.out_of_bounds);
 
             // Filter positions based on the view port, if present
             // FIXME: remove clone() on positions?
@@ -295,27 +773,24 @@ impl Core {
             };
 
             // Rebase the positions into the current space
-            let p = filtered.filter_map(move |position| {
-                match Space::change_base(&position, from, to) {
-                    Err(_) => None,
-                    Ok(position) => {
-                        let position: Vec<f64> = position.into();
-                        match to.encode(&position) {
-                            Err(_) => None,
-                            Ok(position) => Some(position),
-                        }
+            let p = parameters
+                .bounded(filtered, move |position| conversion.position(&position))?
+                .filter_map(move |position| {
+                    let position: Vec<f64> = position.into();
+                    match to.encode(&position) {
+                        Err(_) => None,
+                        Ok(position) => Some(position),
                     }
-                }
-            });
+                });
 
             // Select the data based on the rebased viewport filter.
             let r = s
                 .get_by_positions(p, parameters)?
-                .map(move |(position, fields)| (position, &self.properties[fields.value()]));
+                .map(move |(position, value)| (position, &properties[value]));
 
             results.push((
                 s.name(),
-                Self::decode_positions(Box::new(r), to, db, output_space)?,
+                Self::decode_positions(Box::new(r), to, parameters)?,
             ));
         }
 
@@ -330,7 +805,8 @@ impl Core {
     ///     Search parameters, see [CoreQueryParameters](struct.CoreQueryParameters.html).
     ///
     ///  * `shape`:
-    ///     Volume to use to filter data points.
+    ///     Volume to use to filter data points. Malformed shapes are
+    ///     rejected, see [validate](space/enum.Shape.html#method.validate).
     ///
     ///  * `space_id`:
     ///     *shape* is defined as decoded coordinates in this
@@ -343,34 +819,111 @@ impl Core {
         shape: Shape,
         space_id: &'d str,
     ) -> ResultSet<'d> {
-        let CoreQueryParameters {
-            db, output_space, ..
-        } = parameters;
+        let CoreQueryParameters { db, .. } = parameters;
+
+        shape.validate()?;
 
         let mut results = vec![];
         let shape_space = db.space(space_id)?;
+        let properties = self.properties.get()?;
 
         for s in &self.space_db {
             let current_space = db.space(s.name())?;
 
-            let current_shape = shape.rebase(shape_space, current_space)?;
+            let current_shape = shape.convert(&db.conversion(shape_space, current_space)?)?;
             //            println!("current shape: {:?}", current_shape);
             //            let current_shape = shape.encode(current_space)?;
             //            println!("current shape Encoded: {:?}", current_shape);
 
             let r = s
                 .get_by_shape(current_shape, parameters)?
-                .map(move |(position, fields)| (position, &self.properties[fields.value()]));
+                .map(move |(position, value)| (position, &properties[value]));
 
             results.push((
                 s.name(),
-                Self::decode_positions(Box::new(r), current_space, db, output_space)?,
+                Self::decode_positions(Box::new(r), current_space, parameters)?,
             ));
         }
 
         Ok(results)
     }
 
+    /// Search for the `k` nearest objects to a position.
+    ///
+    /// The distances are computed in the Universe, so that objects from
+    /// all the reference spaces can be compared. Each object is listed
+    /// once, with the distance of its nearest position, in any of the
+    /// reference spaces, and the list is ordered by increasing distance.
+    ///
+    /// The positions of the reference spaces connected both ways to the
+    /// space of *position* by declared transformations are first
+    /// converted through them into that space, see
+    /// [DataBase::declare_transform](struct.DataBase.html#method.declare_transform).
+    /// Positions which fall outside of it are then ignored.
+    ///
+    /// # Parameters
+    ///
+    ///  * `parameters`:
+    ///     Search parameters, see [CoreQueryParameters](struct.CoreQueryParameters.html).
+    ///
+    ///  * `position`:
+    ///     Position from which to compute distances.
+    ///
+    ///  * `k`:
+    ///     Number of objects to return at most.
+    ///
+    ///  * `space_id`:
+    ///     *position* is defined as encoded coordinates in this
+    ///     reference space.
+    pub fn get_nearest<'d>(
+        &'d self,
+        parameters: &'d CoreQueryParameters,
+        position: &Position,
+        k: usize,
+        space_id: &'d str,
+    ) -> Result<Vec<(f64, &'d Properties)>, Error> {
+        let CoreQueryParameters { db, .. } = parameters;
+
+        let from = db.space(space_id)?;
+        let origin = db.conversion(from, Space::universe())?.position(position)?;
+        let properties = self.properties.get()?;
+
+        // Collect the nearest objects of each reference space, then keep
+        // the overall nearest ones.
+        let mut candidates = vec![];
+        for s in &self.space_db {
+            let to = db.space(s.name())?;
+            let forward = db.conversion(from, to)?;
+            let backward = db.conversion(to, from)?;
+
+            // Search around the position converted into the index, and
+            // compare positions through the declared transformations,
+            // when they allow to do both.
+            let center = forward.position(position)?;
+            let through = match (forward.transform(), backward.transform()) {
+                (Some(_), Some(_)) => Some(backward.with_bounds(OutOfBounds::Reject)),
+                _ => None,
+            };
+
+            candidates.extend(s.get_nearest(
+                to,
+                &center,
+                through.as_ref(),
+                &origin,
+                k,
+                parameters,
+            )?);
+        }
+
+        let mut nearest = nearest(candidates);
+        nearest.truncate(k);
+
+        Ok(nearest
+            .into_iter()
+            .map(|(distance, value)| (distance, &properties[value]))
+            .collect())
+    }
+
     /// Search by Id, a.k.a retrieve all the positions linked to this id.
     ///
     /// # Parameters
@@ -385,7 +938,7 @@ impl Core {
         &'s self,
         parameters: &'s CoreQueryParameters,
         id: S,
-    ) -> Result<Vec<(&String, IterPositions<'s>)>, String>
+    ) -> Result<Vec<(&String, IterPositions<'s>)>, Error>
     where
         S: Into<String>,
     {
@@ -399,6 +952,7 @@ impl Core {
         // Do we have this ID registered at all?
         if let Ok(offset) = self
             .properties
+            .get()?
             .binary_search_by_key(&id.as_str(), |properties| properties.id())
         {
             // Yes, so now let's find all the position linked to it, per
@@ -411,17 +965,16 @@ impl Core {
                 //Self::decode_positions(r.as_mut_slice(), current_space, db, output_space)?;
                 let positions: IterPositions = if let Some(unified_id) = *output_space {
                     let unified = db.space(unified_id)?;
+                    let conversion = db
+                        .conversion(current_space, unified)?
+                        .with_bounds(parameters.out_of_bounds);
 
                     // Rebase the point to the requested output space before decoding.
-                    Box::new(positions_by_id.filter_map(move |position| {
-                        match Space::change_base(&position, current_space, unified) {
-                            Err(_) => None,
-                            Ok(rebased) => match unified.decode(&rebased) {
-                                Err(_) => None,
-                                Ok(decoded) => Some(decoded.into()),
-                            },
-                        }
-                    }))
+                    parameters.bounded(positions_by_id, move |position| {
+                        let rebased = conversion.position(&position)?;
+
+                        Ok(unified.decode(&rebased)?.into())
+                    })?
                 } else {
                     // Decode the positions into f64 values, which are defined in their
                     // respective reference space.
@@ -459,80 +1012,61 @@ impl Core {
     where
         S: Into<String>,
     {
-        let CoreQueryParameters {
-            db, output_space, ..
-        } = parameters;
+        let CoreQueryParameters { db, .. } = parameters;
 
         let id: String = id.into();
         let mut results = vec![];
 
-        // Convert the view port to the encoded space coordinates
-        let view_port = parameters.view_port(Space::universe());
-
-        if let Ok(offset) = self
-            .properties
-            .binary_search_by_key(&id.as_str(), |properties| properties.id())
-        {
-            // Generate the search volume. Iterate over all reference spaces, to
-            // retrieve a list of SpaceSetObjects linked to `id`, then iterate
-            // over the result to generate a list of positions in Universe.
-            let search_volume = self
-                .space_db
-                .iter()
-                .filter_map(move |s| {
-                    match db.space(s.name()) {
-                        Err(_) => None,
-                        Ok(from) => match s.get_by_id(offset, parameters) {
-                            Err(_) => None,
-                            Ok(v) => {
-                                // Convert the search Volume into Universe.
-                                let mut p = vec![];
-                                for position in v {
-                                    if let Ok(position) =
-                                        Space::change_base(&position, from, Space::universe())
-                                    {
-                                        p.push(position)
-                                    }
-                                }
-
-                                Some(p)
-                            }
-                        },
-                    }
-                })
-                .flatten();
+        let properties = self.properties.get()?;
 
+        if let Ok(offset) = properties.binary_search_by_key(&id.as_str(), |p| p.id()) {
             // Select based on the volume, and filter out the label position themselves.
             for s in &self.space_db {
                 let to = db.space(s.name())?;
 
-                let search_volume: IterPositions = if let Some(view) = view_port.clone() {
-                    Box::new(search_volume.clone().filter(move |p| view.contains(p)))
+                // Generate the search volume. Iterate over all reference
+                // spaces, to retrieve a list of SpaceSetObjects linked to
+                // `id`, then convert them directly into the target space.
+                let search_volume = self
+                    .space_db
+                    .iter()
+                    .filter_map(move |label| {
+                        let conversion = match db.space(label.name()) {
+                            Err(_) => return None,
+                            Ok(from) => match db.conversion(from, to) {
+                                Err(_) => return None,
+                                Ok(conversion) => conversion.with_bounds(parameters.out_of_bounds),
+                            },
+                        };
+
+                        match label.get_by_id(offset, parameters) {
+                            Err(_) => None,
+                            Ok(v) => Some(v.map(move |position| conversion.position(&position))),
+                        }
+                    })
+                    .flatten();
+                let search_volume = parameters.bounded(search_volume, |position| position)?;
+
+                // Convert the view port to the encoded space coordinates
+                let p: IterPositions = if let Some(view) = parameters.view_port(to) {
+                    Box::new(search_volume.filter(move |p| view.contains(p)))
                 } else {
-                    Box::new(search_volume.clone())
+                    Box::new(search_volume)
                 };
 
-                // Convert the search Volume into the target space.
-                let p = search_volume.filter_map(move |position| {
-                    match Space::change_base(&position, Space::universe(), to) {
-                        Err(_) => None,
-                        Ok(position) => Some(position),
-                    }
-                });
-
                 let r = s
                     .get_by_positions(p, parameters)?
-                    .filter_map(move |(position, fields)| {
-                        if fields.value() == offset {
+                    .filter_map(move |(position, value)| {
+                        if value == offset {
                             None
                         } else {
-                            Some((position, &self.properties[fields.value()]))
+                            Some((position, &properties[value]))
                         }
                     });
 
                 results.push((
                     s.name(),
-                    Self::decode_positions(Box::new(r), to, db, output_space)?,
+                    Self::decode_positions(Box::new(r), to, parameters)?,
                 ));
             }
         }
@@ -0,0 +1,260 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fs;
+use std::fs::File;
+use std::io::BufReader;
+use std::io::BufWriter;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use crate::Error;
+
+// Encoded coordinates of a position, and the offset of its identifier.
+pub type Record = (Vec<u64>, usize);
+
+// Maximum number of runs merged at once, which bounds the number of files
+// open at the same time.
+const MERGE_FAN_IN: usize = 64;
+
+// Used to generate unique file names for the runs of this process.
+static RUN_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+// Sorted list of unique records, stored in a temporary file. The file is
+// removed once the run is dropped.
+pub struct Run {
+    path: PathBuf,
+    len: usize,
+}
+
+impl Run {
+    // Write a sorted list of unique records into a new temporary file,
+    // within `directory`.
+    fn create<I>(directory: &Path, records: I) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = Result<Record, Error>>,
+    {
+        let path = directory.join(format!(
+            "mercator_db.{}.{}.run",
+            std::process::id(),
+            RUN_COUNT.fetch_add(1, Ordering::SeqCst)
+        ));
+
+        // Create the run first, so that the file is removed on errors.
+        let mut run = Run { path, len: 0 };
+        let mut writer = BufWriter::new(File::create(&run.path)?);
+
+        for record in records {
+            if let Err(e) = bincode::serialize_into(&mut writer, &record?) {
+                return Err(Error::Serialization(format!(
+                    "{}: {}",
+                    run.path.display(),
+                    e
+                )));
+            }
+            run.len += 1;
+        }
+
+        if let Err(e) = writer.into_inner() {
+            return Err(e.into_error().into());
+        }
+
+        Ok(run)
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    // Read back the records, in order.
+    pub fn iter(&self) -> Result<RunIter, Error> {
+        Ok(RunIter {
+            path: self.path.clone(),
+            reader: BufReader::new(File::open(&self.path)?),
+            remaining: self.len,
+        })
+    }
+
+    // Reduce the precision of every position of the run, removing
+    // `scale[k]` bits on axis k, and sort the result into a new run.
+    pub fn reduce_precision(
+        &self,
+        scale: &[u32],
+        directory: &Path,
+        chunk_size: usize,
+    ) -> Result<Run, Error> {
+        let mut sorter = Sorter::new(directory, chunk_size);
+
+        for record in self.iter()? {
+            let (key, value) = record?;
+            let key = key.iter().zip(scale).map(|(c, s)| c >> s).collect();
+            sorter.push((key, value))?;
+        }
+
+        sorter.finish(|record| record)
+    }
+}
+
+impl Drop for Run {
+    fn drop(&mut self) {
+        if let Err(e) = fs::remove_file(&self.path) {
+            warn!("Failed to remove '{}': {}", self.path.display(), e);
+        }
+    }
+}
+
+pub struct RunIter {
+    path: PathBuf,
+    reader: BufReader<File>,
+    remaining: usize,
+}
+
+impl Iterator for RunIter {
+    type Item = Result<Record, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        match bincode::deserialize_from(&mut self.reader) {
+            Ok(record) => Some(Ok(record)),
+            Err(e) => {
+                // Stop at the first error, the rest of the file is unusable.
+                self.remaining = 0;
+                Some(Err(Error::Deserialization(format!(
+                    "{}: {}",
+                    self.path.display(),
+                    e
+                ))))
+            }
+        }
+    }
+}
+
+// Sort an arbitrary number of records using a bounded amount of memory.
+//
+// Records are buffered until `chunk_size` of them are available, then the
+// buffer is sorted, de-duplicated and spilled to disk as a run. When the
+// input is exhausted, runs are merged by groups of at most `MERGE_FAN_IN`,
+// as many times as needed to obtain a single one.
+pub struct Sorter {
+    directory: PathBuf,
+    chunk_size: usize,
+    buffer: Vec<Record>,
+    runs: Vec<Run>,
+}
+
+impl Sorter {
+    pub fn new(directory: &Path, chunk_size: usize) -> Self {
+        // Buffers of less than one element would never be spilled.
+        let chunk_size = chunk_size.max(1);
+
+        Sorter {
+            directory: directory.to_path_buf(),
+            chunk_size,
+            buffer: Vec::with_capacity(chunk_size),
+            runs: vec![],
+        }
+    }
+
+    pub fn push(&mut self, record: Record) -> Result<(), Error> {
+        self.buffer.push(record);
+
+        if self.buffer.len() >= self.chunk_size {
+            self.spill()?;
+        }
+
+        Ok(())
+    }
+
+    fn spill(&mut self) -> Result<(), Error> {
+        let mut buffer = std::mem::replace(&mut self.buffer, Vec::with_capacity(self.chunk_size));
+        buffer.sort_unstable();
+        buffer.dedup();
+
+        self.runs
+            .push(Run::create(&self.directory, buffer.into_iter().map(Ok))?);
+
+        Ok(())
+    }
+
+    // Merge every run into a single sorted run without duplicates, then
+    // apply `map` to each record as it is written out.
+    //
+    // `map` must not change the relative order of the records, nor
+    // introduce duplicates.
+    pub fn finish<F>(mut self, map: F) -> Result<Run, Error>
+    where
+        F: Fn(Record) -> Record,
+    {
+        if !self.buffer.is_empty() {
+            self.spill()?;
+        }
+
+        // Merge intermediate runs until the last pass fits the fan-in.
+        let mut runs = self.runs;
+        while runs.len() > MERGE_FAN_IN {
+            let mut merged = Vec::with_capacity(runs.len().div_ceil(MERGE_FAN_IN));
+            for group in runs.chunks(MERGE_FAN_IN) {
+                merged.push(merge(&self.directory, group, |record| record)?);
+            }
+
+            // Dropping the merged runs removes their files.
+            runs = merged;
+        }
+
+        merge(&self.directory, &runs, map)
+    }
+}
+
+// Merge sorted runs into a single sorted run without duplicates, applying
+// `map` to each record as it is written out.
+fn merge<F>(directory: &Path, runs: &[Run], map: F) -> Result<Run, Error>
+where
+    F: Fn(Record) -> Record,
+{
+    let mut iterators = runs
+        .iter()
+        .map(|run| run.iter())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Seed the heap with the first record of every run.
+    let mut heap = BinaryHeap::with_capacity(iterators.len());
+    for (i, iterator) in iterators.iter_mut().enumerate() {
+        if let Some(record) = iterator.next() {
+            heap.push(Reverse((record?, i)));
+        }
+    }
+
+    let mut error = None;
+    let mut previous: Option<Record> = None;
+
+    let merged = std::iter::from_fn(|| loop {
+        let Reverse((record, i)) = heap.pop()?;
+
+        match iterators[i].next() {
+            Some(Ok(next)) => heap.push(Reverse((next, i))),
+            Some(Err(e)) => {
+                error = Some(e);
+                return None;
+            }
+            None => (),
+        }
+
+        // Runs are sorted, so duplicates are consecutive.
+        if previous.as_ref() != Some(&record) {
+            previous = Some(record.clone());
+            return Some(Ok(map(record)));
+        }
+    });
+
+    let run = Run::create(directory, merged)?;
+
+    match error {
+        Some(e) => Err(e),
+        None => Ok(run),
+    }
+}
@@ -0,0 +1,370 @@
+use std::borrow::Cow;
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::fmt;
+use std::ops::Deref;
+use std::ops::Range;
+use std::sync::Arc;
+use std::sync::OnceLock;
+
+use memmap::Mmap;
+use serde::de;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+
+use crate::storage::bincode::checksum;
+use crate::storage::bincode::INDEX_FORMAT;
+use crate::Error;
+
+thread_local! {
+    // File from which values are currently being deserialized, and the
+    // format of that file, see `with_source`.
+    static SOURCE: RefCell<Option<(Arc<Source>, u32)>> = const { RefCell::new(None) };
+
+    // Location and checksum of the sections found so far within that
+    // file, see `sections`.
+    static SECTIONS: RefCell<Vec<(Range<usize>, u64)>> = const { RefCell::new(Vec::new()) };
+
+    // Address and length of the content of the section currently being
+    // serialized, see `is_section`.
+    static WRITING: Cell<(usize, usize)> = const { Cell::new((0, 0)) };
+
+    // Error which interrupted the serialization of a `Mapped` value, see
+    // `failure`.
+    static FAILURE: RefCell<Option<Error>> = const { RefCell::new(None) };
+}
+
+// Content of an index file.
+pub enum Source {
+    // The file is used as-is.
+    Mapped(Mmap),
+    // The file was compressed, and is kept decompressed in memory.
+    Decompressed(Vec<u8>),
+}
+
+impl Deref for Source {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            Source::Mapped(map) => map,
+            Source::Decompressed(bytes) => bytes,
+        }
+    }
+}
+
+// Deserialize the content of `map`, an index file of format `format`,
+// using `f`.
+//
+// While `f` runs, the values of type `Mapped` found within `map` are not
+// decoded, but keep a reference to their location in the file instead.
+pub fn with_source<F, R>(map: Arc<Source>, format: u32, f: F) -> R
+where
+    F: FnOnce(&[u8]) -> R,
+{
+    // Make sure the source is unregistered, even on panics.
+    struct Reset;
+
+    impl Drop for Reset {
+        fn drop(&mut self) {
+            SOURCE.with(|source| source.borrow_mut().take());
+            SECTIONS.with(|sections| sections.borrow_mut().clear());
+        }
+    }
+
+    SOURCE.with(|source| *source.borrow_mut() = Some((map.clone(), format)));
+    SECTIONS.with(|sections| sections.borrow_mut().clear());
+    let _reset = Reset;
+
+    f(&map[..])
+}
+
+// Location within the file and checksum of the content of every value
+// of type `Mapped` deserialized so far by the `f` given to
+// `with_source`, in the order they were found.
+pub fn sections() -> Vec<(Range<usize>, u64)> {
+    SECTIONS.with(|sections| sections.borrow_mut().split_off(0))
+}
+
+// Whether `bytes` are part of the content of the value of type `Mapped`
+// currently being serialized, as opposed to the data surrounding it.
+pub fn is_section(bytes: &[u8]) -> bool {
+    let (start, len) = WRITING.with(|writing| writing.get());
+    let address = bytes.as_ptr() as usize;
+
+    !bytes.is_empty() && address >= start && address + bytes.len() <= start + len
+}
+
+// Error which interrupted the last serialization of a value of type
+// `Mapped` on this thread, if any, as serializers only keep its message.
+pub fn failure() -> Option<Error> {
+    FAILURE.with(|failure| failure.borrow_mut().take())
+}
+
+// Location of an encoded value within a memory-mapped file.
+#[derive(Clone)]
+struct Section {
+    map: Arc<Source>,
+    offset: usize,
+    len: usize,
+    checksum: u64,
+    // Format of the file, which selects the checksum algorithm.
+    format: u32,
+}
+
+impl Section {
+    fn bytes(&self) -> &[u8] {
+        &self.map[self.offset..self.offset + self.len]
+    }
+
+    // The content of the section, once checked against its checksum.
+    fn verified(&self) -> Result<&[u8], Error> {
+        let bytes = self.bytes();
+
+        if checksum(self.format, bytes) != self.checksum {
+            return Err(Error::Corrupted(format!(
+                "checksum mismatch for section at offset {}",
+                self.offset
+            )));
+        }
+
+        Ok(bytes)
+    }
+
+    fn decode<T: DeserializeOwned>(&self) -> Result<T, Error> {
+        let bytes = self.verified()?;
+
+        match bincode::deserialize(bytes) {
+            Ok(value) => Ok(value),
+            Err(e) => Err(Error::Deserialization(format!(
+                "section at offset {}: {}",
+                self.offset, e
+            ))),
+        }
+    }
+}
+
+// A value which is decoded from the index file only when it is first
+// accessed.
+//
+// It is stored as a standalone, checksummed, Bincode blob, so that the
+// pages of the file are only loaded when the value is needed.
+//
+// This is not zero-copy: once accessed, the value is decoded into heap
+// structures, which are kept until the `Mapped` value is dropped. Only
+// the cost of loading is deferred and limited to the values used, the
+// pages of the file backing a decoded value are clean, and can be
+// reclaimed by the operating system.
+pub struct Mapped<T> {
+    value: OnceLock<T>,
+    // Always set when value is not.
+    section: Option<Section>,
+}
+
+impl<T> Mapped<T>
+where
+    T: DeserializeOwned,
+{
+    pub fn new(value: T) -> Self {
+        Mapped {
+            value: OnceLock::from(value),
+            section: None,
+        }
+    }
+
+    pub fn get(&self) -> Result<&T, Error> {
+        if let Some(value) = self.value.get() {
+            return Ok(value);
+        }
+
+        let value = match &self.section {
+            Some(section) => section.decode()?,
+            None => unreachable!(),
+        };
+
+        // Another thread might have decoded it in the meantime, in which
+        // case both values are the same.
+        Ok(self.value.get_or_init(|| value))
+    }
+
+    pub fn get_mut(&mut self) -> Result<&mut T, Error> {
+        self.get()?;
+        self.section = None;
+
+        match self.value.get_mut() {
+            Some(value) => Ok(value),
+            None => unreachable!(),
+        }
+    }
+}
+
+impl<T> Clone for Mapped<T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Mapped {
+            value: self.value.clone(),
+            section: self.section.clone(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for Mapped<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.value.get() {
+            Some(value) => value.fmt(f),
+            None => write!(f, "<not loaded>"),
+        }
+    }
+}
+
+struct RawBytes<'a>(&'a [u8]);
+
+impl Serialize for RawBytes<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        WRITING.with(|writing| writing.set((self.0.as_ptr() as usize, self.0.len())));
+        let serialized = serializer.serialize_bytes(self.0);
+        WRITING.with(|writing| writing.set((0, 0)));
+
+        serialized
+    }
+}
+
+impl<T> Serialize for Mapped<T>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // Copy the section as-is when the value has not been decoded, as
+        // its layout is the same in every format. It is checked first, as
+        // it gets a new checksum, which might also use another algorithm.
+        let bytes = match (self.value.get(), &self.section) {
+            (Some(value), _) => match bincode::serialize(value) {
+                Ok(bytes) => Cow::Owned(bytes),
+                Err(e) => return Err(serde::ser::Error::custom(e)),
+            },
+            (None, Some(section)) => match section.verified() {
+                Ok(bytes) => Cow::Borrowed(bytes),
+                Err(e) => {
+                    let message = e.to_string();
+                    FAILURE.with(|failure| *failure.borrow_mut() = Some(e));
+
+                    return Err(serde::ser::Error::custom(message));
+                }
+            },
+            (None, None) => unreachable!(),
+        };
+
+        (checksum(INDEX_FORMAT, &bytes), RawBytes(&bytes)).serialize(serializer)
+    }
+}
+
+struct BytesVisitor;
+
+impl<'de> de::Visitor<'de> for BytesVisitor {
+    type Value = Cow<'de, [u8]>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a byte array")
+    }
+
+    fn visit_borrowed_bytes<E>(self, v: &'de [u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Cow::Borrowed(v))
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Cow::Owned(v.to_vec()))
+    }
+
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Cow::Owned(v))
+    }
+}
+
+struct Bytes<'a>(Cow<'a, [u8]>);
+
+impl<'de> Deserialize<'de> for Bytes<'de> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Bytes(deserializer.deserialize_bytes(BytesVisitor)?))
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Mapped<T>
+where
+    T: DeserializeOwned,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (expected, Bytes(bytes)) = <(u64, Bytes<'de>)>::deserialize(deserializer)?;
+
+        let source = SOURCE.with(|source| source.borrow().clone());
+        let format = match &source {
+            Some((_, format)) => *format,
+            None => INDEX_FORMAT,
+        };
+
+        // Keep a reference to the bytes if they are within the file being
+        // loaded.
+        if let Cow::Borrowed(bytes) = &bytes {
+            if let Some((map, _)) = source {
+                let base = map.as_ptr() as usize;
+                let start = bytes.as_ptr() as usize;
+
+                if start >= base && start + bytes.len() <= base + map.len() {
+                    let offset = start - base;
+                    SECTIONS.with(|sections| {
+                        let range = offset..offset + bytes.len();
+                        sections.borrow_mut().push((range, expected))
+                    });
+
+                    return Ok(Mapped {
+                        value: OnceLock::new(),
+                        section: Some(Section {
+                            map,
+                            offset,
+                            len: bytes.len(),
+                            checksum: expected,
+                            format,
+                        }),
+                    });
+                }
+            }
+        }
+
+        if checksum(format, &bytes) != expected {
+            return Err(de::Error::custom("section checksum mismatch"));
+        }
+
+        match bincode::deserialize(&bytes) {
+            Ok(value) => Ok(Mapped::new(value)),
+            Err(e) => Err(de::Error::custom(e)),
+        }
+    }
+}
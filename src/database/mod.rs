@@ -1,18 +1,31 @@
 mod db_core;
+mod external_sort;
+pub(crate) mod mapped;
 pub mod space;
 mod space_db;
 pub(crate) mod space_index;
+mod transform_graph;
+
+#[cfg(test)]
+mod tests;
 
 use std::collections::HashMap;
+use std::sync::Arc;
 
 use ironsea_index::Indexed;
 
 use super::storage;
+use crate::Error;
+pub use db_core::Attributes;
 pub use db_core::Core;
 pub use db_core::CoreQueryParameters;
+pub(crate) use db_core::CoreV1;
 pub use db_core::Properties;
+use space::Conversion;
 use space::Position;
 use space::Space;
+use space::Transform;
+use transform_graph::TransformGraph;
 
 /// TODO doc
 pub type IterPositions<'i> = Box<dyn Iterator<Item = Position> + 'i>;
@@ -24,18 +37,20 @@ pub type IterObjectsBySpaces<'i> = Vec<(&'i String, IterObjects<'i>)>;
 /// Selected tuples matching a query.
 ///
 /// This is either:
-///  * `Err` with a reason stored as a `String`
+///  * `Err` with the reason stored as an [`Error`](enum.Error.html)
 ///  * `Ok`, with a vector of tuples defined as:
 ///        `(Space Name, [(Position, Properties)])`
-pub type ResultSet<'r> = Result<IterObjectsBySpaces<'r>, String>;
+pub type ResultSet<'r> = Result<IterObjectsBySpaces<'r>, Error>;
 
 type ReferenceSpaceIndex = ironsea_index_hashmap::Index<Space, String>;
-type CoreIndex = ironsea_index_hashmap::Index<Core, String>;
 
 /// Collection of datasets and their reference spaces.
 pub struct DataBase {
     reference_spaces: ReferenceSpaceIndex,
-    cores: CoreIndex,
+    // Cores are kept in a mutable map, in order to allow updates.
+    cores: HashMap<String, Core>,
+    core_keys: Vec<String>,
+    transforms: TransformGraph,
 }
 
 impl DataBase {
@@ -51,20 +66,33 @@ impl DataBase {
     ///      `DataBase` struct.
     // TODO: Replace vectors with iterators?
     pub fn new(spaces: Vec<Space>, cores: Vec<Core>) -> Self {
+        let cores = cores
+            .into_iter()
+            .map(|core| (core.name().clone(), core))
+            .collect::<HashMap<_, _>>();
+
+        let mut core_keys = cores.keys().cloned().collect::<Vec<_>>();
+        core_keys.sort_unstable();
+
         DataBase {
             reference_spaces: ReferenceSpaceIndex::new(spaces.into_iter()),
-            cores: CoreIndex::new(cores.into_iter()),
+            cores,
+            core_keys,
+            transforms: TransformGraph::default(),
         }
     }
 
     /// Load a list of indices.
     ///
+    /// The parts of the indices are decoded when queries first use them,
+    /// see [load_index](storage/bincode/fn.load_index.html).
+    ///
     /// # Parameters
     ///
     ///  * `indices`:
     ///      The list of index file names to load.
-    pub fn load(indices: &[&str]) -> Result<Self, String> {
-        let mut spaces = HashMap::new();
+    pub fn load(indices: &[&str]) -> Result<Self, Error> {
+        let mut spaces: HashMap<String, Space> = HashMap::new();
         let mut cores = vec![];
 
         for index in indices.iter() {
@@ -73,12 +101,12 @@ impl DataBase {
                 if let Some(space) = spaces.get(core_space.name()) {
                     // Space is already registered, but with a different definitions.
                     if space != &core_space {
-                        return Err(format!(
-                            "Reference Space ID `{}` defined two times, but differently\n{:?}\n VS \n{:?}",
-                            core_space.name(),
-                            spaces.get(core_space.name()),
-                            core_space
-                        ));
+                        debug!("{:?}\n VS \n{:?}", space, core_space);
+                        return Err(Error::SpaceConflict {
+                            name: core_space.name().clone(),
+                            existing: Box::new(space.clone()),
+                            conflicting: Box::new(core_space),
+                        });
                     }
                 } else {
                     spaces.insert(core_space.name().clone(), core_space);
@@ -93,28 +121,19 @@ impl DataBase {
         Ok(DataBase::new(spaces, cores))
     }
 
-    fn load_core(name: &str) -> Result<(Vec<Space>, Core), String> {
-        match storage::bincode::load(name) {
-            Err(e) => Err(format!("Index deserialization error: {:?}", e)),
-            Ok(index) => Ok(index),
-        }
+    fn load_core(name: &str) -> Result<(Vec<Space>, Core), Error> {
+        storage::bincode::load_index(name)
     }
 
-    fn check_exactly_one<'t, T>(list: &[&'t T], name: &str, value: &str) -> Result<&'t T, String> {
+    fn check_exactly_one<'t>(list: &[&'t Space], name: &str) -> Result<&'t Space, Error> {
         if list.len() > 1 {
-            Err(format!(
-                "Multiple {} registered under `{}`: {}",
-                name,
-                value,
-                list.len()
-            ))
+            Err(Error::SpaceConflict {
+                name: name.to_string(),
+                existing: Box::new(list[0].clone()),
+                conflicting: Box::new(list[1].clone()),
+            })
         } else if list.is_empty() {
-            Err(format!(
-                "No {} registered under `{}`: {}",
-                name,
-                value,
-                list.len()
-            ))
+            Err(Error::UnknownSpace(name.to_string()))
         } else {
             Ok(list[0])
         }
@@ -131,7 +150,7 @@ impl DataBase {
     ///
     ///  * `name`:
     ///      The name of the reference space to search for.
-    pub fn space(&self, name: &str) -> Result<&Space, String> {
+    pub fn space(&self, name: &str) -> Result<&Space, Error> {
         if name == space::Space::universe().name() {
             Ok(space::Space::universe())
         } else {
@@ -140,13 +159,91 @@ impl DataBase {
                 .find(&name.to_string())
                 .collect::<Vec<_>>();
 
-            Self::check_exactly_one(&r, "spaces", name)
+            Self::check_exactly_one(&r, name)
+        }
+    }
+
+    /// Declare a direct transformation between two reference spaces.
+    ///
+    /// Positions are then converted along the shortest chain of declared
+    /// transformations between two spaces, instead of going through the
+    /// Universe, see [conversion](#method.conversion). When it is
+    /// invertible, the transformation is also used in the opposite
+    /// direction.
+    ///
+    /// # Parameters
+    ///
+    ///  * `from`:
+    ///      The name of the source reference space.
+    ///
+    ///  * `to`:
+    ///      The name of the target reference space.
+    ///
+    ///  * `transform`:
+    ///      The transformation of decoded coordinates from `from` to
+    ///      `to`, which replaces any previous one between these spaces.
+    pub fn declare_transform(
+        &mut self,
+        from: &str,
+        to: &str,
+        transform: Transform,
+    ) -> Result<(), Error> {
+        for (space, dimensions) in &[
+            (self.space(from)?, transform.inputs()),
+            (self.space(to)?, transform.outputs()),
+        ] {
+            // The Universe accepts any number of dimensions.
+            if space.dimensions() > 0 && space.dimensions() != *dimensions {
+                return Err(Error::DimensionMismatch {
+                    expected: space.dimensions(),
+                    found: *dimensions,
+                });
+            }
         }
+
+        self.transforms.declare(from, to, transform)
+    }
+
+    /// Lookup the transformation between two reference spaces, composed
+    /// from the ones declared.
+    ///
+    /// Returns `None` when the spaces are not connected by declared
+    /// transformations.
+    ///
+    /// # Parameters
+    ///
+    ///  * `from`:
+    ///      The name of the source reference space.
+    ///
+    ///  * `to`:
+    ///      The name of the target reference space.
+    pub fn transform(&self, from: &str, to: &str) -> Result<Option<Arc<Transform>>, Error> {
+        self.transforms.find(from, to)
+    }
+
+    /// Build the conversion of encoded coordinates between two
+    /// reference spaces.
+    ///
+    /// The declared transformations are used when the spaces are
+    /// connected by them, otherwise the coordinates are converted
+    /// through the Universe.
+    ///
+    /// # Parameters
+    ///
+    ///  * `from`:
+    ///      Space in which the coordinates are defined.
+    ///
+    ///  * `to`:
+    ///      Target space in which the coordinates should be expressed.
+    pub fn conversion<'s>(&self, from: &'s Space, to: &'s Space) -> Result<Conversion<'s>, Error> {
+        let transform = self.transform(from.name(), to.name())?;
+
+        Ok(Conversion::new(from, to, transform))
     }
 
     /// Returns an ordered list of dataset (Core) names registered.
     pub fn core_keys(&self) -> &Vec<String> {
-        self.cores.keys()
+        &self.core_keys
     }
 
     /// Lookup a dataset within the datasets registered.
@@ -155,20 +252,88 @@ impl DataBase {
     ///
     ///  * `name`:
     ///      The name of the dataset (core) to search for.
-    pub fn core(&self, name: &str) -> Result<&Core, String> {
-        let r = self.cores.find(&name.to_string()).collect::<Vec<_>>();
+    pub fn core(&self, name: &str) -> Result<&Core, Error> {
+        match self.cores.get(name) {
+            None => Err(Error::UnknownCore(name.to_string())),
+            Some(core) => Ok(core),
+        }
+    }
+
+    /// Add objects to a dataset within the datasets registered.
+    ///
+    /// The index of the dataset is updated in place, see
+    /// [Core::insert](struct.Core.html#method.insert).
+    ///
+    /// # Parameters
+    ///
+    ///  * `name`:
+    ///      The name of the dataset (core) to update.
+    ///
+    ///  * `objects`:
+    ///      The objects to add, expressed in the reference spaces
+    ///      registered.
+    pub fn insert<O>(&mut self, name: &str, objects: &[O]) -> Result<(), Error>
+    where
+        O: storage::model::Indexable,
+    {
+        let spaces = self.spaces()?;
 
-        Self::check_exactly_one(&r, "cores", name)
+        storage::model::insert_into(self.core_mut(name)?, &spaces, objects)
     }
-}
 
-impl ironsea_index::Record<String> for Space {
-    fn key(&self) -> String {
-        self.name().clone()
+    /// Remove an object from a dataset within the datasets registered.
+    ///
+    /// # Parameters
+    ///
+    ///  * `name`:
+    ///      The name of the dataset (core) to update.
+    ///
+    ///  * `id`:
+    ///      The identifier of the object to remove.
+    pub fn remove(&mut self, name: &str, id: &str) -> Result<(), Error> {
+        let spaces = self.spaces()?;
+
+        self.core_mut(name)?.remove(&spaces, id)
+    }
+
+    /// Replace the shapes and attributes of an object from a dataset
+    /// within the datasets registered.
+    ///
+    /// # Parameters
+    ///
+    ///  * `name`:
+    ///      The name of the dataset (core) to update.
+    ///
+    ///  * `object`:
+    ///      The object to replace, identified by its properties, with
+    ///      its new attributes and shapes, expressed in the reference
+    ///      spaces registered.
+    pub fn replace<O>(&mut self, name: &str, object: &O) -> Result<(), Error>
+    where
+        O: storage::model::Indexable,
+    {
+        let spaces = self.spaces()?;
+
+        storage::model::replace_in(self.core_mut(name)?, &spaces, object)
+    }
+
+    fn core_mut(&mut self, name: &str) -> Result<&mut Core, Error> {
+        match self.cores.get_mut(name) {
+            None => Err(Error::UnknownCore(name.to_string())),
+            Some(core) => Ok(core),
+        }
+    }
+
+    // List of all the reference spaces registered.
+    fn spaces(&self) -> Result<Vec<Space>, Error> {
+        self.space_keys()
+            .iter()
+            .map(|key| self.space(key).cloned())
+            .collect()
     }
 }
 
-impl ironsea_index::Record<String> for Core {
+impl ironsea_index::Record<String> for Space {
     fn key(&self) -> String {
         self.name().clone()
     }
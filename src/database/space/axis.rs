@@ -3,6 +3,11 @@ use serde::Serialize;
 
 use super::coordinate::Coordinate;
 use super::position::Position;
+use crate::Error;
+
+// Largest difference with the closest integer of values accepted as
+// integers, to absorb rounding errors.
+const INTEGER_TOLERANCE: f64 = 1e-9;
 
 /// Mathematical set numbers.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
@@ -17,6 +22,33 @@ pub enum NumberSet {
     R,
 }
 
+impl NumberSet {
+    /// Whether only integers belong to the set.
+    pub fn is_integer(&self) -> bool {
+        match self {
+            NumberSet::N | NumberSet::Z => true,
+            NumberSet::Q | NumberSet::R => false,
+        }
+    }
+
+    /// Check if a value belongs to the set, up to rounding errors for
+    /// integers.
+    ///
+    /// # Parameters
+    ///
+    ///  * `value`:
+    ///      The value to check.
+    pub fn contains(&self, value: f64) -> bool {
+        let integer = (value - value.round()).abs() <= INTEGER_TOLERANCE;
+
+        match self {
+            NumberSet::N => integer && value.round() >= 0.0,
+            NumberSet::Z => integer,
+            NumberSet::Q | NumberSet::R => value.is_finite(),
+        }
+    }
+}
+
 impl From<&str> for NumberSet {
     fn from(set: &str) -> Self {
         match set {
@@ -33,7 +65,7 @@ impl From<&NumberSet> for String {
     fn from(set: &NumberSet) -> String {
         let s = match set {
             NumberSet::N => "N",
-            NumberSet::Z => "R",
+            NumberSet::Z => "Z",
             NumberSet::Q => "Q",
             NumberSet::R => "R",
         };
@@ -42,8 +74,26 @@ impl From<&NumberSet> for String {
     }
 }
 
+/// Handling of values outside of the valid range of an axis.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub enum OutOfBounds {
+    /// Replace the value with the closest valid one, on the boundary of
+    /// the range, or the closest integer on axes of integers.
+    #[default]
+    Clip,
+    /// Fail with an [`OutOfRange`](../enum.Error.html#variant.OutOfRange)
+    /// error, or a [`NotInSet`](../enum.Error.html#variant.NotInSet)
+    /// error for values which are not integers on axes of integers.
+    Reject,
+    /// Fail as with `Reject`, so that the value can be skipped. Queries
+    /// keep count of the positions skipped this way, see
+    /// [CoreQueryParameters](../struct.CoreQueryParameters.html).
+    Drop,
+}
+
 /// Definition of a fixed-precision, finite length axis.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(from = "StoredGraduation")]
 pub struct Graduation {
     /// Set of numbers allowed on the axis.
     pub set: NumberSet,
@@ -59,13 +109,129 @@ pub struct Graduation {
 }
 
 impl Graduation {
-    fn new(set: NumberSet, minimum: f64, maximum: f64, steps: u64) -> Result<Self, String> {
-        Ok(Graduation {
+    /// Instantiate a new graduation.
+    ///
+    /// # Parameters
+    ///
+    ///  * `set`:
+    ///     The valid numbers on the axis.
+    ///
+    ///  * `minimum`:
+    ///     The minimum value *included*.
+    ///
+    ///  * `maximum`:
+    ///     The maximum value *included*.
+    ///
+    ///  * `steps`:
+    ///     The number of steps, or discrete *ticks*.
+    ///
+    /// On axes of integers, the bounds as well as the length between
+    /// two *ticks* must be integers, so that every *tick* is part of the
+    /// set.
+    pub fn new(set: NumberSet, minimum: f64, maximum: f64, steps: u64) -> Result<Self, Error> {
+        let epsilon = (maximum - minimum) / (steps as f64);
+
+        for (name, value) in &[("minimum", minimum), ("maximum", maximum)] {
+            if !value.is_finite() {
+                return Err(Error::InvalidDefinition(format!(
+                    "Graduation {} {} is not a finite number",
+                    name, value
+                )));
+            }
+        }
+
+        Graduation {
             set,
             minimum,
             maximum,
             steps,
-            epsilon: (maximum - minimum) / (steps as f64),
+            epsilon,
+        }
+        .checked()
+    }
+
+    // Instantiate a graduation defined before the sets were enforced,
+    // when an axis of integers which does not fit its set is treated as
+    // an axis of real numbers, with a warning.
+    pub(crate) fn legacy(
+        set: NumberSet,
+        minimum: f64,
+        maximum: f64,
+        steps: u64,
+    ) -> Result<Self, Error> {
+        let integer = set.is_integer();
+
+        match Graduation::new(set, minimum, maximum, steps) {
+            Err(Error::InvalidDefinition(e)) if integer => {
+                warn!("{}, using R", e);
+                Graduation::new(NumberSet::R, minimum, maximum, steps)
+            }
+            graduation => graduation,
+        }
+    }
+
+    // Ensure every *tick* of an axis of integers is part of its set.
+    fn checked(self) -> Result<Self, Error> {
+        let set = &self.set;
+
+        if !set.is_integer() {
+            return Ok(self);
+        }
+
+        let integers = [self.minimum, self.maximum, self.epsilon]
+            .iter()
+            .all(|v| set.contains(*v));
+
+        if !integers || self.epsilon < 1.0 {
+            return Err(Error::InvalidDefinition(format!(
+                "Graduation of {} steps between {} and {} has ticks outside of {}",
+                self.steps,
+                self.minimum,
+                self.maximum,
+                String::from(set)
+            )));
+        }
+
+        // Remove rounding errors from the values checked to be integers.
+        Ok(Graduation {
+            minimum: self.minimum.round(),
+            maximum: self.maximum.round(),
+            epsilon: self.epsilon.round(),
+            ..self
+        })
+    }
+}
+
+// Layout of `Graduation` in index files, which is checked as it is
+// read, as index files might predate the enforcement of the sets.
+#[derive(Deserialize)]
+struct StoredGraduation {
+    set: NumberSet,
+    minimum: f64,
+    maximum: f64,
+    steps: u64,
+    epsilon: f64,
+}
+
+impl From<StoredGraduation> for Graduation {
+    fn from(stored: StoredGraduation) -> Self {
+        let graduation = Graduation {
+            set: stored.set,
+            minimum: stored.minimum,
+            maximum: stored.maximum,
+            steps: stored.steps,
+            epsilon: stored.epsilon,
+        };
+
+        // Axes of integers which do not fit their set are read as axes
+        // of real numbers, as they were before the sets were enforced.
+        graduation.clone().checked().unwrap_or_else(|e| {
+            warn!("{}, using R", e);
+
+            Graduation {
+                set: NumberSet::R,
+                ..graduation
+            }
         })
     }
 }
@@ -171,16 +337,26 @@ impl Axis {
         minimum: f64,
         maximum: f64,
         steps: u64,
-    ) -> Result<Self, String> {
+    ) -> Result<Self, Error> {
+        let graduation = Graduation::new(set, minimum, maximum, steps)?;
+
+        Ok(Axis::with_graduation(unit, unit_vector, graduation))
+    }
+
+    // Instantiate an axis of an already checked graduation.
+    pub(crate) fn with_graduation(
+        unit: &str,
+        unit_vector: Vec<f64>,
+        graduation: Graduation,
+    ) -> Self {
         // Convert to Position, and ensure it is a unit vector.
         let unit_vector = Position::from(unit_vector).unit();
-        let graduation = Graduation::new(set, minimum, maximum, steps)?;
 
-        Ok(Axis {
+        Axis {
             measurement_unit: unit.into(),
             graduation,
             unit_vector,
-        })
+        }
     }
 
     /// The unit, as in [SI unit] used on this axis, more specifically,
@@ -218,6 +394,11 @@ impl Axis {
     /// The resulting coordinate is expressed as an encoded coordinate
     /// on this axis.
     ///
+    /// This is an orthogonal projection, which matches the coordinate of
+    /// the position only when the axes of the base are orthogonal, see
+    /// [CoordinateSystem::rebase](struct.CoordinateSystem.html#method.rebase)
+    /// otherwise.
+    ///
     /// # Parameters
     ///
     ///  * `position`:
@@ -225,40 +406,34 @@ impl Axis {
     ///      Universe coordinates, but with any translations already
     ///      applied so that the origin of the vector is the origin of
     ///      this axis.
-    pub fn project_in(&self, position: &Position) -> Result<Coordinate, String> {
-        let max = self.graduation.maximum;
-        let min = self.graduation.minimum;
-
-        let d = position.dot_product(&self.unit_vector);
+    ///
+    ///  * `bounds`:
+    ///      How to handle positions outside of the valid range.
+    pub fn project_in(
+        &self,
+        position: &Position,
+        bounds: OutOfBounds,
+    ) -> Result<Coordinate, Error> {
+        self.length_in(position.dot_product(&self.unit_vector), bounds)
+    }
 
+    /// Convert a length along the unit vector of this axis into a
+    /// coordinate.
+    ///
+    /// The resulting coordinate is expressed as an encoded coordinate
+    /// on this axis.
+    ///
+    /// # Parameters
+    ///
+    ///  * `length`:
+    ///      The length from the origin of this axis, expressed in
+    ///      Universe units.
+    ///
+    ///  * `bounds`:
+    ///      How to handle lengths outside of the valid range.
+    pub fn length_in(&self, length: f64, bounds: OutOfBounds) -> Result<Coordinate, Error> {
         // Apply Unit scaling
-        let mut d = d / self.measurement_unit.factor();
-
-        // Ensure it is within allowed range: Upper bound.
-        if d > max {
-            // FIXME: Should we generate an error instead?
-            //return Err(format!(
-            //    "project_in: position out of bounds: {} >= {}",
-            //    d, max
-            //));
-
-            // FIXME: For now, just clip.
-            d = max;
-        }
-
-        // Ensure it is within allowed range: Lower bound.
-        if d < min {
-            // FIXME: Should we generate an error instead?
-            //return Err(format!(
-            //    "project_in: position out of bounds: {} < {}",
-            //    d, min
-            //));
-
-            // FIXME: For now, just clip.
-            d = min;
-        }
-
-        self.encode(d)
+        self.encode_bounded(length / self.measurement_unit.factor(), bounds)
     }
 
     /// Convert an encoded coordinate expressed on this axis into a
@@ -274,36 +449,128 @@ impl Axis {
     ///  * `coordinate`:
     ///      The coordinate to project out of this axis. It must be
     ///      defined as an encoded coordinate on this axis.
-    pub fn project_out(&self, coordinate: &Coordinate) -> Result<Position, String> {
+    pub fn project_out(&self, coordinate: &Coordinate) -> Result<Position, Error> {
         let d = self.decode(coordinate)?;
 
+        Ok(&self.unit_vector * self.length_out(d))
+    }
+
+    /// Convert a value on this axis into a length along its unit
+    /// vector, expressed in Universe units.
+    ///
+    /// # Parameters
+    ///
+    ///  * `value`:
+    ///      The decoded value on this axis.
+    pub fn length_out(&self, value: f64) -> f64 {
         // Apply Unit scaling
-        let d = d * self.measurement_unit.factor();
+        value * self.measurement_unit.factor()
+    }
 
-        Ok(&self.unit_vector * d)
+    /// Project a vector on this axis.
+    ///
+    /// Contrary to [project_in](#method.project_in), the result is
+    /// neither clipped to the valid range of the axis, nor rounded, and
+    /// is expressed as a length in *ticks* on this axis.
+    ///
+    /// # Parameters
+    ///
+    ///  * `vector`:
+    ///      The vector to project on this axis, expressed in Universe
+    ///      coordinates.
+    pub fn project_vector_in(&self, vector: &Position) -> f64 {
+        self.vector_length_in(vector.dot_product(&self.unit_vector))
+    }
+
+    /// Convert a length along the unit vector of this axis into a
+    /// number of *ticks*, which is neither clipped nor rounded.
+    ///
+    /// # Parameters
+    ///
+    ///  * `length`:
+    ///      The length to convert, expressed in Universe units.
+    pub fn vector_length_in(&self, length: f64) -> f64 {
+        length / self.measurement_unit.factor() / self.graduation.epsilon
+    }
+
+    /// Convert a length expressed in *ticks* on this axis into a
+    /// vector.
+    ///
+    /// The resulting vector is expressed in the Universe reference
+    /// space.
+    ///
+    /// # Parameters
+    ///
+    ///  * `length`:
+    ///      The length to project out of this axis, as a number of
+    ///      *ticks*.
+    pub fn project_vector_out(&self, length: f64) -> Position {
+        let d = length * self.graduation.epsilon * self.measurement_unit.factor();
+
+        &self.unit_vector * d
     }
 
     /// Encode a coordinate expressed on this axis.
     ///
+    /// On axes of integers, the coordinate must be an integer, up to
+    /// rounding errors.
+    ///
+    /// # Parameters
+    ///
+    ///  * `val`:
+    ///      The coordinate to encode. It must be defined as a
+    ///      coordinate on this axis.
+    pub fn encode(&self, val: f64) -> Result<Coordinate, Error> {
+        self.encode_bounded(val, OutOfBounds::Reject)
+    }
+
+    /// Encode a coordinate expressed on this axis, which might be
+    /// outside of its valid range, or not part of its set of numbers.
+    ///
     /// # Parameters
     ///
     ///  * `val`:
     ///      The coordinate to encode. It must be defined as a
     ///      coordinate on this axis.
-    pub fn encode(&self, val: f64) -> Result<Coordinate, String> {
+    ///
+    ///  * `bounds`:
+    ///      How to handle values which are not valid.
+    pub fn encode_bounded(&self, val: f64, bounds: OutOfBounds) -> Result<Coordinate, Error> {
         let max = self.graduation.maximum;
         let min = self.graduation.minimum;
+        let set = &self.graduation.set;
 
         let mut d = val;
 
-        // Ensure it is within allowed range: Upper bound.
-        if d > max {
-            return Err(format!("encode: position out of bounds: {} >= {}", d, max));
+        if bounds == OutOfBounds::Clip {
+            d = d.max(min).min(max);
+            if set.is_integer() {
+                d = d.round();
+            }
+        }
+
+        // Ensure it is within allowed range.
+        if d > max || d < min {
+            return Err(Error::OutOfRange {
+                space: String::new(),
+                axis: 0,
+                value: d,
+                minimum: min,
+                maximum: max,
+            });
+        }
+
+        // Ensure it is part of the allowed numbers.
+        if !set.contains(d) {
+            return Err(Error::NotInSet {
+                value: d,
+                set: set.into(),
+            });
         }
 
-        // Ensure it is within allowed range: Lower bound.
-        if d < min {
-            return Err(format!("encode: position out of bounds: {} < {}", d, min));
+        if set.is_integer() {
+            // Remove rounding errors.
+            d = d.round();
         }
 
         // Shift range to zero.
@@ -323,7 +590,7 @@ impl Axis {
     ///  * `val`:
     ///      The coordinate to decode. It must be defined as an encoded
     ///      coordinate on this axis.
-    pub fn decode(&self, val: &Coordinate) -> Result<f64, String> {
+    pub fn decode(&self, val: &Coordinate) -> Result<f64, Error> {
         let max = self.graduation.maximum;
         let min = self.graduation.minimum;
 
@@ -336,14 +603,20 @@ impl Axis {
         // Shift range back to origin.
         d += self.graduation.minimum;
 
-        // Ensure it is within allowed range: Upper bound.
-        if d > max {
-            return Err(format!("Decode: position out of bounds: {} >= {}", d, max));
+        if self.graduation.set.is_integer() {
+            // Remove rounding errors.
+            d = d.round();
         }
 
-        // Ensure it is within allowed range: Lower bound.
-        if d < min {
-            return Err(format!("Decode: position out of bounds: {} < {}", d, min));
+        // Ensure it is within allowed range.
+        if d > max || d < min {
+            return Err(Error::OutOfRange {
+                space: String::new(),
+                axis: 0,
+                value: d,
+                minimum: min,
+                maximum: max,
+            });
         }
 
         Ok(d)
@@ -2,22 +2,32 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use super::axis::Axis;
+use super::axis::Graduation;
+use super::axis::OutOfBounds;
 use super::coordinate::Coordinate;
+use super::displacement_field::DisplacementField;
 use super::position::Position;
-use super::MAX_K;
+use super::shape::Shape;
+use crate::Error;
 
 /// Kinds of space coordinate systems, or bases
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub enum CoordinateSystem {
     /// Absolute base, which allows to generate transformation between
     /// spaces by anchoring them relative to each other.
+    ///
+    /// Positions with any number of dimensions are accepted in this
+    /// base.
     Universe {
         /// A position which contains zeroes for all its coordinates,
-        /// but has a coordinate per dimensions of the highest
-        /// dimensions space referenced.
+        /// and no coordinates at all, as the number of dimensions of
+        /// the Universe is not fixed.
         origin: Position,
     },
     /// Base which needs only an affine transformation to map into the Universe.
+    ///
+    /// The axes do not need to be orthogonal, but must be linearly
+    /// independent.
     AffineSystem {
         /// Coordinates in Universe, expressed in f64, or decoded, and
         /// in the Universe number of dimensions.
@@ -26,6 +36,23 @@ pub enum CoordinateSystem {
         /// The definition of the coordinate system, through its axes.
         axes: Vec<Axis>,
     },
+    /// Base which needs a non-linear transformation to map into the
+    /// Universe, such as the result of a non-linear registration.
+    ///
+    /// Positions are mapped into the Universe by `system`, then moved by
+    /// the displacement found at that location in `forward`. Universe
+    /// positions are moved by the displacement found at their location
+    /// in `inverse`, then rebased in `system`.
+    DeformedSystem {
+        /// The affine part of the transformation.
+        system: Box<CoordinateSystem>,
+
+        /// Displacements from `system` into the Universe.
+        forward: DisplacementField,
+
+        /// Displacements from the Universe into `system`.
+        inverse: DisplacementField,
+    },
 }
 
 impl CoordinateSystem {
@@ -46,11 +73,154 @@ impl CoordinateSystem {
         }
     }
 
+    /// Instantiate a coordinate system from an affine transformation.
+    ///
+    /// Rotations, scalings and shears are supported, as long as the
+    /// transformation is invertible. The values on each axis are
+    /// lengths along the matching column of `matrix`, so the graduations
+    /// are scaled by the norm of that column, which keeps the encoded
+    /// positions the same as with the given graduations.
+    ///
+    /// # Parameters
+    ///
+    ///  * `matrix`:
+    ///      The transformation from positions in this base into
+    ///      positions in the Universe, as a row-major matrix in
+    ///      homogeneous coordinates. The last row, `[0, …, 0, 1]`, may be
+    ///      omitted.
+    ///
+    ///  * `unit`:
+    ///      SI Unit used by `matrix`, for positions both in this base and
+    ///      in the Universe. See
+    ///      [Axis::measurement_unit](struct.Axis.html#method.measurement_unit).
+    ///
+    ///  * `graduations`:
+    ///      The valid values on each axis, as coordinates given to
+    ///      `matrix`.
+    pub fn from_matrix(
+        matrix: &[Vec<f64>],
+        unit: &str,
+        graduations: Vec<Graduation>,
+    ) -> Result<Self, Error> {
+        let n = graduations.len();
+
+        if n == 0
+            || (matrix.len() != n && matrix.len() != n + 1)
+            || matrix.iter().any(|row| row.len() != n + 1)
+        {
+            return Err(Error::InvalidDefinition(format!(
+                "Expected a {}x{} affine transformation matrix",
+                n + 1,
+                n + 1
+            )));
+        }
+
+        if matrix.iter().flatten().any(|v| !v.is_finite()) {
+            return Err(Error::InvalidDefinition(format!(
+                "Affine transformation matrix with non-finite values: {:?}",
+                matrix
+            )));
+        }
+
+        if let Some(last) = matrix.get(n) {
+            if last[n] != 1.0 || last[..n].iter().any(|v| *v != 0.0) {
+                return Err(Error::InvalidDefinition(format!(
+                    "Invalid last row of affine transformation matrix: {:?}",
+                    last
+                )));
+            }
+        }
+
+        let columns = (0..n)
+            .map(|k| matrix[..n].iter().map(|row| row[k]).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        let determinant = Shape::determinant(
+            &columns
+                .iter()
+                .map(|c| c.clone().into())
+                .collect::<Vec<Position>>(),
+        );
+        if determinant == 0.0 || !determinant.is_finite() {
+            return Err(Error::InvalidDefinition(
+                "The affine transformation is not invertible".to_string(),
+            ));
+        }
+
+        let mut axes = Vec::with_capacity(n);
+        for (column, g) in columns.into_iter().zip(graduations) {
+            let norm = Position::from(column.clone()).norm();
+            axes.push(Axis::new(
+                unit,
+                column,
+                g.set,
+                g.minimum * norm,
+                g.maximum * norm,
+                g.steps,
+            )?);
+        }
+
+        // All the axes share the unit of the translation vector.
+        let origin = matrix[..n]
+            .iter()
+            .map(|row| axes[0].length_out(row[n]))
+            .collect::<Vec<_>>();
+
+        Ok(CoordinateSystem::new(origin, axes))
+    }
+
+    /// Instantiate a coordinate system deformed by a displacement field.
+    ///
+    /// # Parameters
+    ///
+    ///  * `system`:
+    ///      The affine part of the transformation into the Universe.
+    ///
+    ///  * `forward`:
+    ///      The displacements applied to the positions mapped into the
+    ///      Universe by `system`.
+    ///
+    ///  * `inverse`:
+    ///      The displacements applied to Universe positions, before they
+    ///      are rebased in `system`. See
+    ///      [DisplacementField::inverse](struct.DisplacementField.html#method.inverse)
+    ///      to compute it from `forward`.
+    pub fn deformed(
+        system: CoordinateSystem,
+        forward: DisplacementField,
+        inverse: DisplacementField,
+    ) -> Result<Self, Error> {
+        let dimensions = match &system {
+            CoordinateSystem::AffineSystem { origin, .. } => origin.dimensions(),
+            _ => {
+                return Err(Error::InvalidDefinition(
+                    "Only affine coordinate systems can be deformed".to_string(),
+                ))
+            }
+        };
+
+        for field in &[&forward, &inverse] {
+            if field.origin().len() != dimensions {
+                return Err(Error::DimensionMismatch {
+                    expected: dimensions,
+                    found: field.origin().len(),
+                });
+            }
+        }
+
+        Ok(CoordinateSystem::DeformedSystem {
+            system: Box::new(system),
+            forward,
+            inverse,
+        })
+    }
+
     /// The translation vector, in Universe coordinates.
     pub fn origin(&self) -> &Position {
         match self {
             CoordinateSystem::Universe { origin, .. } => origin,
             CoordinateSystem::AffineSystem { origin, .. } => origin,
+            CoordinateSystem::DeformedSystem { system, .. } => system.origin(),
         }
     }
 
@@ -62,21 +232,35 @@ impl CoordinateSystem {
                 unimplemented!()
             }
             CoordinateSystem::AffineSystem { axes, .. } => axes,
+            CoordinateSystem::DeformedSystem { system, .. } => system.axes(),
+        }
+    }
+
+    /// The displacement fields of this base, as `(forward, inverse)`,
+    /// when it is deformed.
+    pub fn deformation(&self) -> Option<(&DisplacementField, &DisplacementField)> {
+        match self {
+            CoordinateSystem::DeformedSystem {
+                forward, inverse, ..
+            } => Some((forward, inverse)),
+            _ => None,
         }
     }
 
     /// The number of dimensions of positions within this base.
+    ///
+    /// This is `0` for the Universe, which accepts positions with any
+    /// number of dimensions.
     pub fn dimensions(&self) -> usize {
         match self {
-            CoordinateSystem::Universe { .. } => MAX_K,
+            CoordinateSystem::Universe { .. } => 0,
             CoordinateSystem::AffineSystem { axes, .. } => axes.len(),
+            CoordinateSystem::DeformedSystem { system, .. } => system.dimensions(),
         }
     }
 
     /// The smallest bounding box containing the whole base, expressed
     /// in decoded Universe coordinates.
-    ///
-    // FIXME: Add the translation vector!
     pub fn bounding_box(&self) -> (Position, Position) {
         let mut low = Vec::with_capacity(self.dimensions());
         let mut high = Vec::with_capacity(self.dimensions());
@@ -88,31 +272,110 @@ impl CoordinateSystem {
                     high.push(f64::MAX);
                 }
             }
-            CoordinateSystem::AffineSystem { axes, .. } => {
+            CoordinateSystem::AffineSystem { origin, axes } => {
+                low = origin.into();
+                high = low.clone();
+
+                // The base is a parallelotope, so each axis extends the
+                // box independently of the others.
                 for a in axes {
-                    low.push(a.graduation().minimum);
-                    high.push(a.graduation().maximum);
+                    let g = a.graduation();
+                    let minimum = a.unit_vector() * a.length_out(g.minimum);
+                    let maximum = a.unit_vector() * a.length_out(g.maximum);
+
+                    for (k, (l, h)) in low.iter_mut().zip(high.iter_mut()).enumerate() {
+                        let (x, y) = (minimum[k].f64(), maximum[k].f64());
+                        *l += x.min(y);
+                        *h += x.max(y);
+                    }
                 }
             }
+            CoordinateSystem::DeformedSystem {
+                system, forward, ..
+            } => {
+                let (l, h) = system.bounding_box();
+
+                // Positions are moved at most by the extreme displacements.
+                let (shift_low, shift_high) = forward.bounds();
+                low = Vec::<f64>::from(l)
+                    .iter()
+                    .zip(shift_low)
+                    .map(|(c, s)| c + s)
+                    .collect();
+                high = Vec::<f64>::from(h)
+                    .iter()
+                    .zip(shift_high)
+                    .map(|(c, s)| c + s)
+                    .collect();
+            }
         }
 
         (low.into(), high.into())
     }
 
-    /// The volume of this space.
-    ///
-    // FIXME: This assumes orthogonal spaces!
+    /// The volume of this space, expressed in the units of its axes.
     pub fn volume(&self) -> f64 {
-        let (low, high) = self.bounding_box();
-        let difference: Vec<_> = (high - low).into();
+        match self {
+            CoordinateSystem::Universe { .. } => {
+                let (low, high) = self.bounding_box();
+                let difference: Vec<f64> = (high - low).into();
 
-        let mut volume = 1.0;
+                difference.iter().product()
+            }
+            CoordinateSystem::AffineSystem { axes, .. } => {
+                let lengths = axes
+                    .iter()
+                    .map(|a| a.graduation().maximum - a.graduation().minimum)
+                    .product::<f64>();
 
-        for l in difference {
-            volume *= l;
+                // Skewed axes span a smaller volume than orthogonal ones.
+                let skew = Shape::determinant(&CoordinateSystem::gram(axes)).abs();
+
+                lengths * skew.sqrt()
+            }
+            CoordinateSystem::DeformedSystem { system, .. } => system.volume(),
         }
+    }
+
+    // Scalar products of the unit vectors of the axes, two by two.
+    fn gram(axes: &[Axis]) -> Vec<Position> {
+        axes.iter()
+            .map(|a| {
+                axes.iter()
+                    .map(|b| a.unit_vector().dot_product(b.unit_vector()))
+                    .collect()
+            })
+            .collect()
+    }
+
+    // Express `vector` as a linear combination of the unit vectors of the
+    // axes. When the axes do not span the whole Universe, `vector` is
+    // projected on the subspace they span first.
+    fn components(axes: &[Axis], vector: &Position) -> Result<Vec<f64>, Error> {
+        let zero: Position = vec![0.0; axes.len()].into();
 
-        volume
+        let components = if axes.len() == vector.dimensions() {
+            let vectors = axes
+                .iter()
+                .map(|a| a.unit_vector().clone())
+                .collect::<Vec<_>>();
+
+            Shape::coefficients(&zero, &vectors, vector)
+        } else {
+            let projected = axes
+                .iter()
+                .map(|a| a.unit_vector().dot_product(vector))
+                .collect();
+
+            Shape::coefficients(&zero, &CoordinateSystem::gram(axes), &projected)
+        };
+
+        match components {
+            Some(components) => Ok(components),
+            None => Err(Error::InvalidDefinition(
+                "The axes of the coordinate system are not linearly independent".to_string(),
+            )),
+        }
     }
 
     /// Rebase a position in this coordinate space.
@@ -125,30 +388,48 @@ impl CoordinateSystem {
     ///  * `position`:
     ///      expressed in decoded Universe coordinates.
     ///
+    ///  * `bounds`:
+    ///      How to handle positions outside of the valid range of the
+    ///      axes.
+    ///
     /// # Return value
     ///
     /// The encoded coordinates within this coordinate system.
-    pub fn rebase(&self, position: &Position) -> Result<Position, String> {
+    pub fn rebase(&self, position: &Position, bounds: OutOfBounds) -> Result<Position, Error> {
         match self {
-            CoordinateSystem::Universe { origin } => {
+            CoordinateSystem::Universe { .. } => {
                 // Ensure the coordinates are encoded into F64 variants of
-                // coordinates by forcing an addition to the origin position
-                // which is expressed as F64 variants. The addition will convert
-                // to F64 automatically.
-                Ok(origin + position)
+                // coordinates, whatever the number of dimensions.
+                Ok((0..position.dimensions())
+                    .map(|k| position[k].f64())
+                    .collect())
             }
             CoordinateSystem::AffineSystem { origin, axes } => {
-                let dimensions = axes.len();
+                if position.dimensions() != origin.dimensions() {
+                    return Err(Error::DimensionMismatch {
+                        expected: origin.dimensions(),
+                        found: position.dimensions(),
+                    });
+                }
+
                 let translated = position - origin;
-                let mut rebased = Vec::with_capacity(dimensions);
+                let lengths = CoordinateSystem::components(axes, &translated)?;
+                let mut rebased = Vec::with_capacity(axes.len());
 
-                for a in axes.iter().take(dimensions) {
-                    let c = a.project_in(&translated)?;
+                for (k, (a, length)) in axes.iter().zip(lengths).enumerate() {
+                    let c = a.length_in(length, bounds).map_err(|e| e.on_axis(k))?;
                     rebased.push(c);
                 }
 
                 Ok(rebased.into())
             }
+            CoordinateSystem::DeformedSystem {
+                system, inverse, ..
+            } => {
+                let displacement = inverse.displacement(position)?;
+
+                system.rebase(&(position + &displacement), bounds)
+            }
         }
     }
 
@@ -162,16 +443,18 @@ impl CoordinateSystem {
     /// # Return value
     ///
     /// The position expressed in Universe decoded coordinates.
-    pub fn absolute_position(&self, position: &Position) -> Result<Position, String> {
+    pub fn absolute_position(&self, position: &Position) -> Result<Position, Error> {
         match self {
-            CoordinateSystem::Universe { origin } => {
+            CoordinateSystem::Universe { .. } => {
                 // Ensure the coordinates are encoded into F64 variants of
-                // coordinates by forcing an addition to the origin position
-                // which is expressed as F64 variants. The addition will convert
-                // to F64 automatically.
-                Ok(origin + position)
+                // coordinates, whatever the number of dimensions.
+                Ok((0..position.dimensions())
+                    .map(|k| position[k].f64())
+                    .collect())
             }
             CoordinateSystem::AffineSystem { axes, .. } => {
+                self.check_dimensions(position.dimensions())?;
+
                 // Start from the base origin.
                 let mut rebased = self.origin().clone();
 
@@ -183,6 +466,127 @@ impl CoordinateSystem {
 
                 Ok(rebased)
             }
+            CoordinateSystem::DeformedSystem {
+                system, forward, ..
+            } => {
+                let mut absolute = system.absolute_position(position)?;
+                absolute += forward.displacement(&absolute)?;
+
+                Ok(absolute)
+            }
+        }
+    }
+
+    /// Rebase a vector in this coordinate space.
+    ///
+    /// Vectors, for example the semi-axes of a shape, are not anchored
+    /// to the origin of the coordinate system. Their coordinates are
+    /// expressed as lengths in *ticks* along each axis, which are
+    /// neither rounded nor clipped.
+    ///
+    /// As vectors have no location, only the affine part of the
+    /// transformation of a deformed base is applied to them.
+    ///
+    /// # Parameters
+    ///
+    ///  * `vector`:
+    ///      expressed in decoded Universe coordinates.
+    pub fn rebase_vector(&self, vector: &Position) -> Result<Position, Error> {
+        match self {
+            CoordinateSystem::Universe { .. } => {
+                Ok((0..vector.dimensions()).map(|k| vector[k].f64()).collect())
+            }
+            CoordinateSystem::AffineSystem { origin, axes } => {
+                if vector.dimensions() != origin.dimensions() {
+                    return Err(Error::DimensionMismatch {
+                        expected: origin.dimensions(),
+                        found: vector.dimensions(),
+                    });
+                }
+
+                let lengths = CoordinateSystem::components(axes, vector)?;
+
+                Ok(axes
+                    .iter()
+                    .zip(lengths)
+                    .map(|(a, length)| a.vector_length_in(length))
+                    .collect())
+            }
+            CoordinateSystem::DeformedSystem { system, .. } => system.rebase_vector(vector),
+        }
+    }
+
+    /// Express a vector in the Universe coordinate system.
+    ///
+    /// As vectors have no location, only the affine part of the
+    /// transformation of a deformed base is applied to them.
+    ///
+    /// # Parameters
+    ///
+    ///  * `vector`:
+    ///      expressed as lengths in *ticks* along each axis of the
+    ///      coordinate system.
+    pub fn absolute_vector(&self, vector: &Position) -> Result<Position, Error> {
+        match self {
+            CoordinateSystem::Universe { .. } => {
+                Ok((0..vector.dimensions()).map(|k| vector[k].f64()).collect())
+            }
+            CoordinateSystem::AffineSystem { origin, axes } => {
+                self.check_dimensions(vector.dimensions())?;
+
+                let mut absolute: Position = vec![0.0; origin.dimensions()].into();
+                for k in 0..axes.len() {
+                    absolute += axes[k].project_vector_out(vector[k].f64());
+                }
+
+                Ok(absolute)
+            }
+            CoordinateSystem::DeformedSystem { system, .. } => system.absolute_vector(vector),
+        }
+    }
+
+    /// Encode a vector expressed in the current coordinate system.
+    ///
+    /// The resulting coordinates are lengths in *ticks* along each
+    /// axis, which are neither rounded nor clipped.
+    ///
+    /// # Parameters
+    ///
+    ///  * `vector`:
+    ///      expressed in the current coordinate system.
+    pub fn encode_vector(&self, vector: &[f64]) -> Result<Position, Error> {
+        self.check_dimensions(vector.len())?;
+
+        match self {
+            CoordinateSystem::Universe { .. } => Ok(vector.to_vec().into()),
+            CoordinateSystem::AffineSystem { axes, .. } => Ok(axes
+                .iter()
+                .zip(vector)
+                .map(|(a, v)| v / a.graduation().epsilon)
+                .collect()),
+            CoordinateSystem::DeformedSystem { system, .. } => system.encode_vector(vector),
+        }
+    }
+
+    /// Decode a vector expressed as lengths in *ticks* in the current
+    /// coordinate system.
+    ///
+    /// # Parameters
+    ///
+    ///  * `vector`:
+    ///      expressed in the current coordinate system, as encoded
+    ///      lengths.
+    pub fn decode_vector(&self, vector: &Position) -> Result<Vec<f64>, Error> {
+        self.check_dimensions(vector.dimensions())?;
+
+        match self {
+            CoordinateSystem::Universe { .. } => Ok(vector.into()),
+            CoordinateSystem::AffineSystem { axes, .. } => Ok(axes
+                .iter()
+                .enumerate()
+                .map(|(k, a)| vector[k].f64() * a.graduation().epsilon)
+                .collect()),
+            CoordinateSystem::DeformedSystem { system, .. } => system.decode_vector(vector),
         }
     }
 
@@ -199,22 +603,44 @@ impl CoordinateSystem {
     /// # Return value
     ///
     /// The encoded coordinates within this coordinate system.
-    pub fn encode(&self, position: &[f64]) -> Result<Position, String> {
+    pub fn encode(&self, position: &[f64]) -> Result<Position, Error> {
+        self.encode_bounded(position, OutOfBounds::Reject)
+    }
+
+    /// Encode a position expressed in the current coordinate system,
+    /// which might be outside of the valid range of the axes.
+    ///
+    /// # Parameters
+    ///
+    ///  * `position`:
+    ///      expressed in the current coordinate system.
+    ///
+    ///  * `bounds`:
+    ///      How to handle positions outside of the valid range of the
+    ///      axes.
+    pub fn encode_bounded(&self, position: &[f64], bounds: OutOfBounds) -> Result<Position, Error> {
         let mut encoded = vec![];
 
+        self.check_dimensions(position.len())?;
+
         match self {
             CoordinateSystem::Universe { .. } => {
-                assert_eq!(position.len(), MAX_K);
                 for c in position {
                     encoded.push(Coordinate::CoordinateF64(*c));
                 }
             }
             CoordinateSystem::AffineSystem { axes, .. } => {
-                assert_eq!(position.len(), axes.len());
                 for k in 0..axes.len() {
-                    encoded.push(axes[k].encode(position[k])?);
+                    encoded.push(
+                        axes[k]
+                            .encode_bounded(position[k], bounds)
+                            .map_err(|e| e.on_axis(k))?,
+                    );
                 }
             }
+            CoordinateSystem::DeformedSystem { system, .. } => {
+                return system.encode_bounded(position, bounds)
+            }
         };
 
         Ok(encoded.into())
@@ -234,24 +660,36 @@ impl CoordinateSystem {
     /// # Return value
     ///
     /// The decoded coordinates within this coordinate system.
-    pub fn decode(&self, position: &Position) -> Result<Vec<f64>, String> {
+    pub fn decode(&self, position: &Position) -> Result<Vec<f64>, Error> {
         let mut decoded = vec![];
 
+        self.check_dimensions(position.dimensions())?;
+
         match self {
             CoordinateSystem::Universe { .. } => {
-                assert_eq!(position.dimensions(), MAX_K);
                 for c in 0..position.dimensions() {
                     decoded.push(position[c].into());
                 }
             }
             CoordinateSystem::AffineSystem { axes, .. } => {
-                assert_eq!(position.dimensions(), axes.len());
                 for k in 0..axes.len() {
-                    decoded.push(axes[k].decode(&position[k])?);
+                    decoded.push(axes[k].decode(&position[k]).map_err(|e| e.on_axis(k))?);
                 }
             }
+            CoordinateSystem::DeformedSystem { system, .. } => return system.decode(position),
         };
 
         Ok(decoded)
     }
+
+    fn check_dimensions(&self, dimensions: usize) -> Result<(), Error> {
+        match self {
+            CoordinateSystem::Universe { .. } => Ok(()),
+            _ if dimensions == self.dimensions() => Ok(()),
+            _ => Err(Error::DimensionMismatch {
+                expected: self.dimensions(),
+                found: dimensions,
+            }),
+        }
+    }
 }
@@ -0,0 +1,242 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::position::Position;
+use crate::Error;
+
+// Maximum number of iterations used to invert a displacement.
+const INVERSE_ITERATIONS: usize = 64;
+
+// Precision of the inverted displacements, relative to the spacing
+// between samples.
+const INVERSE_TOLERANCE: f64 = 1e-9;
+
+/// Displacements sampled on a regular grid of the Universe.
+///
+/// The grid is aligned with the axes of the Universe. Between samples,
+/// the displacements are interpolated linearly along each axis, which
+/// is a trilinear interpolation for three dimensions. Outside of the
+/// grid, the displacement found on its closest border is used.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct DisplacementField {
+    origin: Vec<f64>,
+    spacing: Vec<f64>,
+    dimensions: Vec<usize>,
+    // The vectors of all the samples, one after the other, with the
+    // first axis varying fastest.
+    displacements: Vec<f64>,
+}
+
+impl DisplacementField {
+    /// Instantiate a new displacement field.
+    ///
+    /// # Parameters
+    ///
+    ///  * `origin`:
+    ///      Position of the first sample, in Universe coordinates.
+    ///
+    ///  * `spacing`:
+    ///      Distance between two consecutive samples, along each axis
+    ///      of the Universe.
+    ///
+    ///  * `dimensions`:
+    ///      Number of samples along each axis of the Universe.
+    ///
+    ///  * `displacements`:
+    ///      The displacement vectors of all the samples, one after the
+    ///      other, with the first axis varying fastest.
+    pub fn new(
+        origin: Vec<f64>,
+        spacing: Vec<f64>,
+        dimensions: Vec<usize>,
+        displacements: Vec<f64>,
+    ) -> Result<Self, Error> {
+        let n = origin.len();
+
+        if n == 0 || spacing.len() != n || dimensions.len() != n {
+            return Err(Error::InvalidDefinition(format!(
+                "Displacement field with inconsistent dimensions: origin {}, spacing {}, samples {}",
+                n,
+                spacing.len(),
+                dimensions.len()
+            )));
+        }
+
+        if spacing.iter().any(|s| *s <= 0.0 || !s.is_finite()) {
+            return Err(Error::InvalidDefinition(format!(
+                "Invalid displacement field spacing: {:?}",
+                spacing
+            )));
+        }
+
+        let samples = dimensions.iter().product::<usize>();
+        if samples == 0 || displacements.len() != samples * n {
+            return Err(Error::InvalidDefinition(format!(
+                "Expected {} displacement components, found {}",
+                samples * n,
+                displacements.len()
+            )));
+        }
+
+        Ok(DisplacementField {
+            origin,
+            spacing,
+            dimensions,
+            displacements,
+        })
+    }
+
+    /// Position of the first sample, in Universe coordinates.
+    pub fn origin(&self) -> &[f64] {
+        &self.origin
+    }
+
+    /// Distance between two consecutive samples, along each axis of the
+    /// Universe.
+    pub fn spacing(&self) -> &[f64] {
+        &self.spacing
+    }
+
+    /// Number of samples along each axis of the Universe.
+    pub fn dimensions(&self) -> &[usize] {
+        &self.dimensions
+    }
+
+    /// The displacement vectors of all the samples, one after the other,
+    /// with the first axis varying fastest.
+    pub fn displacements(&self) -> &[f64] {
+        &self.displacements
+    }
+
+    /// The smallest and largest displacements along each axis of the
+    /// Universe.
+    pub fn bounds(&self) -> (Vec<f64>, Vec<f64>) {
+        let n = self.origin.len();
+        let mut low = vec![f64::MAX; n];
+        let mut high = vec![f64::MIN; n];
+
+        for sample in self.displacements.chunks(n) {
+            for ((l, h), v) in low.iter_mut().zip(high.iter_mut()).zip(sample) {
+                *l = l.min(*v);
+                *h = h.max(*v);
+            }
+        }
+
+        (low, high)
+    }
+
+    /// Interpolate the displacement at a position.
+    ///
+    /// # Parameters
+    ///
+    ///  * `position`:
+    ///      expressed in decoded Universe coordinates.
+    pub fn displacement(&self, position: &Position) -> Result<Position, Error> {
+        if position.dimensions() != self.origin.len() {
+            return Err(Error::DimensionMismatch {
+                expected: self.origin.len(),
+                found: position.dimensions(),
+            });
+        }
+
+        Ok(self.interpolate(&Vec::<f64>::from(position)).into())
+    }
+
+    /// Compute the inverse of this displacement field, sampled on the
+    /// same grid.
+    ///
+    /// The inverse displacement of each sample is found by fixed-point
+    /// iteration, which converges as long as the displacements vary
+    /// less than the distance between the samples.
+    pub fn inverse(&self) -> Self {
+        let n = self.origin.len();
+        let mut displacements = Vec::with_capacity(self.displacements.len());
+
+        for sample in 0..self.displacements.len() / n {
+            // Position of the sample.
+            let mut rest = sample;
+            let target = (0..n)
+                .map(|k| {
+                    let i = rest % self.dimensions[k];
+                    rest /= self.dimensions[k];
+                    self.origin[k] + i as f64 * self.spacing[k]
+                })
+                .collect::<Vec<_>>();
+
+            // Look for the position which is displaced onto the sample.
+            let mut position = target.clone();
+            for _ in 0..INVERSE_ITERATIONS {
+                let displacement = self.interpolate(&position);
+                let mut converged = true;
+
+                for k in 0..n {
+                    let next = target[k] - displacement[k];
+                    if (next - position[k]).abs() > INVERSE_TOLERANCE * self.spacing[k] {
+                        converged = false;
+                    }
+                    position[k] = next;
+                }
+
+                if converged {
+                    break;
+                }
+            }
+
+            displacements.extend(position.iter().zip(&target).map(|(p, t)| p - t));
+        }
+
+        DisplacementField {
+            origin: self.origin.clone(),
+            spacing: self.spacing.clone(),
+            dimensions: self.dimensions.clone(),
+            displacements,
+        }
+    }
+
+    fn interpolate(&self, position: &[f64]) -> Vec<f64> {
+        let n = self.origin.len();
+
+        // Cell containing the position, and the offset of the position
+        // within that cell, from 0 to 1, along each axis.
+        let mut cell = Vec::with_capacity(n);
+        let mut offset = Vec::with_capacity(n);
+        for (k, p) in position.iter().enumerate() {
+            let last = (self.dimensions[k] - 1) as f64;
+            let x = ((p - self.origin[k]) / self.spacing[k]).clamp(0.0, last);
+            let c = x.floor().min((last - 1.0).max(0.0));
+
+            cell.push(c as usize);
+            offset.push(x - c);
+        }
+
+        // Weighted sum of the displacements at the corners of the cell.
+        let mut displacement = vec![0.0; n];
+        for corner in 0..(1usize << n) {
+            let mut weight = 1.0;
+            let mut index = 0;
+            let mut stride = 1;
+
+            for k in 0..n {
+                let i = if (corner >> k) & 1 == 1 {
+                    weight *= offset[k];
+                    (cell[k] + 1).min(self.dimensions[k] - 1)
+                } else {
+                    weight *= 1.0 - offset[k];
+                    cell[k]
+                };
+
+                index += i * stride;
+                stride *= self.dimensions[k];
+            }
+
+            if weight > 0.0 {
+                let sample = &self.displacements[index * n..(index + 1) * n];
+                for (d, v) in displacement.iter_mut().zip(sample) {
+                    *d += weight * v;
+                }
+            }
+        }
+
+        displacement
+    }
+}
@@ -5,8 +5,10 @@
 mod axis;
 mod coordinate;
 mod coordinate_system;
+mod displacement_field;
 mod position;
 mod shape;
+mod transform;
 
 #[cfg(test)]
 mod tests;
@@ -17,25 +19,23 @@ use serde::Serialize;
 pub use axis::Axis;
 pub use axis::Graduation;
 pub use axis::NumberSet;
+pub use axis::OutOfBounds;
 pub use coordinate::Coordinate;
 pub use coordinate_system::CoordinateSystem;
+pub use displacement_field::DisplacementField;
 pub use position::Position;
 pub use shape::Shape;
+pub use shape::Voxels;
+pub use transform::Conversion;
+pub use transform::Transform;
 
-// Maximum number of dimensions currently supported.
-//
-// **Note:** This will be deprecated as soon as support is implemented
-//           in some dependencies. This is linked to limitations in
-//           [ironsea_index_sfc_dbc].
-//
-// [ironsea_index_sfc_dbc]: https://github.com/epfl-dias/ironsea_index_sfc_dbc
-const MAX_K: usize = 3;
+use crate::Error;
 
 lazy_static! {
     static ref UNIVERSE: Space = Space {
         name: "Universe".into(),
         system: CoordinateSystem::Universe {
-            origin: [0f64; MAX_K].to_vec().into()
+            origin: Vec::<f64>::new().into()
         },
     };
 }
@@ -70,13 +70,20 @@ impl Space {
     /// Returns the Universe Space.
     ///
     /// This space contains all of the spaces, and allows us to connect
-    /// them between each others.
+    /// them between each others. It accepts positions with any number
+    /// of dimensions, so that spaces with the same number of dimensions
+    /// can be connected.
     pub fn universe() -> &'static Self {
         &UNIVERSE
     }
 
     /// Transform a position from space `from` into a position in space `to`.
     ///
+    /// The position is converted through the Universe, see
+    /// [`DataBase::conversion`](../struct.DataBase.html#method.conversion)
+    /// to use the transformations declared between spaces instead.
+    /// Positions outside of `to` are clipped to its boundaries.
+    ///
     /// # Parameters
     ///
     ///  * `position`:
@@ -87,8 +94,34 @@ impl Space {
     ///
     ///  *  `to`:
     ///      Target space in which `position` should be expressed.
-    pub fn change_base(position: &Position, from: &Space, to: &Space) -> Result<Position, String> {
-        to.rebase(&from.absolute_position(position)?)
+    pub fn change_base(position: &Position, from: &Space, to: &Space) -> Result<Position, Error> {
+        to.rebase(&from.absolute_position(position)?, OutOfBounds::Clip)
+    }
+
+    /// Transform a vector from space `from` into a vector in space `to`.
+    ///
+    /// Contrary to positions, vectors are not translated, nor clipped
+    /// to the valid range of the axes of `to`. They are not deformed
+    /// either, only the affine part of the transformation of a deformed
+    /// space is applied to them.
+    ///
+    /// # Parameters
+    ///
+    ///  * `vector`:
+    ///      Vector to transform, expressed as encoded lengths.
+    ///
+    ///  *  `from`:
+    ///      Space in which `vector` is defined.
+    ///
+    ///  *  `to`:
+    ///      Target space in which `vector` should be expressed.
+    pub fn change_base_vector(
+        vector: &Position,
+        from: &Space,
+        to: &Space,
+    ) -> Result<Position, Error> {
+        to.system
+            .rebase_vector(&from.system.absolute_vector(vector)?)
     }
 
     /// Id of the reference space.
@@ -101,11 +134,25 @@ impl Space {
         self.system.origin()
     }
 
+    /// Number of dimensions of the positions within the space.
+    ///
+    /// This is `0` for the Universe, which accepts positions with any
+    /// number of dimensions.
+    pub fn dimensions(&self) -> usize {
+        self.system.dimensions()
+    }
+
     /// Axes definition of the space.
     pub fn axes(&self) -> &Vec<Axis> {
         self.system.axes()
     }
 
+    /// Displacement fields of the space, as `(forward, inverse)`, when
+    /// it is related to the Universe by a non-linear transformation.
+    pub fn deformation(&self) -> Option<(&DisplacementField, &DisplacementField)> {
+        self.system.deformation()
+    }
+
     /// Returns the bounding box enclosing the whole space.
     pub fn bounding_box(&self) -> (Position, Position) {
         self.system.bounding_box()
@@ -118,13 +165,15 @@ impl Space {
 
     // `position` is expressed in the Universe, this return encoded
     // coordinates in the current space.
-    fn rebase(&self, position: &Position) -> Result<Position, String> {
-        self.system.rebase(position)
+    fn rebase(&self, position: &Position, bounds: OutOfBounds) -> Result<Position, Error> {
+        self.system
+            .rebase(position, bounds)
+            .map_err(|e| e.in_space(&self.name))
     }
 
     // The position is expressed in encoded coordinates in the current space,
     // return an absolute position in Universe.
-    fn absolute_position(&self, position: &Position) -> Result<Position, String> {
+    fn absolute_position(&self, position: &Position) -> Result<Position, Error> {
         self.system.absolute_position(position)
     }
 
@@ -139,8 +188,10 @@ impl Space {
     /// # Return value
     ///
     /// The decoded position within the space.
-    pub fn decode(&self, position: &Position) -> Result<Vec<f64>, String> {
-        self.system.decode(position)
+    pub fn decode(&self, position: &Position) -> Result<Vec<f64>, Error> {
+        self.system
+            .decode(position)
+            .map_err(|e| e.in_space(&self.name))
     }
 
     /// Encode a position expressed in the current space within the axes
@@ -154,7 +205,37 @@ impl Space {
     /// # Return value
     ///
     /// The encoded coordinates within the space.
-    pub fn encode(&self, position: &[f64]) -> Result<Position, String> {
-        self.system.encode(position)
+    pub fn encode(&self, position: &[f64]) -> Result<Position, Error> {
+        self.encode_bounded(position, OutOfBounds::Reject)
+    }
+
+    // Encode a position expressed in the current space, which might be
+    // outside of the valid range of the axes.
+    fn encode_bounded(&self, position: &[f64], bounds: OutOfBounds) -> Result<Position, Error> {
+        self.system
+            .encode_bounded(position, bounds)
+            .map_err(|e| e.in_space(&self.name))
+    }
+
+    /// Decode a vector expressed as encoded lengths in the current
+    /// space.
+    ///
+    /// # Parameters
+    ///
+    ///  * `vector`:
+    ///      expressed in encoded lengths within the current space.
+    pub fn decode_vector(&self, vector: &Position) -> Result<Vec<f64>, Error> {
+        self.system.decode_vector(vector)
+    }
+
+    /// Encode a vector expressed in the current space as lengths in
+    /// *ticks* along each axis.
+    ///
+    /// # Parameters
+    ///
+    ///  * `vector`:
+    ///      expressed in the current space.
+    pub fn encode_vector(&self, vector: &[f64]) -> Result<Position, Error> {
+        self.system.encode_vector(vector)
     }
 }
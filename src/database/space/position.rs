@@ -109,12 +109,14 @@ impl Position {
     /// # Parameters
     ///
     ///  * `scale`:
-    ///      Number of bits of precision to remove from each coordinates.
-    pub fn reduce_precision(&self, scale: u32) -> Self {
+    ///      Number of bits of precision to remove from each coordinates,
+    ///      given per axis.
+    pub fn reduce_precision(&self, scale: &[u32]) -> Self {
+        assert_eq!(self.dimensions(), scale.len());
         let mut position = Vec::with_capacity(self.dimensions());
 
         for i in 0..self.dimensions() {
-            position.push(self[i].u64() >> scale)
+            position.push(self[i].u64() >> scale[i])
         }
 
         position.into()
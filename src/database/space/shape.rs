@@ -1,9 +1,19 @@
+use std::collections::HashSet;
+
+use serde::de;
 use serde::Deserialize;
+use serde::Deserializer;
 use serde::Serialize;
 
+use super::Conversion;
 use super::Coordinate;
 use super::Position;
 use super::Space;
+use crate::Error;
+
+// Relative tolerance used when comparing vectors, or checking if a position
+// is on the boundary of a shape.
+const TOLERANCE: f64 = 1e-9;
 
 /// Known shapes descriptions
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -16,7 +26,202 @@ pub enum Shape {
 
     /// Hyperrectangle whose faces have one of the axis as a normal.
     BoundingBox(Position, Position),
-    //Nifti(nifti_data??),
+
+    /// Hyperrectangle of arbitrary orientation.
+    ///
+    /// It is defined by its center, and for each dimension, the vector
+    /// from the center to the center of a face. These vectors must be
+    /// linearly independent, but are not required to be orthogonal, so
+    /// that the shape stays exact through any affine change of base.
+    OrientedBox(Position, Vec<Position>),
+
+    /// Ellipsoid of arbitrary orientation.
+    ///
+    /// It is defined by its center, and for each dimension, a vector
+    /// giving one of its semi-axes. Linearly independent, non-orthogonal
+    /// vectors are accepted as conjugate semi-axes.
+    Ellipsoid(Position, Vec<Position>),
+
+    /// A closed triangle mesh in a 3 dimensions space.
+    ///
+    /// The mesh is defined as a list of vertices, and a list of
+    /// triangular faces, each given as the offsets of its vertices in
+    /// the former list.
+    Mesh(Vec<Position>, Vec<[usize; 3]>),
+
+    /// Volume covered by at least one of the shapes.
+    ///
+    /// The list must contain at least one shape.
+    Union(Vec<Shape>),
+
+    /// Volume covered by all of the shapes.
+    ///
+    /// The list must contain at least one shape.
+    Intersection(Vec<Shape>),
+
+    /// Volume covered by the first shape, but not by the second.
+    Difference(Box<Shape>, Box<Shape>),
+
+    /// Set of voxels of a regular grid of arbitrary orientation, such as
+    /// a label of a NIfTI volume.
+    ///
+    /// It is defined by the lower corner of the first voxel of the grid,
+    /// and for each dimension, the vector along one edge of a voxel. As
+    /// for oriented boxes, these vectors must be linearly independent.
+    Mask(Position, Vec<Position>, Voxels),
+}
+
+/// Set of voxels of a regular grid.
+#[derive(Clone, Debug, Serialize)]
+pub struct Voxels {
+    dimensions: Vec<usize>,
+    // One bit per voxel, the first dimension varying the fastest.
+    bits: Vec<u64>,
+}
+
+impl Voxels {
+    /// Instantiate an empty set of voxels.
+    ///
+    /// # Parameters
+    ///
+    ///  * `dimensions`:
+    ///      Number of voxels of the grid along each dimension.
+    pub fn new(dimensions: Vec<usize>) -> Self {
+        let size = dimensions.iter().product::<usize>();
+
+        Voxels {
+            dimensions,
+            bits: vec![0; size.div_ceil(64)],
+        }
+    }
+
+    /// Number of voxels of the grid along each dimension.
+    pub fn dimensions(&self) -> &[usize] {
+        &self.dimensions
+    }
+
+    /// Number of voxels in the set.
+    pub fn len(&self) -> usize {
+        self.bits.iter().map(|b| b.count_ones() as usize).sum()
+    }
+
+    /// Check if the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.bits.iter().all(|b| *b == 0)
+    }
+
+    /// Add a voxel to the set.
+    ///
+    /// # Parameters
+    ///
+    ///  * `voxel`:
+    ///      Offsets of the voxel along each dimension of the grid.
+    pub fn insert(&mut self, voxel: &[usize]) -> Result<(), Error> {
+        match self.offset(voxel) {
+            Some(offset) => {
+                self.bits[offset / 64] |= 1 << (offset % 64);
+                Ok(())
+            }
+            None => Err(Error::InvalidShape(format!(
+                "Voxel {:?} is outside of the grid {:?}",
+                voxel, self.dimensions
+            ))),
+        }
+    }
+
+    /// Check if a voxel is in the set.
+    ///
+    /// # Parameters
+    ///
+    ///  * `voxel`:
+    ///      Offsets of the voxel along each dimension of the grid.
+    pub fn contains(&self, voxel: &[usize]) -> bool {
+        match self.offset(voxel) {
+            Some(offset) => self.bits[offset / 64] & (1 << (offset % 64)) != 0,
+            None => false,
+        }
+    }
+
+    fn offset(&self, voxel: &[usize]) -> Option<usize> {
+        if voxel.len() != self.dimensions.len() {
+            return None;
+        }
+
+        let mut offset = 0;
+        for (v, d) in voxel.iter().zip(&self.dimensions).rev() {
+            if v >= d {
+                return None;
+            }
+            offset = offset * d + v;
+        }
+
+        Some(offset)
+    }
+
+    // Smallest and largest offsets, per dimension, of the voxels in the
+    // set.
+    fn bounds(&self) -> Option<(Vec<usize>, Vec<usize>)> {
+        let mut bounds: Option<(Vec<usize>, Vec<usize>)> = None;
+
+        for (w, word) in self.bits.iter().enumerate() {
+            let mut word = *word;
+            while word != 0 {
+                let mut offset = w * 64 + word.trailing_zeros() as usize;
+                word &= word - 1;
+
+                let voxel = self
+                    .dimensions
+                    .iter()
+                    .map(|d| {
+                        let v = offset % d;
+                        offset /= d;
+                        v
+                    })
+                    .collect::<Vec<_>>();
+
+                match &mut bounds {
+                    None => bounds = Some((voxel.clone(), voxel)),
+                    Some((lower, higher)) => {
+                        for (k, v) in voxel.into_iter().enumerate() {
+                            lower[k] = lower[k].min(v);
+                            higher[k] = higher[k].max(v);
+                        }
+                    }
+                }
+            }
+        }
+
+        bounds
+    }
+}
+
+// Voxels are provided by clients, check the bits match the size of the
+// grid before using them.
+impl<'de> Deserialize<'de> for Voxels {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Grid {
+            dimensions: Vec<usize>,
+            bits: Vec<u64>,
+        }
+
+        let Grid { dimensions, bits } = Grid::deserialize(deserializer)?;
+        let size = dimensions
+            .iter()
+            .try_fold(1usize, |size, d| size.checked_mul(*d));
+
+        match size {
+            Some(size) if bits.len() == size.div_ceil(64) => Ok(Voxels { dimensions, bits }),
+            _ => Err(de::Error::custom(format!(
+                "{} words of bits do not match the grid {:?}",
+                bits.len(),
+                dimensions
+            ))),
+        }
+    }
 }
 
 impl Shape {
@@ -32,27 +237,190 @@ impl Shape {
     ///
     ///  * `to`:
     ///     Target reference space.
-    pub fn rebase(&self, from: &Space, to: &Space) -> Result<Shape, String> {
+    pub fn rebase(&self, from: &Space, to: &Space) -> Result<Shape, Error> {
+        self.convert(&Conversion::new(from, to, None))
+    }
+
+    /// Convert the encoded coordinates between two reference spaces,
+    /// using a specific conversion.
+    ///
+    /// The resulting shape is expressed in encoded coordinates in the
+    /// target space of the conversion.
+    ///
+    /// # Parameters
+    ///
+    ///  * `conversion`:
+    ///     Conversion from the current reference space of the shape.
+    pub fn convert(&self, conversion: &Conversion) -> Result<Shape, Error> {
         match self {
-            Shape::Point(position) => Ok(Shape::Point(Space::change_base(position, from, to)?)),
+            Shape::Point(position) => Ok(Shape::Point(conversion.position(position)?)),
             Shape::HyperSphere(center, radius) => {
-                //FIXME: Is the length properly dealt with? How do we process this for space conversions?
-                let mut r = Vec::with_capacity(center.dimensions());
-                for _ in 0..center.dimensions() {
-                    r.push(*radius);
+                let vectors = Shape::axis_vectors(&vec![radius.f64(); center.dimensions()]);
+                let vectors = Shape::convert_vectors(&vectors, conversion)?;
+
+                Ok(Shape::ellipsoid(conversion.position(center)?, vectors))
+            }
+            Shape::BoundingBox(lower, higher) => {
+                let half = (0..lower.dimensions())
+                    .map(|k| (higher[k].f64() - lower[k].f64()) / 2.0)
+                    .collect::<Vec<_>>();
+                let vectors = Shape::convert_vectors(&Shape::axis_vectors(&half), conversion)?;
+
+                if Shape::axis_aligned(&vectors) {
+                    // Rebase the corners directly to keep them exact, but
+                    // re-order the coordinates, as an axis might have been
+                    // flipped.
+                    let mut lower = conversion.position(lower)?;
+                    let mut higher = conversion.position(higher)?;
+                    for k in 0..lower.dimensions() {
+                        if lower[k] > higher[k] {
+                            std::mem::swap(&mut lower[k], &mut higher[k]);
+                        }
+                    }
+
+                    Ok(Shape::BoundingBox(lower, higher))
+                } else {
+                    let center: Position = (0..lower.dimensions())
+                        .map(|k| lower[k].f64() + half[k])
+                        .collect();
+
+                    Ok(Shape::OrientedBox(conversion.position(&center)?, vectors))
                 }
-                let r = r.into();
-                let r = from.absolute_position(&r)?;
-                let r = to.rebase(&(r))?[0];
-                Ok(Shape::HyperSphere(Space::change_base(center, from, to)?, r))
-            }
-            Shape::BoundingBox(lower, higher) => Ok(Shape::BoundingBox(
-                Space::change_base(lower, from, to)?,
-                Space::change_base(higher, from, to)?,
+            }
+            Shape::OrientedBox(center, vectors) => Ok(Shape::oriented_box(
+                conversion.position(center)?,
+                Shape::convert_vectors(vectors, conversion)?,
+            )),
+            Shape::Ellipsoid(center, vectors) => Ok(Shape::ellipsoid(
+                conversion.position(center)?,
+                Shape::convert_vectors(vectors, conversion)?,
+            )),
+            Shape::Mesh(vertices, faces) => {
+                let vertices = vertices
+                    .iter()
+                    .map(|vertex| conversion.position(vertex))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok(Shape::Mesh(vertices, faces.clone()))
+            }
+            Shape::Union(shapes) => Ok(Shape::Union(
+                shapes
+                    .iter()
+                    .map(|shape| shape.convert(conversion))
+                    .collect::<Result<_, _>>()?,
+            )),
+            Shape::Intersection(shapes) => Ok(Shape::Intersection(
+                shapes
+                    .iter()
+                    .map(|shape| shape.convert(conversion))
+                    .collect::<Result<_, _>>()?,
+            )),
+            Shape::Difference(shape, removed) => Ok(Shape::Difference(
+                Box::new(shape.convert(conversion)?),
+                Box::new(removed.convert(conversion)?),
+            )),
+            Shape::Mask(origin, vectors, voxels) => Ok(Shape::Mask(
+                conversion.position(origin)?,
+                Shape::convert_vectors(vectors, conversion)?,
+                voxels.clone(),
             )),
         }
     }
 
+    // Build the vectors aligned with the axes, whose lengths are given
+    // for each dimension.
+    fn axis_vectors(lengths: &[f64]) -> Vec<Position> {
+        (0..lengths.len())
+            .map(|i| {
+                (0..lengths.len())
+                    .map(|k| if i == k { lengths[k] } else { 0.0 })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn convert_vectors(
+        vectors: &[Position],
+        conversion: &Conversion,
+    ) -> Result<Vec<Position>, Error> {
+        let vectors = vectors
+            .iter()
+            .map(|vector| conversion.vector(vector))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // The shape is described with one vector per dimension, so the
+        // spaces must have the same number of dimensions.
+        for vector in &vectors {
+            if vector.dimensions() != vectors.len() {
+                return Err(Error::DimensionMismatch {
+                    expected: vectors.len(),
+                    found: vector.dimensions(),
+                });
+            }
+        }
+
+        Ok(vectors)
+    }
+
+    // Check if each vector is parallel to a distinct axis.
+    fn axis_aligned(vectors: &[Position]) -> bool {
+        let mut aligned = vec![false; vectors.len()];
+
+        for vector in vectors {
+            let norm = vector.norm();
+            let mut axes =
+                (0..vector.dimensions()).filter(|&k| vector[k].f64().abs() > TOLERANCE * norm);
+
+            match (axes.next(), axes.next()) {
+                (Some(k), None) if !aligned[k] => aligned[k] = true,
+                _ => return false,
+            }
+        }
+
+        true
+    }
+
+    // Build an oriented box, or a bounding box if it is aligned with the
+    // axes.
+    fn oriented_box(center: Position, vectors: Vec<Position>) -> Shape {
+        let shape = Shape::OrientedBox(center, vectors);
+
+        if let Shape::OrientedBox(_, vectors) = &shape {
+            if Shape::axis_aligned(vectors) {
+                let (lower, higher) = shape.get_mbb();
+                return Shape::BoundingBox(lower, higher);
+            }
+        }
+
+        shape
+    }
+
+    // Build an ellipsoid, or a sphere if all its semi-axes are orthogonal
+    // and of the same length.
+    fn ellipsoid(center: Position, vectors: Vec<Position>) -> Shape {
+        let radius = vectors.first().map_or(0.0, |v| v.norm());
+
+        for (i, a) in vectors.iter().enumerate() {
+            if (a.norm() - radius).abs() > TOLERANCE * radius {
+                return Shape::Ellipsoid(center, vectors);
+            }
+
+            for b in vectors.iter().skip(i + 1) {
+                if a.dot_product(b).abs() > TOLERANCE * radius * radius {
+                    return Shape::Ellipsoid(center, vectors);
+                }
+            }
+        }
+
+        // Keep the radius in the same kind of coordinates as the center.
+        let radius = match center[0] {
+            Coordinate::CoordinateF64(_) => radius.into(),
+            _ => (radius.round() as u64).into(),
+        };
+
+        Shape::HyperSphere(center, radius)
+    }
+
     /// Decode the coordinates of the shape.
     ///
     /// The encoded coordinates of the shapes are expressed in the
@@ -67,7 +435,7 @@ impl Shape {
     /// # Return value
     ///
     /// The shape with decoded positions within the space.
-    pub fn decode(&self, space: &Space) -> Result<Shape, String> {
+    pub fn decode(&self, space: &Space) -> Result<Shape, Error> {
         let s = match self {
             Shape::Point(position) => Shape::Point(space.decode(position)?.into()),
             Shape::HyperSphere(center, radius) => {
@@ -77,11 +445,65 @@ impl Shape {
             Shape::BoundingBox(lower, higher) => {
                 Shape::BoundingBox(space.decode(lower)?.into(), space.decode(higher)?.into())
             }
+            Shape::Mesh(vertices, faces) => {
+                let vertices = vertices
+                    .iter()
+                    .map(|vertex| Ok(space.decode(vertex)?.into()))
+                    .collect::<Result<Vec<_>, Error>>()?;
+
+                Shape::Mesh(vertices, faces.clone())
+            }
+            Shape::OrientedBox(center, vectors) => Shape::OrientedBox(
+                space.decode(center)?.into(),
+                Shape::decode_vectors(vectors, space)?,
+            ),
+            Shape::Ellipsoid(center, vectors) => Shape::Ellipsoid(
+                space.decode(center)?.into(),
+                Shape::decode_vectors(vectors, space)?,
+            ),
+            Shape::Union(shapes) => Shape::Union(
+                shapes
+                    .iter()
+                    .map(|shape| shape.decode(space))
+                    .collect::<Result<_, _>>()?,
+            ),
+            Shape::Intersection(shapes) => Shape::Intersection(
+                shapes
+                    .iter()
+                    .map(|shape| shape.decode(space))
+                    .collect::<Result<_, _>>()?,
+            ),
+            Shape::Difference(shape, removed) => Shape::Difference(
+                Box::new(shape.decode(space)?),
+                Box::new(removed.decode(space)?),
+            ),
+            Shape::Mask(origin, vectors, voxels) => Shape::Mask(
+                space.decode(origin)?.into(),
+                Shape::decode_vectors(vectors, space)?,
+                voxels.clone(),
+            ),
         };
 
         Ok(s)
     }
 
+    fn decode_vectors(vectors: &[Position], space: &Space) -> Result<Vec<Position>, Error> {
+        vectors
+            .iter()
+            .map(|vector| Ok(space.decode_vector(vector)?.into()))
+            .collect()
+    }
+
+    fn encode_vectors(vectors: &[Position], space: &Space) -> Result<Vec<Position>, Error> {
+        vectors
+            .iter()
+            .map(|vector| {
+                let v: Vec<f64> = vector.into();
+                space.encode_vector(&v)
+            })
+            .collect()
+    }
+
     /// Encode the positions of the shape.
     ///
     /// The positions of the shapes are expressed in the provided space.
@@ -95,7 +517,7 @@ impl Shape {
     /// # Return value
     ///
     /// The shape with encoded coordinates within the space.
-    pub fn encode(&self, space: &Space) -> Result<Shape, String> {
+    pub fn encode(&self, space: &Space) -> Result<Shape, Error> {
         let s = match self {
             Shape::Point(position) => {
                 let p: Vec<f64> = position.into();
@@ -111,11 +533,152 @@ impl Shape {
                 let higher: Vec<f64> = higher.into();
                 Shape::BoundingBox(space.encode(&lower)?, space.encode(&higher)?)
             }
+            Shape::Mesh(vertices, faces) => {
+                let vertices = vertices
+                    .iter()
+                    .map(|vertex| {
+                        let p: Vec<f64> = vertex.into();
+                        space.encode(&p)
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Shape::Mesh(vertices, faces.clone())
+            }
+            Shape::OrientedBox(center, vectors) => {
+                let p: Vec<f64> = center.into();
+                Shape::OrientedBox(space.encode(&p)?, Shape::encode_vectors(vectors, space)?)
+            }
+            Shape::Ellipsoid(center, vectors) => {
+                let p: Vec<f64> = center.into();
+                Shape::Ellipsoid(space.encode(&p)?, Shape::encode_vectors(vectors, space)?)
+            }
+            Shape::Union(shapes) => Shape::Union(
+                shapes
+                    .iter()
+                    .map(|shape| shape.encode(space))
+                    .collect::<Result<_, _>>()?,
+            ),
+            Shape::Intersection(shapes) => Shape::Intersection(
+                shapes
+                    .iter()
+                    .map(|shape| shape.encode(space))
+                    .collect::<Result<_, _>>()?,
+            ),
+            Shape::Difference(shape, removed) => Shape::Difference(
+                Box::new(shape.encode(space)?),
+                Box::new(removed.encode(space)?),
+            ),
+            Shape::Mask(origin, vectors, voxels) => {
+                let p: Vec<f64> = origin.into();
+                Shape::Mask(
+                    space.encode(&p)?,
+                    Shape::encode_vectors(vectors, space)?,
+                    voxels.clone(),
+                )
+            }
         };
 
         Ok(s)
     }
 
+    /// Check the shape is well formed.
+    ///
+    /// Shapes are usually provided by clients, this should be called
+    /// before using them, as the other methods assume they are valid.
+    pub fn validate(&self) -> Result<(), Error> {
+        match self {
+            Shape::Mesh(vertices, faces) => {
+                if vertices.is_empty() || faces.is_empty() {
+                    return Err(Error::InvalidShape(
+                        "Meshes require at least one vertex and one face".to_string(),
+                    ));
+                }
+
+                if let Some(vertex) = vertices.iter().find(|v| v.dimensions() != 3) {
+                    return Err(Error::InvalidShape(format!(
+                        "Mesh vertex {:?} is not in 3 dimensions",
+                        vertex
+                    )));
+                }
+
+                if let Some(face) = faces
+                    .iter()
+                    .find(|face| face.iter().any(|v| *v >= vertices.len()))
+                {
+                    return Err(Error::InvalidShape(format!(
+                        "Mesh face {:?} references a vertex out of {} provided",
+                        face,
+                        vertices.len()
+                    )));
+                }
+
+                Ok(())
+            }
+            Shape::Union(shapes) | Shape::Intersection(shapes) => {
+                if shapes.is_empty() {
+                    return Err(Error::InvalidShape(
+                        "Unions and intersections require at least one shape".to_string(),
+                    ));
+                }
+
+                shapes.iter().try_for_each(|shape| shape.validate())
+            }
+            Shape::Difference(shape, other) => {
+                shape.validate()?;
+                other.validate()
+            }
+            Shape::Mask(origin, vectors, voxels) => {
+                if vectors.len() != voxels.dimensions().len()
+                    || vectors
+                        .iter()
+                        .any(|v| v.dimensions() != origin.dimensions())
+                {
+                    return Err(Error::InvalidShape(format!(
+                        "Mask grid {:?} does not match its {} vectors, or its origin {:?}",
+                        voxels.dimensions(),
+                        vectors.len(),
+                        origin
+                    )));
+                }
+
+                Ok(())
+            }
+            Shape::OrientedBox(center, vectors) | Shape::Ellipsoid(center, vectors) => {
+                let finite = |p: &Position| (0..p.dimensions()).all(|k| p[k].f64().is_finite());
+
+                if vectors
+                    .iter()
+                    .any(|v| v.dimensions() != center.dimensions())
+                {
+                    return Err(Error::InvalidShape(format!(
+                        "Shape vectors {:?} do not match the dimensions of the center {:?}",
+                        vectors, center
+                    )));
+                }
+
+                if !finite(center) || !vectors.iter().all(finite) {
+                    return Err(Error::InvalidShape(format!(
+                        "Shape center {:?} or vectors {:?} are not finite",
+                        center, vectors
+                    )));
+                }
+
+                // The vectors can be solved for only when there is one per
+                // dimension, and they are linearly independent.
+                if Shape::coefficients(center, vectors, center).is_none() {
+                    return Err(Error::InvalidShape(format!(
+                        "Shape vectors {:?} are not {} linearly independent vectors",
+                        vectors,
+                        center.dimensions()
+                    )));
+                }
+
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Compute the minimum bounding box of the shape.
     ///
     /// This is an hyperrectangle whose faces are perpendicular to an
@@ -133,9 +696,132 @@ impl Shape {
                 (center - vr, center + vr)
             }
             Shape::BoundingBox(lower, higher) => (lower.clone(), higher.clone()),
+            Shape::Mesh(vertices, _) => {
+                let mut lower = vertices[0].clone();
+                let mut higher = vertices[0].clone();
+
+                for vertex in vertices {
+                    for k in 0..vertex.dimensions() {
+                        if vertex[k] < lower[k] {
+                            lower[k] = vertex[k];
+                        }
+                        if vertex[k] > higher[k] {
+                            higher[k] = vertex[k];
+                        }
+                    }
+                }
+
+                (lower, higher)
+            }
+            Shape::OrientedBox(center, vectors) => {
+                // The extent of the box on an axis is the sum of the
+                // extents of its vectors.
+                Shape::extent_mbb(center, |k| vectors.iter().map(|v| v[k].f64().abs()).sum())
+            }
+            Shape::Ellipsoid(center, vectors) => Shape::extent_mbb(center, |k| {
+                vectors
+                    .iter()
+                    .map(|v| v[k].f64().powi(2))
+                    .sum::<f64>()
+                    .sqrt()
+            }),
+            Shape::Union(shapes) => {
+                let (mut lower, mut higher) = shapes[0].get_mbb();
+
+                for (l, h) in shapes.iter().skip(1).map(|shape| shape.get_mbb()) {
+                    for k in 0..lower.dimensions() {
+                        if l[k] < lower[k] {
+                            lower[k] = l[k];
+                        }
+                        if h[k] > higher[k] {
+                            higher[k] = h[k];
+                        }
+                    }
+                }
+
+                (lower, higher)
+            }
+            Shape::Intersection(shapes) => {
+                // The result might be empty, with some of the lower
+                // coordinates greater than the higher ones.
+                let (mut lower, mut higher) = shapes[0].get_mbb();
+
+                for (l, h) in shapes.iter().skip(1).map(|shape| shape.get_mbb()) {
+                    for k in 0..lower.dimensions() {
+                        if l[k] > lower[k] {
+                            lower[k] = l[k];
+                        }
+                        if h[k] < higher[k] {
+                            higher[k] = h[k];
+                        }
+                    }
+                }
+
+                (lower, higher)
+            }
+            Shape::Difference(shape, _) => shape.get_mbb(),
+            Shape::Mask(origin, vectors, voxels) => {
+                let (first, last) = match voxels.bounds() {
+                    Some(bounds) => bounds,
+                    None => return (origin.clone(), origin.clone()),
+                };
+
+                // The extent of the occupied voxels on an axis is the sum
+                // of the extents of the vectors, scaled by the range of
+                // voxels along each of them.
+                let (mut lower, mut higher): (Vec<_>, Vec<_>) = (0..origin.dimensions())
+                    .map(|k| (origin[k].f64(), origin[k].f64()))
+                    .unzip();
+                for (v, (f, l)) in vectors.iter().zip(first.iter().zip(&last)) {
+                    for k in 0..origin.dimensions() {
+                        let a = *f as f64 * v[k].f64();
+                        let b = (l + 1) as f64 * v[k].f64();
+                        lower[k] += a.min(b);
+                        higher[k] += a.max(b);
+                    }
+                }
+
+                Shape::bounds_mbb(origin, &lower, &higher)
+            }
         }
     }
 
+    // Build a bounding box around `center`, using for each axis `k` the
+    // half-length `extent(k)`. When the center is expressed in encoded
+    // coordinates, the box is extended to the closest encoded values.
+    fn extent_mbb<F>(center: &Position, extent: F) -> (Position, Position)
+    where
+        F: Fn(usize) -> f64,
+    {
+        let (lower, higher): (Vec<_>, Vec<_>) = (0..center.dimensions())
+            .map(|k| (center[k].f64() - extent(k), center[k].f64() + extent(k)))
+            .unzip();
+
+        Shape::bounds_mbb(center, &lower, &higher)
+    }
+
+    // Build a bounding box from its corners, using the same kind of
+    // coordinates as `reference`.
+    fn bounds_mbb(reference: &Position, lower: &[f64], higher: &[f64]) -> (Position, Position) {
+        let mut l: Vec<Coordinate> = Vec::with_capacity(reference.dimensions());
+        let mut h: Vec<Coordinate> = Vec::with_capacity(reference.dimensions());
+
+        for k in 0..reference.dimensions() {
+            match reference[k] {
+                Coordinate::CoordinateF64(_) => {
+                    l.push(lower[k].into());
+                    h.push(higher[k].into());
+                }
+                _ => {
+                    l.push((lower[k].floor().max(0.0) as u64).into());
+                    h.push((higher[k].ceil() as u64).into());
+                }
+            }
+        }
+
+        (l.into(), h.into())
+    }
+
     /// Check if the shape overlaps with the given position.
     ///
     /// # Parameters
@@ -145,11 +831,180 @@ impl Shape {
     pub fn contains(&self, position: &Position) -> bool {
         match self {
             Shape::Point(reference) => reference == position,
-            Shape::HyperSphere(center, radius) => (position - center).norm() <= radius.f64(),
+            Shape::HyperSphere(center, radius) => distance(position, center) <= radius.f64(),
             Shape::BoundingBox(lower, higher) => lower <= position && position <= higher,
+            Shape::Mesh(vertices, faces) => Shape::mesh_contains(vertices, faces, position),
+            Shape::OrientedBox(center, vectors) => {
+                match Shape::coefficients(center, vectors, position) {
+                    None => false,
+                    Some(c) => c.iter().all(|c| c.abs() <= 1.0 + TOLERANCE),
+                }
+            }
+            Shape::Ellipsoid(center, vectors) => {
+                match Shape::coefficients(center, vectors, position) {
+                    None => false,
+                    Some(c) => c.iter().map(|c| c * c).sum::<f64>() <= 1.0 + TOLERANCE,
+                }
+            }
+            Shape::Union(shapes) => shapes.iter().any(|shape| shape.contains(position)),
+            Shape::Intersection(shapes) => shapes.iter().all(|shape| shape.contains(position)),
+            Shape::Difference(shape, removed) => {
+                shape.contains(position) && !removed.contains(position)
+            }
+            Shape::Mask(origin, vectors, voxels) => {
+                match Shape::coefficients(origin, vectors, position) {
+                    None => false,
+                    Some(c) => {
+                        let mut voxel = Vec::with_capacity(c.len());
+                        for c in c {
+                            let c = (c + TOLERANCE).floor();
+                            if c < 0.0 {
+                                return false;
+                            }
+                            voxel.push(c as usize);
+                        }
+
+                        voxels.contains(&voxel)
+                    }
+                }
+            }
         }
     }
 
+    // Express `position - center` as a linear combination of `vectors`,
+    // using a Gaussian elimination with partial pivoting.
+    //
+    // Returns `None` when the vectors are not linearly independent, or do
+    // not match the number of dimensions of the position.
+    pub(super) fn coefficients(
+        center: &Position,
+        vectors: &[Position],
+        position: &Position,
+    ) -> Option<Vec<f64>> {
+        let n = position.dimensions();
+        if center.dimensions() != n || vectors.len() != n {
+            return None;
+        }
+
+        // Augmented matrix, whose columns are the vectors, followed by the
+        // position relative to the center.
+        let mut m = (0..n)
+            .map(|k| {
+                let mut row = vectors.iter().map(|v| v[k].f64()).collect::<Vec<_>>();
+                row.push(position[k].f64() - center[k].f64());
+                row
+            })
+            .collect::<Vec<_>>();
+
+        for i in 0..n {
+            let pivot = (i..n).max_by(|&a, &b| m[a][i].abs().total_cmp(&m[b][i].abs()))?;
+            if m[pivot][i].abs() < f64::EPSILON {
+                return None;
+            }
+            m.swap(i, pivot);
+
+            let reference = m[i].clone();
+            for row in m.iter_mut().skip(i + 1) {
+                let factor = row[i] / reference[i];
+                for (x, p) in row.iter_mut().zip(&reference).skip(i) {
+                    *x -= factor * p;
+                }
+            }
+        }
+
+        let mut c = vec![0.0; n];
+        for i in (0..n).rev() {
+            let s = (i + 1..n).map(|k| m[i][k] * c[k]).sum::<f64>();
+            c[i] = (m[i][n] - s) / m[i][i];
+        }
+
+        Some(c)
+    }
+
+    // Determinant of the matrix whose columns are `vectors`.
+    pub(super) fn determinant(vectors: &[Position]) -> f64 {
+        let n = vectors.len();
+        let mut m = (0..n)
+            .map(|k| vectors.iter().map(|v| v[k].f64()).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let mut determinant = 1.0;
+
+        for i in 0..n {
+            let pivot = match (i..n).max_by(|&a, &b| m[a][i].abs().total_cmp(&m[b][i].abs())) {
+                Some(pivot) => pivot,
+                None => return 0.0,
+            };
+            if m[pivot][i] == 0.0 {
+                return 0.0;
+            }
+            if pivot != i {
+                m.swap(i, pivot);
+                determinant = -determinant;
+            }
+
+            determinant *= m[i][i];
+            let reference = m[i].clone();
+            for row in m.iter_mut().skip(i + 1) {
+                let factor = row[i] / reference[i];
+                for (x, p) in row.iter_mut().zip(&reference).skip(i) {
+                    *x -= factor * p;
+                }
+            }
+        }
+
+        determinant
+    }
+
+    // Check if a position is inside a closed triangle mesh, by counting the
+    // number of faces crossed by a ray starting at that position. The
+    // intersections are computed with the Möller–Trumbore algorithm.
+    fn mesh_contains(vertices: &[Position], faces: &[[usize; 3]], position: &Position) -> bool {
+        if position.dimensions() != 3 {
+            return false;
+        }
+
+        // Use an arbitrary direction for the ray, which is unlikely to be
+        // aligned with the faces or edges of the mesh.
+        const DIRECTION: [f64; 3] = [0.573_478_123, 0.312_571_394, 0.757_102_847];
+        const EPSILON: f64 = 1e-12;
+
+        let origin = vector(position);
+        let mut crossings = 0;
+
+        for face in faces {
+            let v0 = vector(&vertices[face[0]]);
+            let e1 = difference(&vector(&vertices[face[1]]), &v0);
+            let e2 = difference(&vector(&vertices[face[2]]), &v0);
+
+            let p = cross(&DIRECTION, &e2);
+            let determinant = dot(&e1, &p);
+            if determinant.abs() < EPSILON {
+                // The ray is parallel to the face.
+                continue;
+            }
+
+            let inverse = 1.0 / determinant;
+            let t = difference(&origin, &v0);
+            let u = dot(&t, &p) * inverse;
+            if !(0.0..=1.0).contains(&u) {
+                continue;
+            }
+
+            let q = cross(&t, &e1);
+            let v = dot(&DIRECTION, &q) * inverse;
+            if v < 0.0 || u + v > 1.0 {
+                continue;
+            }
+
+            // Count only the faces in front of the position.
+            if dot(&e2, &q) * inverse > EPSILON {
+                crossings += 1;
+            }
+        }
+
+        crossings % 2 == 1
+    }
+
     /* Original version proposed by Charles François Rey - 2019
     ```perl
     use strict;
@@ -235,7 +1090,7 @@ impl Shape {
     /// Transform a Shape into a list of `Position` which approximate
     /// the shape.
     // TODO: Return an iterator instead, for performance!
-    pub fn rasterise(&self) -> Result<Vec<Position>, String> {
+    pub fn rasterise(&self) -> Result<Vec<Position>, Error> {
         match self {
             Shape::Point(position) => Ok(vec![position.clone()]),
             Shape::HyperSphere(center, radius) => {
@@ -244,12 +1099,42 @@ impl Shape {
 
                 let positions = Shape::gen(&lower, &higher)
                     .into_iter()
-                    .filter(|p| (p - center).norm() <= radius)
+                    .filter(|p| distance(p, center) <= radius)
                     .collect();
 
                 Ok(positions)
             }
             Shape::BoundingBox(lower, higher) => Ok(Shape::gen(lower, higher)),
+            Shape::Mesh(_, _)
+            | Shape::OrientedBox(_, _)
+            | Shape::Ellipsoid(_, _)
+            | Shape::Intersection(_)
+            | Shape::Difference(_, _)
+            | Shape::Mask(_, _, _) => {
+                let (lower, higher) = self.get_mbb();
+
+                let positions = Shape::gen(&lower, &higher)
+                    .into_iter()
+                    .filter(|p| self.contains(p))
+                    .collect();
+
+                Ok(positions)
+            }
+            Shape::Union(shapes) => {
+                // Rasterise each shape on its own, as the members of the
+                // union might be far apart.
+                let mut unique = HashSet::new();
+                let mut positions = vec![];
+                for shape in shapes {
+                    for position in shape.rasterise()? {
+                        if unique.insert(position.clone()) {
+                            positions.push(position);
+                        }
+                    }
+                }
+
+                Ok(positions)
+            }
         }
     }
 
@@ -262,7 +1147,7 @@ impl Shape {
     ///      Reference space in which the shape is expressed.
     ///
     // TODO: Return an iterator instead, for performance!
-    pub fn rasterise_from(&self, space: &Space) -> Result<Vec<Position>, String> {
+    pub fn rasterise_from(&self, space: &Space) -> Result<Vec<Position>, Error> {
         Ok(self
             .rasterise()?
             .into_iter()
@@ -274,6 +1159,9 @@ impl Shape {
     }
 
     /// Compute the volume.
+    ///
+    /// For unions, intersections and differences of shapes, this is an
+    /// upper bound of the actual volume.
     pub fn volume(&self) -> f64 {
         match self {
             Shape::Point(_) => f64::EPSILON, // Smallest non-zero volume possible
@@ -292,30 +1180,95 @@ impl Shape {
                 volume
             }
             Shape::HyperSphere(position, radius) => {
-                // Formula from https://en.wikipedia.org/wiki/N-sphere#/media/File:N_SpheresVolumeAndSurfaceArea.png
                 let k = position.dimensions(); // Number of dimensions.
-                let radius = radius.f64();
 
-                let pi = std::f64::consts::PI;
-                let factor = 2.0 * pi;
+                Shape::unit_ball_volume(k) * radius.f64().powi(k as i32)
+            }
+            Shape::OrientedBox(_, vectors) => {
+                // Volume of the unit hypercube, scaled by the vectors.
+                2f64.powi(vectors.len() as i32) * Shape::determinant(vectors).abs()
+            }
+            Shape::Ellipsoid(_, vectors) => {
+                Shape::unit_ball_volume(vectors.len()) * Shape::determinant(vectors).abs()
+            }
+            Shape::Mesh(vertices, faces) => {
+                // Sum the signed volumes of the tetrahedra formed by each
+                // face and the origin, following the divergence theorem.
+                let mut volume = 0.0;
 
-                // Set starting values for the coefficient
-                let mut a = 2.0;
-                let mut i = if (k % 2) == 0 {
-                    a = pi;
-                    2
-                } else {
-                    1
-                };
+                for face in faces {
+                    let v0 = vector(&vertices[face[0]]);
+                    let v1 = vector(&vertices[face[1]]);
+                    let v2 = vector(&vertices[face[2]]);
 
-                while i < k {
-                    i += 2;
-                    a *= factor;
-                    a /= i as f64;
+                    volume += dot(&v0, &cross(&v1, &v2));
                 }
 
-                a * radius.powi(i as i32)
+                (volume / 6.0).abs()
+            }
+            Shape::Union(shapes) => shapes.iter().map(|shape| shape.volume()).sum(),
+            Shape::Intersection(shapes) => shapes
+                .iter()
+                .map(|shape| shape.volume())
+                .fold(f64::INFINITY, f64::min),
+            Shape::Difference(shape, _) => shape.volume(),
+            Shape::Mask(_, vectors, voxels) => {
+                voxels.len() as f64 * Shape::determinant(vectors).abs()
             }
         }
     }
+
+    // Volume of the ball of radius 1 in `k` dimensions.
+    fn unit_ball_volume(k: usize) -> f64 {
+        // Formula from https://en.wikipedia.org/wiki/N-sphere#/media/File:N_SpheresVolumeAndSurfaceArea.png
+        let pi = std::f64::consts::PI;
+        let factor = 2.0 * pi;
+
+        // Set starting values for the coefficient
+        let mut a = 2.0;
+        let mut i = if (k % 2) == 0 {
+            a = pi;
+            2
+        } else {
+            1
+        };
+
+        while i < k {
+            i += 2;
+            a *= factor;
+            a /= i as f64;
+        }
+
+        a
+    }
+}
+
+// Euclidean distance between two positions. Computed on `f64` values, as
+// the subtraction of encoded coordinates saturates at zero.
+fn distance(a: &Position, b: &Position) -> f64 {
+    (0..a.dimensions())
+        .map(|k| (a[k].f64() - b[k].f64()).powi(2))
+        .sum::<f64>()
+        .sqrt()
+}
+
+// Helpers for computations on 3 dimensions vectors.
+fn vector(position: &Position) -> [f64; 3] {
+    [position[0].f64(), position[1].f64(), position[2].f64()]
+}
+
+fn difference(a: &[f64; 3], b: &[f64; 3]) -> [f64; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn dot(a: &[f64; 3], b: &[f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross(a: &[f64; 3], b: &[f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
 }
@@ -1,12 +1,27 @@
 use super::*;
 
+// These spaces predate the enforcement of the number sets, their axes
+// of integers with negative bounds are axes of real numbers.
+fn legacy_axis(
+    unit: &str,
+    unit_vector: Vec<f64>,
+    set: NumberSet,
+    minimum: f64,
+    maximum: f64,
+    steps: u64,
+) -> Result<Axis, Error> {
+    let graduation = Graduation::legacy(set, minimum, maximum, steps)?;
+
+    Ok(Axis::with_graduation(unit, unit_vector, graduation))
+}
+
 fn get1() -> Space {
     Space::new(
         "space1",
         CoordinateSystem::new(
             vec![0f64, 0f64, 0f64],
             vec![
-                Axis::new(
+                legacy_axis(
                     "cm",
                     vec![1f64, 0f64, 0f64],
                     NumberSet::N,
@@ -15,7 +30,7 @@ fn get1() -> Space {
                     80,
                 )
                 .unwrap(),
-                Axis::new(
+                legacy_axis(
                     "cm",
                     vec![0f64, 1f64, 0f64],
                     NumberSet::N,
@@ -24,7 +39,7 @@ fn get1() -> Space {
                     80,
                 )
                 .unwrap(),
-                Axis::new(
+                legacy_axis(
                     "cm",
                     vec![0f64, 0f64, 1f64],
                     NumberSet::N,
@@ -44,7 +59,7 @@ fn get2() -> Space {
         CoordinateSystem::new(
             vec![0f64, -2f64, 3f64],
             vec![
-                Axis::new(
+                legacy_axis(
                     "cm",
                     vec![1f64, 0f64, 0f64],
                     NumberSet::N,
@@ -53,7 +68,7 @@ fn get2() -> Space {
                     std::u64::MAX,
                 )
                 .unwrap(),
-                Axis::new(
+                legacy_axis(
                     "cm",
                     vec![0f64, 1f64, 0f64],
                     NumberSet::N,
@@ -62,7 +77,7 @@ fn get2() -> Space {
                     std::u64::MAX,
                 )
                 .unwrap(),
-                Axis::new(
+                legacy_axis(
                     "cm",
                     vec![0f64, 0f64, 1f64],
                     NumberSet::N,
@@ -82,7 +97,7 @@ fn get3() -> Space {
         CoordinateSystem::new(
             vec![0f64, 0f64, 0f64],
             vec![
-                Axis::new(
+                legacy_axis(
                     "cm",
                     vec![1f64, 1f64, 0f64],
                     NumberSet::N,
@@ -91,7 +106,7 @@ fn get3() -> Space {
                     8000,
                 )
                 .unwrap(),
-                Axis::new(
+                legacy_axis(
                     "cm",
                     vec![-1f64, 1f64, 0f64],
                     NumberSet::N,
@@ -100,7 +115,7 @@ fn get3() -> Space {
                     800,
                 )
                 .unwrap(),
-                Axis::new(
+                legacy_axis(
                     "cm",
                     vec![0f64, 0f64, 1f64],
                     NumberSet::N,
@@ -288,3 +303,546 @@ pub fn check_r3() {
 
     assert_eq!(format!("{:?}", t1), "[Position3([CoordinateU8(17), CoordinateU8(3), CoordinateU8(5)]), Position3([CoordinateU8(17), CoordinateU8(3), CoordinateU8(6)]), Position3([CoordinateU8(17), CoordinateU8(3), CoordinateU8(7)]), Position3([CoordinateU8(17), CoordinateU8(3), CoordinateU8(8)]), Position3([CoordinateU8(17), CoordinateU8(3), CoordinateU8(9)]), Position3([CoordinateU8(17), CoordinateU8(3), CoordinateU8(10)]), Position3([CoordinateU8(17), CoordinateU8(3), CoordinateU8(11)]), Position3([CoordinateU8(17), CoordinateU8(3), CoordinateU8(12)]), Position3([CoordinateU8(17), CoordinateU8(3), CoordinateU8(13)]), Position3([CoordinateU8(17), CoordinateU8(3), CoordinateU8(14)]), Position3([CoordinateU8(17), CoordinateU8(3), CoordinateU8(15)]), Position3([CoordinateU8(17), CoordinateU8(3), CoordinateU8(16)]), Position3([CoordinateU8(17), CoordinateU8(3), CoordinateU8(17)]), Position3([CoordinateU8(17), CoordinateU8(3), CoordinateU8(18)]), Position3([CoordinateU8(17), CoordinateU8(3), CoordinateU8(19)]), Position3([CoordinateU8(17), CoordinateU8(3), CoordinateU8(20)]), Position3([CoordinateU8(17), CoordinateU8(3), CoordinateU8(21)]), Position3([CoordinateU8(17), CoordinateU8(3), CoordinateU8(22)]), Position3([CoordinateU8(17), CoordinateU8(4), CoordinateU8(5)]), Position3([CoordinateU8(17), CoordinateU8(4), CoordinateU8(6)]), Position3([CoordinateU8(17), CoordinateU8(4), CoordinateU8(7)]), Position3([CoordinateU8(17), CoordinateU8(4), CoordinateU8(8)]), Position3([CoordinateU8(17), CoordinateU8(4), CoordinateU8(9)]), Position3([CoordinateU8(17), CoordinateU8(4), CoordinateU8(10)]), Position3([CoordinateU8(17), CoordinateU8(4), CoordinateU8(11)]), Position3([CoordinateU8(17), CoordinateU8(4), CoordinateU8(12)]), Position3([CoordinateU8(17), CoordinateU8(4), CoordinateU8(13)]), Position3([CoordinateU8(17), CoordinateU8(4), CoordinateU8(14)]), Position3([CoordinateU8(17), CoordinateU8(4), CoordinateU8(15)]), Position3([CoordinateU8(17), CoordinateU8(4), CoordinateU8(16)]), Position3([CoordinateU8(17), CoordinateU8(4), CoordinateU8(17)]), Position3([CoordinateU8(17), CoordinateU8(4), CoordinateU8(18)]), Position3([CoordinateU8(17), CoordinateU8(4), CoordinateU8(19)]), Position3([CoordinateU8(17), CoordinateU8(4), CoordinateU8(20)]), Position3([CoordinateU8(17), CoordinateU8(4), CoordinateU8(21)]), Position3([CoordinateU8(17), CoordinateU8(4), CoordinateU8(22)]), Position3([CoordinateU8(17), CoordinateU8(5), CoordinateU8(5)]), Position3([CoordinateU8(17), CoordinateU8(5), CoordinateU8(6)]), Position3([CoordinateU8(17), CoordinateU8(5), CoordinateU8(7)]), Position3([CoordinateU8(17), CoordinateU8(5), CoordinateU8(8)]), Position3([CoordinateU8(17), CoordinateU8(5), CoordinateU8(9)]), Position3([CoordinateU8(17), CoordinateU8(5), CoordinateU8(10)]), Position3([CoordinateU8(17), CoordinateU8(5), CoordinateU8(11)]), Position3([CoordinateU8(17), CoordinateU8(5), CoordinateU8(12)]), Position3([CoordinateU8(17), CoordinateU8(5), CoordinateU8(13)]), Position3([CoordinateU8(17), CoordinateU8(5), CoordinateU8(14)]), Position3([CoordinateU8(17), CoordinateU8(5), CoordinateU8(15)]), Position3([CoordinateU8(17), CoordinateU8(5), CoordinateU8(16)]), Position3([CoordinateU8(17), CoordinateU8(5), CoordinateU8(17)]), Position3([CoordinateU8(17), CoordinateU8(5), CoordinateU8(18)]), Position3([CoordinateU8(17), CoordinateU8(5), CoordinateU8(19)]), Position3([CoordinateU8(17), CoordinateU8(5), CoordinateU8(20)]), Position3([CoordinateU8(17), CoordinateU8(5), CoordinateU8(21)]), Position3([CoordinateU8(17), CoordinateU8(5), CoordinateU8(22)]), Position3([CoordinateU8(17), CoordinateU8(6), CoordinateU8(5)]), Position3([CoordinateU8(17), CoordinateU8(6), CoordinateU8(6)]), Position3([CoordinateU8(17), CoordinateU8(6), CoordinateU8(7)]), Position3([CoordinateU8(17), CoordinateU8(6), CoordinateU8(8)]), Position3([CoordinateU8(17), CoordinateU8(6), CoordinateU8(9)]), Position3([CoordinateU8(17), CoordinateU8(6), CoordinateU8(10)]), Position3([CoordinateU8(17), CoordinateU8(6), CoordinateU8(11)]), Position3([CoordinateU8(17), CoordinateU8(6), CoordinateU8(12)]), Position3([CoordinateU8(17), CoordinateU8(6), CoordinateU8(13)]), Position3([CoordinateU8(17), CoordinateU8(6), CoordinateU8(14)]), Position3([CoordinateU8(17), CoordinateU8(6), CoordinateU8(15)]), Position3([CoordinateU8(17), CoordinateU8(6), CoordinateU8(16)]), Position3([CoordinateU8(17), CoordinateU8(6), CoordinateU8(17)]), Position3([CoordinateU8(17), CoordinateU8(6), CoordinateU8(18)]), Position3([CoordinateU8(17), CoordinateU8(6), CoordinateU8(19)]), Position3([CoordinateU8(17), CoordinateU8(6), CoordinateU8(20)]), Position3([CoordinateU8(17), CoordinateU8(6), CoordinateU8(21)]), Position3([CoordinateU8(17), CoordinateU8(6), CoordinateU8(22)]), Position3([CoordinateU8(17), CoordinateU8(7), CoordinateU8(5)]), Position3([CoordinateU8(17), CoordinateU8(7), CoordinateU8(6)]), Position3([CoordinateU8(17), CoordinateU8(7), CoordinateU8(7)]), Position3([CoordinateU8(17), CoordinateU8(7), CoordinateU8(8)]), Position3([CoordinateU8(17), CoordinateU8(7), CoordinateU8(9)]), Position3([CoordinateU8(17), CoordinateU8(7), CoordinateU8(10)]), Position3([CoordinateU8(17), CoordinateU8(7), CoordinateU8(11)]), Position3([CoordinateU8(17), CoordinateU8(7), CoordinateU8(12)]), Position3([CoordinateU8(17), CoordinateU8(7), CoordinateU8(13)]), Position3([CoordinateU8(17), CoordinateU8(7), CoordinateU8(14)]), Position3([CoordinateU8(17), CoordinateU8(7), CoordinateU8(15)]), Position3([CoordinateU8(17), CoordinateU8(7), CoordinateU8(16)]), Position3([CoordinateU8(17), CoordinateU8(7), CoordinateU8(17)]), Position3([CoordinateU8(17), CoordinateU8(7), CoordinateU8(18)]), Position3([CoordinateU8(17), CoordinateU8(7), CoordinateU8(19)]), Position3([CoordinateU8(17), CoordinateU8(7), CoordinateU8(20)]), Position3([CoordinateU8(17), CoordinateU8(7), CoordinateU8(21)]), Position3([CoordinateU8(17), CoordinateU8(7), CoordinateU8(22)]), Position3([CoordinateU8(17), CoordinateU8(8), CoordinateU8(5)]), Position3([CoordinateU8(17), CoordinateU8(8), CoordinateU8(6)]), Position3([CoordinateU8(17), CoordinateU8(8), CoordinateU8(7)]), Position3([CoordinateU8(17), CoordinateU8(8), CoordinateU8(8)]), Position3([CoordinateU8(17), CoordinateU8(8), CoordinateU8(9)]), Position3([CoordinateU8(17), CoordinateU8(8), CoordinateU8(10)]), Position3([CoordinateU8(17), CoordinateU8(8), CoordinateU8(11)]), Position3([CoordinateU8(17), CoordinateU8(8), CoordinateU8(12)]), Position3([CoordinateU8(17), CoordinateU8(8), CoordinateU8(13)]), Position3([CoordinateU8(17), CoordinateU8(8), CoordinateU8(14)]), Position3([CoordinateU8(17), CoordinateU8(8), CoordinateU8(15)]), Position3([CoordinateU8(17), CoordinateU8(8), CoordinateU8(16)]), Position3([CoordinateU8(17), CoordinateU8(8), CoordinateU8(17)]), Position3([CoordinateU8(17), CoordinateU8(8), CoordinateU8(18)]), Position3([CoordinateU8(17), CoordinateU8(8), CoordinateU8(19)]), Position3([CoordinateU8(17), CoordinateU8(8), CoordinateU8(20)]), Position3([CoordinateU8(17), CoordinateU8(8), CoordinateU8(21)]), Position3([CoordinateU8(17), CoordinateU8(8), CoordinateU8(22)]), Position3([CoordinateU8(17), CoordinateU8(9), CoordinateU8(5)]), Position3([CoordinateU8(17), CoordinateU8(9), CoordinateU8(6)]), Position3([CoordinateU8(17), CoordinateU8(9), CoordinateU8(7)]), Position3([CoordinateU8(17), CoordinateU8(9), CoordinateU8(8)]), Position3([CoordinateU8(17), CoordinateU8(9), CoordinateU8(9)]), Position3([CoordinateU8(17), CoordinateU8(9), CoordinateU8(10)]), Position3([CoordinateU8(17), CoordinateU8(9), CoordinateU8(11)]), Position3([CoordinateU8(17), CoordinateU8(9), CoordinateU8(12)]), Position3([CoordinateU8(17), CoordinateU8(9), CoordinateU8(13)]), Position3([CoordinateU8(17), CoordinateU8(9), CoordinateU8(14)]), Position3([CoordinateU8(17), CoordinateU8(9), CoordinateU8(15)]), Position3([CoordinateU8(17), CoordinateU8(9), CoordinateU8(16)]), Position3([CoordinateU8(17), CoordinateU8(9), CoordinateU8(17)]), Position3([CoordinateU8(17), CoordinateU8(9), CoordinateU8(18)]), Position3([CoordinateU8(17), CoordinateU8(9), CoordinateU8(19)]), Position3([CoordinateU8(17), CoordinateU8(9), CoordinateU8(20)]), Position3([CoordinateU8(17), CoordinateU8(9), CoordinateU8(21)]), Position3([CoordinateU8(17), CoordinateU8(9), CoordinateU8(22)]), Position3([CoordinateU8(17), CoordinateU8(10), CoordinateU8(5)]), Position3([CoordinateU8(17), CoordinateU8(10), CoordinateU8(6)]), Position3([CoordinateU8(17), CoordinateU8(10), CoordinateU8(7)]), Position3([CoordinateU8(17), CoordinateU8(10), CoordinateU8(8)]), Position3([CoordinateU8(17), CoordinateU8(10), CoordinateU8(9)]), Position3([CoordinateU8(17), CoordinateU8(10), CoordinateU8(10)]), Position3([CoordinateU8(17), CoordinateU8(10), CoordinateU8(11)]), Position3([CoordinateU8(17), CoordinateU8(10), CoordinateU8(12)]), Position3([CoordinateU8(17), CoordinateU8(10), CoordinateU8(13)]), Position3([CoordinateU8(17), CoordinateU8(10), CoordinateU8(14)]), Position3([CoordinateU8(17), CoordinateU8(10), CoordinateU8(15)]), Position3([CoordinateU8(17), CoordinateU8(10), CoordinateU8(16)]), Position3([CoordinateU8(17), CoordinateU8(10), CoordinateU8(17)]), Position3([CoordinateU8(17), CoordinateU8(10), CoordinateU8(18)]), Position3([CoordinateU8(17), CoordinateU8(10), CoordinateU8(19)]), Position3([CoordinateU8(17), CoordinateU8(10), CoordinateU8(20)]), Position3([CoordinateU8(17), CoordinateU8(10), CoordinateU8(21)]), Position3([CoordinateU8(17), CoordinateU8(10), CoordinateU8(22)]), Position3([CoordinateU8(17), CoordinateU8(11), CoordinateU8(5)]), Position3([CoordinateU8(17), CoordinateU8(11), CoordinateU8(6)]), Position3([CoordinateU8(17), CoordinateU8(11), CoordinateU8(7)]), Position3([CoordinateU8(17), CoordinateU8(11), CoordinateU8(8)]), Position3([CoordinateU8(17), CoordinateU8(11), CoordinateU8(9)]), Position3([CoordinateU8(17), CoordinateU8(11), CoordinateU8(10)]), Position3([CoordinateU8(17), CoordinateU8(11), CoordinateU8(11)]), Position3([CoordinateU8(17), CoordinateU8(11), CoordinateU8(12)]), Position3([CoordinateU8(17), CoordinateU8(11), CoordinateU8(13)]), Position3([CoordinateU8(17), CoordinateU8(11), CoordinateU8(14)]), Position3([CoordinateU8(17), CoordinateU8(11), CoordinateU8(15)]), Position3([CoordinateU8(17), CoordinateU8(11), CoordinateU8(16)]), Position3([CoordinateU8(17), CoordinateU8(11), CoordinateU8(17)]), Position3([CoordinateU8(17), CoordinateU8(11), CoordinateU8(18)]), Position3([CoordinateU8(17), CoordinateU8(11), CoordinateU8(19)]), Position3([CoordinateU8(17), CoordinateU8(11), CoordinateU8(20)]), Position3([CoordinateU8(17), CoordinateU8(11), CoordinateU8(21)]), Position3([CoordinateU8(17), CoordinateU8(11), CoordinateU8(22)]), Position3([CoordinateU8(17), CoordinateU8(12), CoordinateU8(5)]), Position3([CoordinateU8(17), CoordinateU8(12), CoordinateU8(6)]), Position3([CoordinateU8(17), CoordinateU8(12), CoordinateU8(7)]), Position3([CoordinateU8(17), CoordinateU8(12), CoordinateU8(8)]), Position3([CoordinateU8(17), CoordinateU8(12), CoordinateU8(9)]), Position3([CoordinateU8(17), CoordinateU8(12), CoordinateU8(10)]), Position3([CoordinateU8(17), CoordinateU8(12), CoordinateU8(11)]), Position3([CoordinateU8(17), CoordinateU8(12), CoordinateU8(12)]), Position3([CoordinateU8(17), CoordinateU8(12), CoordinateU8(13)]), Position3([CoordinateU8(17), CoordinateU8(12), CoordinateU8(14)]), Position3([CoordinateU8(17), CoordinateU8(12), CoordinateU8(15)]), Position3([CoordinateU8(17), CoordinateU8(12), CoordinateU8(16)]), Position3([CoordinateU8(17), CoordinateU8(12), CoordinateU8(17)]), Position3([CoordinateU8(17), CoordinateU8(12), CoordinateU8(18)]), Position3([CoordinateU8(17), CoordinateU8(12), CoordinateU8(19)]), Position3([CoordinateU8(17), CoordinateU8(12), CoordinateU8(20)]), Position3([CoordinateU8(17), CoordinateU8(12), CoordinateU8(21)]), Position3([CoordinateU8(17), CoordinateU8(12), CoordinateU8(22)]), Position3([CoordinateU8(17), CoordinateU8(13), CoordinateU8(5)]), Position3([CoordinateU8(17), CoordinateU8(13), CoordinateU8(6)]), Position3([CoordinateU8(17), CoordinateU8(13), CoordinateU8(7)]), Position3([CoordinateU8(17), CoordinateU8(13), CoordinateU8(8)]), Position3([CoordinateU8(17), CoordinateU8(13), CoordinateU8(9)]), Position3([CoordinateU8(17), CoordinateU8(13), CoordinateU8(10)]), Position3([CoordinateU8(17), CoordinateU8(13), CoordinateU8(11)]), Position3([CoordinateU8(17), CoordinateU8(13), CoordinateU8(12)]), Position3([CoordinateU8(17), CoordinateU8(13), CoordinateU8(13)]), Position3([CoordinateU8(17), CoordinateU8(13), CoordinateU8(14)]), Position3([CoordinateU8(17), CoordinateU8(13), CoordinateU8(15)]), Position3([CoordinateU8(17), CoordinateU8(13), CoordinateU8(16)]), Position3([CoordinateU8(17), CoordinateU8(13), CoordinateU8(17)]), Position3([CoordinateU8(17), CoordinateU8(13), CoordinateU8(18)]), Position3([CoordinateU8(17), CoordinateU8(13), CoordinateU8(19)]), Position3([CoordinateU8(17), CoordinateU8(13), CoordinateU8(20)]), Position3([CoordinateU8(17), CoordinateU8(13), CoordinateU8(21)]), Position3([CoordinateU8(17), CoordinateU8(13), CoordinateU8(22)]), Position3([CoordinateU8(17), CoordinateU8(14), CoordinateU8(5)]), Position3([CoordinateU8(17), CoordinateU8(14), CoordinateU8(6)]), Position3([CoordinateU8(17), CoordinateU8(14), CoordinateU8(7)]), Position3([CoordinateU8(17), CoordinateU8(14), CoordinateU8(8)]), Position3([CoordinateU8(17), CoordinateU8(14), CoordinateU8(9)]), Position3([CoordinateU8(17), CoordinateU8(14), CoordinateU8(10)]), Position3([CoordinateU8(17), CoordinateU8(14), CoordinateU8(11)]), Position3([CoordinateU8(17), CoordinateU8(14), CoordinateU8(12)]), Position3([CoordinateU8(17), CoordinateU8(14), CoordinateU8(13)]), Position3([CoordinateU8(17), CoordinateU8(14), CoordinateU8(14)]), Position3([CoordinateU8(17), CoordinateU8(14), CoordinateU8(15)]), Position3([CoordinateU8(17), CoordinateU8(14), CoordinateU8(16)]), Position3([CoordinateU8(17), CoordinateU8(14), CoordinateU8(17)]), Position3([CoordinateU8(17), CoordinateU8(14), CoordinateU8(18)]), Position3([CoordinateU8(17), CoordinateU8(14), CoordinateU8(19)]), Position3([CoordinateU8(17), CoordinateU8(14), CoordinateU8(20)]), Position3([CoordinateU8(17), CoordinateU8(14), CoordinateU8(21)]), Position3([CoordinateU8(17), CoordinateU8(14), CoordinateU8(22)]), Position3([CoordinateU8(17), CoordinateU8(15), CoordinateU8(5)]), Position3([CoordinateU8(17), CoordinateU8(15), CoordinateU8(6)]), Position3([CoordinateU8(17), CoordinateU8(15), CoordinateU8(7)]), Position3([CoordinateU8(17), CoordinateU8(15), CoordinateU8(8)]), Position3([CoordinateU8(17), CoordinateU8(15), CoordinateU8(9)]), Position3([CoordinateU8(17), CoordinateU8(15), CoordinateU8(10)]), Position3([CoordinateU8(17), CoordinateU8(15), CoordinateU8(11)]), Position3([CoordinateU8(17), CoordinateU8(15), CoordinateU8(12)]), Position3([CoordinateU8(17), CoordinateU8(15), CoordinateU8(13)]), Position3([CoordinateU8(17), CoordinateU8(15), CoordinateU8(14)]), Position3([CoordinateU8(17), CoordinateU8(15), CoordinateU8(15)]), Position3([CoordinateU8(17), CoordinateU8(15), CoordinateU8(16)]), Position3([CoordinateU8(17), CoordinateU8(15), CoordinateU8(17)]), Position3([CoordinateU8(17), CoordinateU8(15), CoordinateU8(18)]), Position3([CoordinateU8(17), CoordinateU8(15), CoordinateU8(19)]), Position3([CoordinateU8(17), CoordinateU8(15), CoordinateU8(20)]), Position3([CoordinateU8(17), CoordinateU8(15), CoordinateU8(21)]), Position3([CoordinateU8(17), CoordinateU8(15), CoordinateU8(22)]), Position3([CoordinateU8(17), CoordinateU8(16), CoordinateU8(5)]), Position3([CoordinateU8(17), CoordinateU8(16), CoordinateU8(6)]), Position3([CoordinateU8(17), CoordinateU8(16), CoordinateU8(7)]), Position3([CoordinateU8(17), CoordinateU8(16), CoordinateU8(8)]), Position3([CoordinateU8(17), CoordinateU8(16), CoordinateU8(9)]), Position3([CoordinateU8(17), CoordinateU8(16), CoordinateU8(10)]), Position3([CoordinateU8(17), CoordinateU8(16), CoordinateU8(11)]), Position3([CoordinateU8(17), CoordinateU8(16), CoordinateU8(12)]), Position3([CoordinateU8(17), CoordinateU8(16), CoordinateU8(13)]), Position3([CoordinateU8(17), CoordinateU8(16), CoordinateU8(14)]), Position3([CoordinateU8(17), CoordinateU8(16), CoordinateU8(15)]), Position3([CoordinateU8(17), CoordinateU8(16), CoordinateU8(16)]), Position3([CoordinateU8(17), CoordinateU8(16), CoordinateU8(17)]), Position3([CoordinateU8(17), CoordinateU8(16), CoordinateU8(18)]), Position3([CoordinateU8(17), CoordinateU8(16), CoordinateU8(19)]), Position3([CoordinateU8(17), CoordinateU8(16), CoordinateU8(20)]), Position3([CoordinateU8(17), CoordinateU8(16), CoordinateU8(21)]), Position3([CoordinateU8(17), CoordinateU8(16), CoordinateU8(22)]), Position3([CoordinateU8(17), CoordinateU8(17), CoordinateU8(5)]), Position3([CoordinateU8(17), CoordinateU8(17), CoordinateU8(6)]), Position3([CoordinateU8(17), CoordinateU8(17), CoordinateU8(7)]), Position3([CoordinateU8(17), CoordinateU8(17), CoordinateU8(8)]), Position3([CoordinateU8(17), CoordinateU8(17), CoordinateU8(9)]), Position3([CoordinateU8(17), CoordinateU8(17), CoordinateU8(10)]), Position3([CoordinateU8(17), CoordinateU8(17), CoordinateU8(11)]), Position3([CoordinateU8(17), CoordinateU8(17), CoordinateU8(12)]), Position3([CoordinateU8(17), CoordinateU8(17), CoordinateU8(13)]), Position3([CoordinateU8(17), CoordinateU8(17), CoordinateU8(14)]), Position3([CoordinateU8(17), CoordinateU8(17), CoordinateU8(15)]), Position3([CoordinateU8(17), CoordinateU8(17), CoordinateU8(16)]), Position3([CoordinateU8(17), CoordinateU8(17), CoordinateU8(17)]), Position3([CoordinateU8(17), CoordinateU8(17), CoordinateU8(18)]), Position3([CoordinateU8(17), CoordinateU8(17), CoordinateU8(19)]), Position3([CoordinateU8(17), CoordinateU8(17), CoordinateU8(20)]), Position3([CoordinateU8(17), CoordinateU8(17), CoordinateU8(21)]), Position3([CoordinateU8(17), CoordinateU8(17), CoordinateU8(22)]), Position3([CoordinateU8(17), CoordinateU8(18), CoordinateU8(5)]), Position3([CoordinateU8(17), CoordinateU8(18), CoordinateU8(6)]), Position3([CoordinateU8(17), CoordinateU8(18), CoordinateU8(7)]), Position3([CoordinateU8(17), CoordinateU8(18), CoordinateU8(8)]), Position3([CoordinateU8(17), CoordinateU8(18), CoordinateU8(9)]), Position3([CoordinateU8(17), CoordinateU8(18), CoordinateU8(10)]), Position3([CoordinateU8(17), CoordinateU8(18), CoordinateU8(11)]), Position3([CoordinateU8(17), CoordinateU8(18), CoordinateU8(12)]), Position3([CoordinateU8(17), CoordinateU8(18), CoordinateU8(13)]), Position3([CoordinateU8(17), CoordinateU8(18), CoordinateU8(14)]), Position3([CoordinateU8(17), CoordinateU8(18), CoordinateU8(15)]), Position3([CoordinateU8(17), CoordinateU8(18), CoordinateU8(16)]), Position3([CoordinateU8(17), CoordinateU8(18), CoordinateU8(17)]), Position3([CoordinateU8(17), CoordinateU8(18), CoordinateU8(18)]), Position3([CoordinateU8(17), CoordinateU8(18), CoordinateU8(19)]), Position3([CoordinateU8(17), CoordinateU8(18), CoordinateU8(20)]), Position3([CoordinateU8(17), CoordinateU8(18), CoordinateU8(21)]), Position3([CoordinateU8(17), CoordinateU8(18), CoordinateU8(22)]), Position3([CoordinateU8(17), CoordinateU8(19), CoordinateU8(5)]), Position3([CoordinateU8(17), CoordinateU8(19), CoordinateU8(6)]), Position3([CoordinateU8(17), CoordinateU8(19), CoordinateU8(7)]), Position3([CoordinateU8(17), CoordinateU8(19), CoordinateU8(8)]), Position3([CoordinateU8(17), CoordinateU8(19), CoordinateU8(9)]), Position3([CoordinateU8(17), CoordinateU8(19), CoordinateU8(10)]), Position3([CoordinateU8(17), CoordinateU8(19), CoordinateU8(11)]), Position3([CoordinateU8(17), CoordinateU8(19), CoordinateU8(12)]), Position3([CoordinateU8(17), CoordinateU8(19), CoordinateU8(13)]), Position3([CoordinateU8(17), CoordinateU8(19), CoordinateU8(14)]), Position3([CoordinateU8(17), CoordinateU8(19), CoordinateU8(15)]), Position3([CoordinateU8(17), CoordinateU8(19), CoordinateU8(16)]), Position3([CoordinateU8(17), CoordinateU8(19), CoordinateU8(17)]), Position3([CoordinateU8(17), CoordinateU8(19), CoordinateU8(18)]), Position3([CoordinateU8(17), CoordinateU8(19), CoordinateU8(19)]), Position3([CoordinateU8(17), CoordinateU8(19), CoordinateU8(20)]), Position3([CoordinateU8(17), CoordinateU8(19), CoordinateU8(21)]), Position3([CoordinateU8(17), CoordinateU8(19), CoordinateU8(22)]), Position3([CoordinateU8(17), CoordinateU8(20), CoordinateU8(5)]), Position3([CoordinateU8(17), CoordinateU8(20), CoordinateU8(6)]), Position3([CoordinateU8(17), CoordinateU8(20), CoordinateU8(7)]), Position3([CoordinateU8(17), CoordinateU8(20), CoordinateU8(8)]), Position3([CoordinateU8(17), CoordinateU8(20), CoordinateU8(9)]), Position3([CoordinateU8(17), CoordinateU8(20), CoordinateU8(10)]), Position3([CoordinateU8(17), CoordinateU8(20), CoordinateU8(11)]), Position3([CoordinateU8(17), CoordinateU8(20), CoordinateU8(12)]), Position3([CoordinateU8(17), CoordinateU8(20), CoordinateU8(13)]), Position3([CoordinateU8(17), CoordinateU8(20), CoordinateU8(14)]), Position3([CoordinateU8(17), CoordinateU8(20), CoordinateU8(15)]), Position3([CoordinateU8(17), CoordinateU8(20), CoordinateU8(16)]), Position3([CoordinateU8(17), CoordinateU8(20), CoordinateU8(17)]), Position3([CoordinateU8(17), CoordinateU8(20), CoordinateU8(18)]), Position3([CoordinateU8(17), CoordinateU8(20), CoordinateU8(19)]), Position3([CoordinateU8(17), CoordinateU8(20), CoordinateU8(20)]), Position3([CoordinateU8(17), CoordinateU8(20), CoordinateU8(21)]), Position3([CoordinateU8(17), CoordinateU8(20), CoordinateU8(22)]), Position3([CoordinateU8(17), CoordinateU8(21), CoordinateU8(5)]), Position3([CoordinateU8(17), CoordinateU8(21), CoordinateU8(6)]), Position3([CoordinateU8(17), CoordinateU8(21), CoordinateU8(7)]), Position3([CoordinateU8(17), CoordinateU8(21), CoordinateU8(8)]), Position3([CoordinateU8(17), CoordinateU8(21), CoordinateU8(9)]), Position3([CoordinateU8(17), CoordinateU8(21), CoordinateU8(10)]), Position3([CoordinateU8(17), CoordinateU8(21), CoordinateU8(11)]), Position3([CoordinateU8(17), CoordinateU8(21), CoordinateU8(12)]), Position3([CoordinateU8(17), CoordinateU8(21), CoordinateU8(13)]), Position3([CoordinateU8(17), CoordinateU8(21), CoordinateU8(14)]), Position3([CoordinateU8(17), CoordinateU8(21), CoordinateU8(15)]), Position3([CoordinateU8(17), CoordinateU8(21), CoordinateU8(16)]), Position3([CoordinateU8(17), CoordinateU8(21), CoordinateU8(17)]), Position3([CoordinateU8(17), CoordinateU8(21), CoordinateU8(18)]), Position3([CoordinateU8(17), CoordinateU8(21), CoordinateU8(19)]), Position3([CoordinateU8(17), CoordinateU8(21), CoordinateU8(20)]), Position3([CoordinateU8(17), CoordinateU8(21), CoordinateU8(21)]), Position3([CoordinateU8(17), CoordinateU8(21), CoordinateU8(22)]), Position3([CoordinateU8(17), CoordinateU8(22), CoordinateU8(5)]), Position3([CoordinateU8(17), CoordinateU8(22), CoordinateU8(6)]), Position3([CoordinateU8(17), CoordinateU8(22), CoordinateU8(7)]), Position3([CoordinateU8(17), CoordinateU8(22), CoordinateU8(8)]), Position3([CoordinateU8(17), CoordinateU8(22), CoordinateU8(9)]), Position3([CoordinateU8(17), CoordinateU8(22), CoordinateU8(10)]), Position3([CoordinateU8(17), CoordinateU8(22), CoordinateU8(11)]), Position3([CoordinateU8(17), CoordinateU8(22), CoordinateU8(12)]), Position3([CoordinateU8(17), CoordinateU8(22), CoordinateU8(13)]), Position3([CoordinateU8(17), CoordinateU8(22), CoordinateU8(14)]), Position3([CoordinateU8(17), CoordinateU8(22), CoordinateU8(15)]), Position3([CoordinateU8(17), CoordinateU8(22), CoordinateU8(16)]), Position3([CoordinateU8(17), CoordinateU8(22), CoordinateU8(17)]), Position3([CoordinateU8(17), CoordinateU8(22), CoordinateU8(18)]), Position3([CoordinateU8(17), CoordinateU8(22), CoordinateU8(19)]), Position3([CoordinateU8(17), CoordinateU8(22), CoordinateU8(20)]), Position3([CoordinateU8(17), CoordinateU8(22), CoordinateU8(21)]), Position3([CoordinateU8(17), CoordinateU8(22), CoordinateU8(22)]), Position3([CoordinateU8(18), CoordinateU8(3), CoordinateU8(5)]), Position3([CoordinateU8(18), CoordinateU8(3), CoordinateU8(6)]), Position3([CoordinateU8(18), CoordinateU8(3), CoordinateU8(7)]), Position3([CoordinateU8(18), CoordinateU8(3), CoordinateU8(8)]), Position3([CoordinateU8(18), CoordinateU8(3), CoordinateU8(9)]), Position3([CoordinateU8(18), CoordinateU8(3), CoordinateU8(10)]), Position3([CoordinateU8(18), CoordinateU8(3), CoordinateU8(11)]), Position3([CoordinateU8(18), CoordinateU8(3), CoordinateU8(12)]), Position3([CoordinateU8(18), CoordinateU8(3), CoordinateU8(13)]), Position3([CoordinateU8(18), CoordinateU8(3), CoordinateU8(14)]), Position3([CoordinateU8(18), CoordinateU8(3), CoordinateU8(15)]), Position3([CoordinateU8(18), CoordinateU8(3), CoordinateU8(16)]), Position3([CoordinateU8(18), CoordinateU8(3), CoordinateU8(17)]), Position3([CoordinateU8(18), CoordinateU8(3), CoordinateU8(18)]), Position3([CoordinateU8(18), CoordinateU8(3), CoordinateU8(19)]), Position3([CoordinateU8(18), CoordinateU8(3), CoordinateU8(20)]), Position3([CoordinateU8(18), CoordinateU8(3), CoordinateU8(21)]), Position3([CoordinateU8(18), CoordinateU8(3), CoordinateU8(22)]), Position3([CoordinateU8(18), CoordinateU8(4), CoordinateU8(5)]), Position3([CoordinateU8(18), CoordinateU8(4), CoordinateU8(6)]), Position3([CoordinateU8(18), CoordinateU8(4), CoordinateU8(7)]), Position3([CoordinateU8(18), CoordinateU8(4), CoordinateU8(8)]), Position3([CoordinateU8(18), CoordinateU8(4), CoordinateU8(9)]), Position3([CoordinateU8(18), CoordinateU8(4), CoordinateU8(10)]), Position3([CoordinateU8(18), CoordinateU8(4), CoordinateU8(11)]), Position3([CoordinateU8(18), CoordinateU8(4), CoordinateU8(12)]), Position3([CoordinateU8(18), CoordinateU8(4), CoordinateU8(13)]), Position3([CoordinateU8(18), CoordinateU8(4), CoordinateU8(14)]), Position3([CoordinateU8(18), CoordinateU8(4), CoordinateU8(15)]), Position3([CoordinateU8(18), CoordinateU8(4), CoordinateU8(16)]), Position3([CoordinateU8(18), CoordinateU8(4), CoordinateU8(17)]), Position3([CoordinateU8(18), CoordinateU8(4), CoordinateU8(18)]), Position3([CoordinateU8(18), CoordinateU8(4), CoordinateU8(19)]), Position3([CoordinateU8(18), CoordinateU8(4), CoordinateU8(20)]), Position3([CoordinateU8(18), CoordinateU8(4), CoordinateU8(21)]), Position3([CoordinateU8(18), CoordinateU8(4), CoordinateU8(22)]), Position3([CoordinateU8(18), CoordinateU8(5), CoordinateU8(5)]), Position3([CoordinateU8(18), CoordinateU8(5), CoordinateU8(6)]), Position3([CoordinateU8(18), CoordinateU8(5), CoordinateU8(7)]), Position3([CoordinateU8(18), CoordinateU8(5), CoordinateU8(8)]), Position3([CoordinateU8(18), CoordinateU8(5), CoordinateU8(9)]), Position3([CoordinateU8(18), CoordinateU8(5), CoordinateU8(10)]), Position3([CoordinateU8(18), CoordinateU8(5), CoordinateU8(11)]), Position3([CoordinateU8(18), CoordinateU8(5), CoordinateU8(12)]), Position3([CoordinateU8(18), CoordinateU8(5), CoordinateU8(13)]), Position3([CoordinateU8(18), CoordinateU8(5), CoordinateU8(14)]), Position3([CoordinateU8(18), CoordinateU8(5), CoordinateU8(15)]), Position3([CoordinateU8(18), CoordinateU8(5), CoordinateU8(16)]), Position3([CoordinateU8(18), CoordinateU8(5), CoordinateU8(17)]), Position3([CoordinateU8(18), CoordinateU8(5), CoordinateU8(18)]), Position3([CoordinateU8(18), CoordinateU8(5), CoordinateU8(19)]), Position3([CoordinateU8(18), CoordinateU8(5), CoordinateU8(20)]), Position3([CoordinateU8(18), CoordinateU8(5), CoordinateU8(21)]), Position3([CoordinateU8(18), CoordinateU8(5), CoordinateU8(22)]), Position3([CoordinateU8(18), CoordinateU8(6), CoordinateU8(5)]), Position3([CoordinateU8(18), CoordinateU8(6), CoordinateU8(6)]), Position3([CoordinateU8(18), CoordinateU8(6), CoordinateU8(7)]), Position3([CoordinateU8(18), CoordinateU8(6), CoordinateU8(8)]), Position3([CoordinateU8(18), CoordinateU8(6), CoordinateU8(9)]), Position3([CoordinateU8(18), CoordinateU8(6), CoordinateU8(10)]), Position3([CoordinateU8(18), CoordinateU8(6), CoordinateU8(11)]), Position3([CoordinateU8(18), CoordinateU8(6), CoordinateU8(12)]), Position3([CoordinateU8(18), CoordinateU8(6), CoordinateU8(13)]), Position3([CoordinateU8(18), CoordinateU8(6), CoordinateU8(14)]), Position3([CoordinateU8(18), CoordinateU8(6), CoordinateU8(15)]), Position3([CoordinateU8(18), CoordinateU8(6), CoordinateU8(16)]), Position3([CoordinateU8(18), CoordinateU8(6), CoordinateU8(17)]), Position3([CoordinateU8(18), CoordinateU8(6), CoordinateU8(18)]), Position3([CoordinateU8(18), CoordinateU8(6), CoordinateU8(19)]), Position3([CoordinateU8(18), CoordinateU8(6), CoordinateU8(20)]), Position3([CoordinateU8(18), CoordinateU8(6), CoordinateU8(21)]), Position3([CoordinateU8(18), CoordinateU8(6), CoordinateU8(22)]), Position3([CoordinateU8(18), CoordinateU8(7), CoordinateU8(5)]), Position3([CoordinateU8(18), CoordinateU8(7), CoordinateU8(6)]), Position3([CoordinateU8(18), CoordinateU8(7), CoordinateU8(7)]), Position3([CoordinateU8(18), CoordinateU8(7), CoordinateU8(8)]), Position3([CoordinateU8(18), CoordinateU8(7), CoordinateU8(9)]), Position3([CoordinateU8(18), CoordinateU8(7), CoordinateU8(10)]), Position3([CoordinateU8(18), CoordinateU8(7), CoordinateU8(11)]), Position3([CoordinateU8(18), CoordinateU8(7), CoordinateU8(12)]), Position3([CoordinateU8(18), CoordinateU8(7), CoordinateU8(13)]), Position3([CoordinateU8(18), CoordinateU8(7), CoordinateU8(14)]), Position3([CoordinateU8(18), CoordinateU8(7), CoordinateU8(15)]), Position3([CoordinateU8(18), CoordinateU8(7), CoordinateU8(16)]), Position3([CoordinateU8(18), CoordinateU8(7), CoordinateU8(17)]), Position3([CoordinateU8(18), CoordinateU8(7), CoordinateU8(18)]), Position3([CoordinateU8(18), CoordinateU8(7), CoordinateU8(19)]), Position3([CoordinateU8(18), CoordinateU8(7), CoordinateU8(20)]), Position3([CoordinateU8(18), CoordinateU8(7), CoordinateU8(21)]), Position3([CoordinateU8(18), CoordinateU8(7), CoordinateU8(22)]), Position3([CoordinateU8(18), CoordinateU8(8), CoordinateU8(5)]), Position3([CoordinateU8(18), CoordinateU8(8), CoordinateU8(6)]), Position3([CoordinateU8(18), CoordinateU8(8), CoordinateU8(7)]), Position3([CoordinateU8(18), CoordinateU8(8), CoordinateU8(8)]), Position3([CoordinateU8(18), CoordinateU8(8), CoordinateU8(9)]), Position3([CoordinateU8(18), CoordinateU8(8), CoordinateU8(10)]), Position3([CoordinateU8(18), CoordinateU8(8), CoordinateU8(11)]), Position3([CoordinateU8(18), CoordinateU8(8), CoordinateU8(12)]), Position3([CoordinateU8(18), CoordinateU8(8), CoordinateU8(13)]), Position3([CoordinateU8(18), CoordinateU8(8), CoordinateU8(14)]), Position3([CoordinateU8(18), CoordinateU8(8), CoordinateU8(15)]), Position3([CoordinateU8(18), CoordinateU8(8), CoordinateU8(16)]), Position3([CoordinateU8(18), CoordinateU8(8), CoordinateU8(17)]), Position3([CoordinateU8(18), CoordinateU8(8), CoordinateU8(18)]), Position3([CoordinateU8(18), CoordinateU8(8), CoordinateU8(19)]), Position3([CoordinateU8(18), CoordinateU8(8), CoordinateU8(20)]), Position3([CoordinateU8(18), CoordinateU8(8), CoordinateU8(21)]), Position3([CoordinateU8(18), CoordinateU8(8), CoordinateU8(22)]), Position3([CoordinateU8(18), CoordinateU8(9), CoordinateU8(5)]), Position3([CoordinateU8(18), CoordinateU8(9), CoordinateU8(6)]), Position3([CoordinateU8(18), CoordinateU8(9), CoordinateU8(7)]), Position3([CoordinateU8(18), CoordinateU8(9), CoordinateU8(8)]), Position3([CoordinateU8(18), CoordinateU8(9), CoordinateU8(9)]), Position3([CoordinateU8(18), CoordinateU8(9), CoordinateU8(10)]), Position3([CoordinateU8(18), CoordinateU8(9), CoordinateU8(11)]), Position3([CoordinateU8(18), CoordinateU8(9), CoordinateU8(12)]), Position3([CoordinateU8(18), CoordinateU8(9), CoordinateU8(13)]), Position3([CoordinateU8(18), CoordinateU8(9), CoordinateU8(14)]), Position3([CoordinateU8(18), CoordinateU8(9), CoordinateU8(15)]), Position3([CoordinateU8(18), CoordinateU8(9), CoordinateU8(16)]), Position3([CoordinateU8(18), CoordinateU8(9), CoordinateU8(17)]), Position3([CoordinateU8(18), CoordinateU8(9), CoordinateU8(18)]), Position3([CoordinateU8(18), CoordinateU8(9), CoordinateU8(19)]), Position3([CoordinateU8(18), CoordinateU8(9), CoordinateU8(20)]), Position3([CoordinateU8(18), CoordinateU8(9), CoordinateU8(21)]), Position3([CoordinateU8(18), CoordinateU8(9), CoordinateU8(22)]), Position3([CoordinateU8(18), CoordinateU8(10), CoordinateU8(5)]), Position3([CoordinateU8(18), CoordinateU8(10), CoordinateU8(6)]), Position3([CoordinateU8(18), CoordinateU8(10), CoordinateU8(7)]), Position3([CoordinateU8(18), CoordinateU8(10), CoordinateU8(8)]), Position3([CoordinateU8(18), CoordinateU8(10), CoordinateU8(9)]), Position3([CoordinateU8(18), CoordinateU8(10), CoordinateU8(10)]), Position3([CoordinateU8(18), CoordinateU8(10), CoordinateU8(11)]), Position3([CoordinateU8(18), CoordinateU8(10), CoordinateU8(12)]), Position3([CoordinateU8(18), CoordinateU8(10), CoordinateU8(13)]), Position3([CoordinateU8(18), CoordinateU8(10), CoordinateU8(14)]), Position3([CoordinateU8(18), CoordinateU8(10), CoordinateU8(15)]), Position3([CoordinateU8(18), CoordinateU8(10), CoordinateU8(16)]), Position3([CoordinateU8(18), CoordinateU8(10), CoordinateU8(17)]), Position3([CoordinateU8(18), CoordinateU8(10), CoordinateU8(18)]), Position3([CoordinateU8(18), CoordinateU8(10), CoordinateU8(19)]), Position3([CoordinateU8(18), CoordinateU8(10), CoordinateU8(20)]), Position3([CoordinateU8(18), CoordinateU8(10), CoordinateU8(21)]), Position3([CoordinateU8(18), CoordinateU8(10), CoordinateU8(22)]), Position3([CoordinateU8(18), CoordinateU8(11), CoordinateU8(5)]), Position3([CoordinateU8(18), CoordinateU8(11), CoordinateU8(6)]), Position3([CoordinateU8(18), CoordinateU8(11), CoordinateU8(7)]), Position3([CoordinateU8(18), CoordinateU8(11), CoordinateU8(8)]), Position3([CoordinateU8(18), CoordinateU8(11), CoordinateU8(9)]), Position3([CoordinateU8(18), CoordinateU8(11), CoordinateU8(10)]), Position3([CoordinateU8(18), CoordinateU8(11), CoordinateU8(11)]), Position3([CoordinateU8(18), CoordinateU8(11), CoordinateU8(12)]), Position3([CoordinateU8(18), CoordinateU8(11), CoordinateU8(13)]), Position3([CoordinateU8(18), CoordinateU8(11), CoordinateU8(14)]), Position3([CoordinateU8(18), CoordinateU8(11), CoordinateU8(15)]), Position3([CoordinateU8(18), CoordinateU8(11), CoordinateU8(16)]), Position3([CoordinateU8(18), CoordinateU8(11), CoordinateU8(17)]), Position3([CoordinateU8(18), CoordinateU8(11), CoordinateU8(18)]), Position3([CoordinateU8(18), CoordinateU8(11), CoordinateU8(19)]), Position3([CoordinateU8(18), CoordinateU8(11), CoordinateU8(20)]), Position3([CoordinateU8(18), CoordinateU8(11), CoordinateU8(21)]), Position3([CoordinateU8(18), CoordinateU8(11), CoordinateU8(22)]), Position3([CoordinateU8(18), CoordinateU8(12), CoordinateU8(5)]), Position3([CoordinateU8(18), CoordinateU8(12), CoordinateU8(6)]), Position3([CoordinateU8(18), CoordinateU8(12), CoordinateU8(7)]), Position3([CoordinateU8(18), CoordinateU8(12), CoordinateU8(8)]), Position3([CoordinateU8(18), CoordinateU8(12), CoordinateU8(9)]), Position3([CoordinateU8(18), CoordinateU8(12), CoordinateU8(10)]), Position3([CoordinateU8(18), CoordinateU8(12), CoordinateU8(11)]), Position3([CoordinateU8(18), CoordinateU8(12), CoordinateU8(12)]), Position3([CoordinateU8(18), CoordinateU8(12), CoordinateU8(13)]), Position3([CoordinateU8(18), CoordinateU8(12), CoordinateU8(14)]), Position3([CoordinateU8(18), CoordinateU8(12), CoordinateU8(15)]), Position3([CoordinateU8(18), CoordinateU8(12), CoordinateU8(16)]), Position3([CoordinateU8(18), CoordinateU8(12), CoordinateU8(17)]), Position3([CoordinateU8(18), CoordinateU8(12), CoordinateU8(18)]), Position3([CoordinateU8(18), CoordinateU8(12), CoordinateU8(19)]), Position3([CoordinateU8(18), CoordinateU8(12), CoordinateU8(20)]), Position3([CoordinateU8(18), CoordinateU8(12), CoordinateU8(21)]), Position3([CoordinateU8(18), CoordinateU8(12), CoordinateU8(22)]), Position3([CoordinateU8(18), CoordinateU8(13), CoordinateU8(5)]), Position3([CoordinateU8(18), CoordinateU8(13), CoordinateU8(6)]), Position3([CoordinateU8(18), CoordinateU8(13), CoordinateU8(7)]), Position3([CoordinateU8(18), CoordinateU8(13), CoordinateU8(8)]), Position3([CoordinateU8(18), CoordinateU8(13), CoordinateU8(9)]), Position3([CoordinateU8(18), CoordinateU8(13), CoordinateU8(10)]), Position3([CoordinateU8(18), CoordinateU8(13), CoordinateU8(11)]), Position3([CoordinateU8(18), CoordinateU8(13), CoordinateU8(12)]), Position3([CoordinateU8(18), CoordinateU8(13), CoordinateU8(13)]), Position3([CoordinateU8(18), CoordinateU8(13), CoordinateU8(14)]), Position3([CoordinateU8(18), CoordinateU8(13), CoordinateU8(15)]), Position3([CoordinateU8(18), CoordinateU8(13), CoordinateU8(16)]), Position3([CoordinateU8(18), CoordinateU8(13), CoordinateU8(17)]), Position3([CoordinateU8(18), CoordinateU8(13), CoordinateU8(18)]), Position3([CoordinateU8(18), CoordinateU8(13), CoordinateU8(19)]), Position3([CoordinateU8(18), CoordinateU8(13), CoordinateU8(20)]), Position3([CoordinateU8(18), CoordinateU8(13), CoordinateU8(21)]), Position3([CoordinateU8(18), CoordinateU8(13), CoordinateU8(22)]), Position3([CoordinateU8(18), CoordinateU8(14), CoordinateU8(5)]), Position3([CoordinateU8(18), CoordinateU8(14), CoordinateU8(6)]), Position3([CoordinateU8(18), CoordinateU8(14), CoordinateU8(7)]), Position3([CoordinateU8(18), CoordinateU8(14), CoordinateU8(8)]), Position3([CoordinateU8(18), CoordinateU8(14), CoordinateU8(9)]), Position3([CoordinateU8(18), CoordinateU8(14), CoordinateU8(10)]), Position3([CoordinateU8(18), CoordinateU8(14), CoordinateU8(11)]), Position3([CoordinateU8(18), CoordinateU8(14), CoordinateU8(12)]), Position3([CoordinateU8(18), CoordinateU8(14), CoordinateU8(13)]), Position3([CoordinateU8(18), CoordinateU8(14), CoordinateU8(14)]), Position3([CoordinateU8(18), CoordinateU8(14), CoordinateU8(15)]), Position3([CoordinateU8(18), CoordinateU8(14), CoordinateU8(16)]), Position3([CoordinateU8(18), CoordinateU8(14), CoordinateU8(17)]), Position3([CoordinateU8(18), CoordinateU8(14), CoordinateU8(18)]), Position3([CoordinateU8(18), CoordinateU8(14), CoordinateU8(19)]), Position3([CoordinateU8(18), CoordinateU8(14), CoordinateU8(20)]), Position3([CoordinateU8(18), CoordinateU8(14), CoordinateU8(21)]), Position3([CoordinateU8(18), CoordinateU8(14), CoordinateU8(22)]), Position3([CoordinateU8(18), CoordinateU8(15), CoordinateU8(5)]), Position3([CoordinateU8(18), CoordinateU8(15), CoordinateU8(6)]), Position3([CoordinateU8(18), CoordinateU8(15), CoordinateU8(7)]), Position3([CoordinateU8(18), CoordinateU8(15), CoordinateU8(8)]), Position3([CoordinateU8(18), CoordinateU8(15), CoordinateU8(9)]), Position3([CoordinateU8(18), CoordinateU8(15), CoordinateU8(10)]), Position3([CoordinateU8(18), CoordinateU8(15), CoordinateU8(11)]), Position3([CoordinateU8(18), CoordinateU8(15), CoordinateU8(12)]), Position3([CoordinateU8(18), CoordinateU8(15), CoordinateU8(13)]), Position3([CoordinateU8(18), CoordinateU8(15), CoordinateU8(14)]), Position3([CoordinateU8(18), CoordinateU8(15), CoordinateU8(15)]), Position3([CoordinateU8(18), CoordinateU8(15), CoordinateU8(16)]), Position3([CoordinateU8(18), CoordinateU8(15), CoordinateU8(17)]), Position3([CoordinateU8(18), CoordinateU8(15), CoordinateU8(18)]), Position3([CoordinateU8(18), CoordinateU8(15), CoordinateU8(19)]), Position3([CoordinateU8(18), CoordinateU8(15), CoordinateU8(20)]), Position3([CoordinateU8(18), CoordinateU8(15), CoordinateU8(21)]), Position3([CoordinateU8(18), CoordinateU8(15), CoordinateU8(22)]), Position3([CoordinateU8(18), CoordinateU8(16), CoordinateU8(5)]), Position3([CoordinateU8(18), CoordinateU8(16), CoordinateU8(6)]), Position3([CoordinateU8(18), CoordinateU8(16), CoordinateU8(7)]), Position3([CoordinateU8(18), CoordinateU8(16), CoordinateU8(8)]), Position3([CoordinateU8(18), CoordinateU8(16), CoordinateU8(9)]), Position3([CoordinateU8(18), CoordinateU8(16), CoordinateU8(10)]), Position3([CoordinateU8(18), CoordinateU8(16), CoordinateU8(11)]), Position3([CoordinateU8(18), CoordinateU8(16), CoordinateU8(12)]), Position3([CoordinateU8(18), CoordinateU8(16), CoordinateU8(13)]), Position3([CoordinateU8(18), CoordinateU8(16), CoordinateU8(14)]), Position3([CoordinateU8(18), CoordinateU8(16), CoordinateU8(15)]), Position3([CoordinateU8(18), CoordinateU8(16), CoordinateU8(16)]), Position3([CoordinateU8(18), CoordinateU8(16), CoordinateU8(17)]), Position3([CoordinateU8(18), CoordinateU8(16), CoordinateU8(18)]), Position3([CoordinateU8(18), CoordinateU8(16), CoordinateU8(19)]), Position3([CoordinateU8(18), CoordinateU8(16), CoordinateU8(20)]), Position3([CoordinateU8(18), CoordinateU8(16), CoordinateU8(21)]), Position3([CoordinateU8(18), CoordinateU8(16), CoordinateU8(22)]), Position3([CoordinateU8(18), CoordinateU8(17), CoordinateU8(5)]), Position3([CoordinateU8(18), CoordinateU8(17), CoordinateU8(6)]), Position3([CoordinateU8(18), CoordinateU8(17), CoordinateU8(7)]), Position3([CoordinateU8(18), CoordinateU8(17), CoordinateU8(8)]), Position3([CoordinateU8(18), CoordinateU8(17), CoordinateU8(9)]), Position3([CoordinateU8(18), CoordinateU8(17), CoordinateU8(10)]), Position3([CoordinateU8(18), CoordinateU8(17), CoordinateU8(11)]), Position3([CoordinateU8(18), CoordinateU8(17), CoordinateU8(12)]), Position3([CoordinateU8(18), CoordinateU8(17), CoordinateU8(13)]), Position3([CoordinateU8(18), CoordinateU8(17), CoordinateU8(14)]), Position3([CoordinateU8(18), CoordinateU8(17), CoordinateU8(15)]), Position3([CoordinateU8(18), CoordinateU8(17), CoordinateU8(16)]), Position3([CoordinateU8(18), CoordinateU8(17), CoordinateU8(17)]), Position3([CoordinateU8(18), CoordinateU8(17), CoordinateU8(18)]), Position3([CoordinateU8(18), CoordinateU8(17), CoordinateU8(19)]), Position3([CoordinateU8(18), CoordinateU8(17), CoordinateU8(20)]), Position3([CoordinateU8(18), CoordinateU8(17), CoordinateU8(21)]), Position3([CoordinateU8(18), CoordinateU8(17), CoordinateU8(22)]), Position3([CoordinateU8(18), CoordinateU8(18), CoordinateU8(5)]), Position3([CoordinateU8(18), CoordinateU8(18), CoordinateU8(6)]), Position3([CoordinateU8(18), CoordinateU8(18), CoordinateU8(7)]), Position3([CoordinateU8(18), CoordinateU8(18), CoordinateU8(8)]), Position3([CoordinateU8(18), CoordinateU8(18), CoordinateU8(9)]), Position3([CoordinateU8(18), CoordinateU8(18), CoordinateU8(10)]), Position3([CoordinateU8(18), CoordinateU8(18), CoordinateU8(11)]), Position3([CoordinateU8(18), CoordinateU8(18), CoordinateU8(12)]), Position3([CoordinateU8(18), CoordinateU8(18), CoordinateU8(13)]), Position3([CoordinateU8(18), CoordinateU8(18), CoordinateU8(14)]), Position3([CoordinateU8(18), CoordinateU8(18), CoordinateU8(15)]), Position3([CoordinateU8(18), CoordinateU8(18), CoordinateU8(16)]), Position3([CoordinateU8(18), CoordinateU8(18), CoordinateU8(17)]), Position3([CoordinateU8(18), CoordinateU8(18), CoordinateU8(18)]), Position3([CoordinateU8(18), CoordinateU8(18), CoordinateU8(19)]), Position3([CoordinateU8(18), CoordinateU8(18), CoordinateU8(20)]), Position3([CoordinateU8(18), CoordinateU8(18), CoordinateU8(21)]), Position3([CoordinateU8(18), CoordinateU8(18), CoordinateU8(22)]), Position3([CoordinateU8(18), CoordinateU8(19), CoordinateU8(5)]), Position3([CoordinateU8(18), CoordinateU8(19), CoordinateU8(6)]), Position3([CoordinateU8(18), CoordinateU8(19), CoordinateU8(7)]), Position3([CoordinateU8(18), CoordinateU8(19), CoordinateU8(8)]), Position3([CoordinateU8(18), CoordinateU8(19), CoordinateU8(9)]), Position3([CoordinateU8(18), CoordinateU8(19), CoordinateU8(10)]), Position3([CoordinateU8(18), CoordinateU8(19), CoordinateU8(11)]), Position3([CoordinateU8(18), CoordinateU8(19), CoordinateU8(12)]), Position3([CoordinateU8(18), CoordinateU8(19), CoordinateU8(13)]), Position3([CoordinateU8(18), CoordinateU8(19), CoordinateU8(14)]), Position3([CoordinateU8(18), CoordinateU8(19), CoordinateU8(15)]), Position3([CoordinateU8(18), CoordinateU8(19), CoordinateU8(16)]), Position3([CoordinateU8(18), CoordinateU8(19), CoordinateU8(17)]), Position3([CoordinateU8(18), CoordinateU8(19), CoordinateU8(18)]), Position3([CoordinateU8(18), CoordinateU8(19), CoordinateU8(19)]), Position3([CoordinateU8(18), CoordinateU8(19), CoordinateU8(20)]), Position3([CoordinateU8(18), CoordinateU8(19), CoordinateU8(21)]), Position3([CoordinateU8(18), CoordinateU8(19), CoordinateU8(22)]), Position3([CoordinateU8(18), CoordinateU8(20), CoordinateU8(5)]), Position3([CoordinateU8(18), CoordinateU8(20), CoordinateU8(6)]), Position3([CoordinateU8(18), CoordinateU8(20), CoordinateU8(7)]), Position3([CoordinateU8(18), CoordinateU8(20), CoordinateU8(8)]), Position3([CoordinateU8(18), CoordinateU8(20), CoordinateU8(9)]), Position3([CoordinateU8(18), CoordinateU8(20), CoordinateU8(10)]), Position3([CoordinateU8(18), CoordinateU8(20), CoordinateU8(11)]), Position3([CoordinateU8(18), CoordinateU8(20), CoordinateU8(12)]), Position3([CoordinateU8(18), CoordinateU8(20), CoordinateU8(13)]), Position3([CoordinateU8(18), CoordinateU8(20), CoordinateU8(14)]), Position3([CoordinateU8(18), CoordinateU8(20), CoordinateU8(15)]), Position3([CoordinateU8(18), CoordinateU8(20), CoordinateU8(16)]), Position3([CoordinateU8(18), CoordinateU8(20), CoordinateU8(17)]), Position3([CoordinateU8(18), CoordinateU8(20), CoordinateU8(18)]), Position3([CoordinateU8(18), CoordinateU8(20), CoordinateU8(19)]), Position3([CoordinateU8(18), CoordinateU8(20), CoordinateU8(20)]), Position3([CoordinateU8(18), CoordinateU8(20), CoordinateU8(21)]), Position3([CoordinateU8(18), CoordinateU8(20), CoordinateU8(22)]), Position3([CoordinateU8(18), CoordinateU8(21), CoordinateU8(5)]), Position3([CoordinateU8(18), CoordinateU8(21), CoordinateU8(6)]), Position3([CoordinateU8(18), CoordinateU8(21), CoordinateU8(7)]), Position3([CoordinateU8(18), CoordinateU8(21), CoordinateU8(8)]), Position3([CoordinateU8(18), CoordinateU8(21), CoordinateU8(9)]), Position3([CoordinateU8(18), CoordinateU8(21), CoordinateU8(10)]), Position3([CoordinateU8(18), CoordinateU8(21), CoordinateU8(11)]), Position3([CoordinateU8(18), CoordinateU8(21), CoordinateU8(12)]), Position3([CoordinateU8(18), CoordinateU8(21), CoordinateU8(13)]), Position3([CoordinateU8(18), CoordinateU8(21), CoordinateU8(14)]), Position3([CoordinateU8(18), CoordinateU8(21), CoordinateU8(15)]), Position3([CoordinateU8(18), CoordinateU8(21), CoordinateU8(16)]), Position3([CoordinateU8(18), CoordinateU8(21), CoordinateU8(17)]), Position3([CoordinateU8(18), CoordinateU8(21), CoordinateU8(18)]), Position3([CoordinateU8(18), CoordinateU8(21), CoordinateU8(19)]), Position3([CoordinateU8(18), CoordinateU8(21), CoordinateU8(20)]), Position3([CoordinateU8(18), CoordinateU8(21), CoordinateU8(21)]), Position3([CoordinateU8(18), CoordinateU8(21), CoordinateU8(22)]), Position3([CoordinateU8(18), CoordinateU8(22), CoordinateU8(5)]), Position3([CoordinateU8(18), CoordinateU8(22), CoordinateU8(6)]), Position3([CoordinateU8(18), CoordinateU8(22), CoordinateU8(7)]), Position3([CoordinateU8(18), CoordinateU8(22), CoordinateU8(8)]), Position3([CoordinateU8(18), CoordinateU8(22), CoordinateU8(9)]), Position3([CoordinateU8(18), CoordinateU8(22), CoordinateU8(10)]), Position3([CoordinateU8(18), CoordinateU8(22), CoordinateU8(11)]), Position3([CoordinateU8(18), CoordinateU8(22), CoordinateU8(12)]), Position3([CoordinateU8(18), CoordinateU8(22), CoordinateU8(13)]), Position3([CoordinateU8(18), CoordinateU8(22), CoordinateU8(14)]), Position3([CoordinateU8(18), CoordinateU8(22), CoordinateU8(15)]), Position3([CoordinateU8(18), CoordinateU8(22), CoordinateU8(16)]), Position3([CoordinateU8(18), CoordinateU8(22), CoordinateU8(17)]), Position3([CoordinateU8(18), CoordinateU8(22), CoordinateU8(18)]), Position3([CoordinateU8(18), CoordinateU8(22), CoordinateU8(19)]), Position3([CoordinateU8(18), CoordinateU8(22), CoordinateU8(20)]), Position3([CoordinateU8(18), CoordinateU8(22), CoordinateU8(21)]), Position3([CoordinateU8(18), CoordinateU8(22), CoordinateU8(22)]), Position3([CoordinateU8(19), CoordinateU8(3), CoordinateU8(5)]), Position3([CoordinateU8(19), CoordinateU8(3), CoordinateU8(6)]), Position3([CoordinateU8(19), CoordinateU8(3), CoordinateU8(7)]), Position3([CoordinateU8(19), CoordinateU8(3), CoordinateU8(8)]), Position3([CoordinateU8(19), CoordinateU8(3), CoordinateU8(9)]), Position3([CoordinateU8(19), CoordinateU8(3), CoordinateU8(10)]), Position3([CoordinateU8(19), CoordinateU8(3), CoordinateU8(11)]), Position3([CoordinateU8(19), CoordinateU8(3), CoordinateU8(12)]), Position3([CoordinateU8(19), CoordinateU8(3), CoordinateU8(13)]), Position3([CoordinateU8(19), CoordinateU8(3), CoordinateU8(14)]), Position3([CoordinateU8(19), CoordinateU8(3), CoordinateU8(15)]), Position3([CoordinateU8(19), CoordinateU8(3), CoordinateU8(16)]), Position3([CoordinateU8(19), CoordinateU8(3), CoordinateU8(17)]), Position3([CoordinateU8(19), CoordinateU8(3), CoordinateU8(18)]), Position3([CoordinateU8(19), CoordinateU8(3), CoordinateU8(19)]), Position3([CoordinateU8(19), CoordinateU8(3), CoordinateU8(20)]), Position3([CoordinateU8(19), CoordinateU8(3), CoordinateU8(21)]), Position3([CoordinateU8(19), CoordinateU8(3), CoordinateU8(22)]), Position3([CoordinateU8(19), CoordinateU8(4), CoordinateU8(5)]), Position3([CoordinateU8(19), CoordinateU8(4), CoordinateU8(6)]), Position3([CoordinateU8(19), CoordinateU8(4), CoordinateU8(7)]), Position3([CoordinateU8(19), CoordinateU8(4), CoordinateU8(8)]), Position3([CoordinateU8(19), CoordinateU8(4), CoordinateU8(9)]), Position3([CoordinateU8(19), CoordinateU8(4), CoordinateU8(10)]), Position3([CoordinateU8(19), CoordinateU8(4), CoordinateU8(11)]), Position3([CoordinateU8(19), CoordinateU8(4), CoordinateU8(12)]), Position3([CoordinateU8(19), CoordinateU8(4), CoordinateU8(13)]), Position3([CoordinateU8(19), CoordinateU8(4), CoordinateU8(14)]), Position3([CoordinateU8(19), CoordinateU8(4), CoordinateU8(15)]), Position3([CoordinateU8(19), CoordinateU8(4), CoordinateU8(16)]), Position3([CoordinateU8(19), CoordinateU8(4), CoordinateU8(17)]), Position3([CoordinateU8(19), CoordinateU8(4), CoordinateU8(18)]), Position3([CoordinateU8(19), CoordinateU8(4), CoordinateU8(19)]), Position3([CoordinateU8(19), CoordinateU8(4), CoordinateU8(20)]), Position3([CoordinateU8(19), CoordinateU8(4), CoordinateU8(21)]), Position3([CoordinateU8(19), CoordinateU8(4), CoordinateU8(22)]), Position3([CoordinateU8(19), CoordinateU8(5), CoordinateU8(5)]), Position3([CoordinateU8(19), CoordinateU8(5), CoordinateU8(6)]), Position3([CoordinateU8(19), CoordinateU8(5), CoordinateU8(7)]), Position3([CoordinateU8(19), CoordinateU8(5), CoordinateU8(8)]), Position3([CoordinateU8(19), CoordinateU8(5), CoordinateU8(9)]), Position3([CoordinateU8(19), CoordinateU8(5), CoordinateU8(10)]), Position3([CoordinateU8(19), CoordinateU8(5), CoordinateU8(11)]), Position3([CoordinateU8(19), CoordinateU8(5), CoordinateU8(12)]), Position3([CoordinateU8(19), CoordinateU8(5), CoordinateU8(13)]), Position3([CoordinateU8(19), CoordinateU8(5), CoordinateU8(14)]), Position3([CoordinateU8(19), CoordinateU8(5), CoordinateU8(15)]), Position3([CoordinateU8(19), CoordinateU8(5), CoordinateU8(16)]), Position3([CoordinateU8(19), CoordinateU8(5), CoordinateU8(17)]), Position3([CoordinateU8(19), CoordinateU8(5), CoordinateU8(18)]), Position3([CoordinateU8(19), CoordinateU8(5), CoordinateU8(19)]), Position3([CoordinateU8(19), CoordinateU8(5), CoordinateU8(20)]), Position3([CoordinateU8(19), CoordinateU8(5), CoordinateU8(21)]), Position3([CoordinateU8(19), CoordinateU8(5), CoordinateU8(22)]), Position3([CoordinateU8(19), CoordinateU8(6), CoordinateU8(5)]), Position3([CoordinateU8(19), CoordinateU8(6), CoordinateU8(6)]), Position3([CoordinateU8(19), CoordinateU8(6), CoordinateU8(7)]), Position3([CoordinateU8(19), CoordinateU8(6), CoordinateU8(8)]), Position3([CoordinateU8(19), CoordinateU8(6), CoordinateU8(9)]), Position3([CoordinateU8(19), CoordinateU8(6), CoordinateU8(10)]), Position3([CoordinateU8(19), CoordinateU8(6), CoordinateU8(11)]), Position3([CoordinateU8(19), CoordinateU8(6), CoordinateU8(12)]), Position3([CoordinateU8(19), CoordinateU8(6), CoordinateU8(13)]), Position3([CoordinateU8(19), CoordinateU8(6), CoordinateU8(14)]), Position3([CoordinateU8(19), CoordinateU8(6), CoordinateU8(15)]), Position3([CoordinateU8(19), CoordinateU8(6), CoordinateU8(16)]), Position3([CoordinateU8(19), CoordinateU8(6), CoordinateU8(17)]), Position3([CoordinateU8(19), CoordinateU8(6), CoordinateU8(18)]), Position3([CoordinateU8(19), CoordinateU8(6), CoordinateU8(19)]), Position3([CoordinateU8(19), CoordinateU8(6), CoordinateU8(20)]), Position3([CoordinateU8(19), CoordinateU8(6), CoordinateU8(21)]), Position3([CoordinateU8(19), CoordinateU8(6), CoordinateU8(22)]), Position3([CoordinateU8(19), CoordinateU8(7), CoordinateU8(5)]), Position3([CoordinateU8(19), CoordinateU8(7), CoordinateU8(6)]), Position3([CoordinateU8(19), CoordinateU8(7), CoordinateU8(7)]), Position3([CoordinateU8(19), CoordinateU8(7), CoordinateU8(8)]), Position3([CoordinateU8(19), CoordinateU8(7), CoordinateU8(9)]), Position3([CoordinateU8(19), CoordinateU8(7), CoordinateU8(10)]), Position3([CoordinateU8(19), CoordinateU8(7), CoordinateU8(11)]), Position3([CoordinateU8(19), CoordinateU8(7), CoordinateU8(12)]), Position3([CoordinateU8(19), CoordinateU8(7), CoordinateU8(13)]), Position3([CoordinateU8(19), CoordinateU8(7), CoordinateU8(14)]), Position3([CoordinateU8(19), CoordinateU8(7), CoordinateU8(15)]), Position3([CoordinateU8(19), CoordinateU8(7), CoordinateU8(16)]), Position3([CoordinateU8(19), CoordinateU8(7), CoordinateU8(17)]), Position3([CoordinateU8(19), CoordinateU8(7), CoordinateU8(18)]), Position3([CoordinateU8(19), CoordinateU8(7), CoordinateU8(19)]), Position3([CoordinateU8(19), CoordinateU8(7), CoordinateU8(20)]), Position3([CoordinateU8(19), CoordinateU8(7), CoordinateU8(21)]), Position3([CoordinateU8(19), CoordinateU8(7), CoordinateU8(22)]), Position3([CoordinateU8(19), CoordinateU8(8), CoordinateU8(5)]), Position3([CoordinateU8(19), CoordinateU8(8), CoordinateU8(6)]), Position3([CoordinateU8(19), CoordinateU8(8), CoordinateU8(7)]), Position3([CoordinateU8(19), CoordinateU8(8), CoordinateU8(8)]), Position3([CoordinateU8(19), CoordinateU8(8), CoordinateU8(9)]), Position3([CoordinateU8(19), CoordinateU8(8), CoordinateU8(10)]), Position3([CoordinateU8(19), CoordinateU8(8), CoordinateU8(11)]), Position3([CoordinateU8(19), CoordinateU8(8), CoordinateU8(12)]), Position3([CoordinateU8(19), CoordinateU8(8), CoordinateU8(13)]), Position3([CoordinateU8(19), CoordinateU8(8), CoordinateU8(14)]), Position3([CoordinateU8(19), CoordinateU8(8), CoordinateU8(15)]), Position3([CoordinateU8(19), CoordinateU8(8), CoordinateU8(16)]), Position3([CoordinateU8(19), CoordinateU8(8), CoordinateU8(17)]), Position3([CoordinateU8(19), CoordinateU8(8), CoordinateU8(18)]), Position3([CoordinateU8(19), CoordinateU8(8), CoordinateU8(19)]), Position3([CoordinateU8(19), CoordinateU8(8), CoordinateU8(20)]), Position3([CoordinateU8(19), CoordinateU8(8), CoordinateU8(21)]), Position3([CoordinateU8(19), CoordinateU8(8), CoordinateU8(22)]), Position3([CoordinateU8(19), CoordinateU8(9), CoordinateU8(5)]), Position3([CoordinateU8(19), CoordinateU8(9), CoordinateU8(6)]), Position3([CoordinateU8(19), CoordinateU8(9), CoordinateU8(7)]), Position3([CoordinateU8(19), CoordinateU8(9), CoordinateU8(8)]), Position3([CoordinateU8(19), CoordinateU8(9), CoordinateU8(9)]), Position3([CoordinateU8(19), CoordinateU8(9), CoordinateU8(10)]), Position3([CoordinateU8(19), CoordinateU8(9), CoordinateU8(11)]), Position3([CoordinateU8(19), CoordinateU8(9), CoordinateU8(12)]), Position3([CoordinateU8(19), CoordinateU8(9), CoordinateU8(13)]), Position3([CoordinateU8(19), CoordinateU8(9), CoordinateU8(14)]), Position3([CoordinateU8(19), CoordinateU8(9), CoordinateU8(15)]), Position3([CoordinateU8(19), CoordinateU8(9), CoordinateU8(16)]), Position3([CoordinateU8(19), CoordinateU8(9), CoordinateU8(17)]), Position3([CoordinateU8(19), CoordinateU8(9), CoordinateU8(18)]), Position3([CoordinateU8(19), CoordinateU8(9), CoordinateU8(19)]), Position3([CoordinateU8(19), CoordinateU8(9), CoordinateU8(20)]), Position3([CoordinateU8(19), CoordinateU8(9), CoordinateU8(21)]), Position3([CoordinateU8(19), CoordinateU8(9), CoordinateU8(22)]), Position3([CoordinateU8(19), CoordinateU8(10), CoordinateU8(5)]), Position3([CoordinateU8(19), CoordinateU8(10), CoordinateU8(6)]), Position3([CoordinateU8(19), CoordinateU8(10), CoordinateU8(7)]), Position3([CoordinateU8(19), CoordinateU8(10), CoordinateU8(8)]), Position3([CoordinateU8(19), CoordinateU8(10), CoordinateU8(9)]), Position3([CoordinateU8(19), CoordinateU8(10), CoordinateU8(10)]), Position3([CoordinateU8(19), CoordinateU8(10), CoordinateU8(11)]), Position3([CoordinateU8(19), CoordinateU8(10), CoordinateU8(12)]), Position3([CoordinateU8(19), CoordinateU8(10), CoordinateU8(13)]), Position3([CoordinateU8(19), CoordinateU8(10), CoordinateU8(14)]), Position3([CoordinateU8(19), CoordinateU8(10), CoordinateU8(15)]), Position3([CoordinateU8(19), CoordinateU8(10), CoordinateU8(16)]), Position3([CoordinateU8(19), CoordinateU8(10), CoordinateU8(17)]), Position3([CoordinateU8(19), CoordinateU8(10), CoordinateU8(18)]), Position3([CoordinateU8(19), CoordinateU8(10), CoordinateU8(19)]), Position3([CoordinateU8(19), CoordinateU8(10), CoordinateU8(20)]), Position3([CoordinateU8(19), CoordinateU8(10), CoordinateU8(21)]), Position3([CoordinateU8(19), CoordinateU8(10), CoordinateU8(22)]), Position3([CoordinateU8(19), CoordinateU8(11), CoordinateU8(5)]), Position3([CoordinateU8(19), CoordinateU8(11), CoordinateU8(6)]), Position3([CoordinateU8(19), CoordinateU8(11), CoordinateU8(7)]), Position3([CoordinateU8(19), CoordinateU8(11), CoordinateU8(8)]), Position3([CoordinateU8(19), CoordinateU8(11), CoordinateU8(9)]), Position3([CoordinateU8(19), CoordinateU8(11), CoordinateU8(10)]), Position3([CoordinateU8(19), CoordinateU8(11), CoordinateU8(11)]), Position3([CoordinateU8(19), CoordinateU8(11), CoordinateU8(12)]), Position3([CoordinateU8(19), CoordinateU8(11), CoordinateU8(13)]), Position3([CoordinateU8(19), CoordinateU8(11), CoordinateU8(14)]), Position3([CoordinateU8(19), CoordinateU8(11), CoordinateU8(15)]), Position3([CoordinateU8(19), CoordinateU8(11), CoordinateU8(16)]), Position3([CoordinateU8(19), CoordinateU8(11), CoordinateU8(17)]), Position3([CoordinateU8(19), CoordinateU8(11), CoordinateU8(18)]), Position3([CoordinateU8(19), CoordinateU8(11), CoordinateU8(19)]), Position3([CoordinateU8(19), CoordinateU8(11), CoordinateU8(20)]), Position3([CoordinateU8(19), CoordinateU8(11), CoordinateU8(21)]), Position3([CoordinateU8(19), CoordinateU8(11), CoordinateU8(22)]), Position3([CoordinateU8(19), CoordinateU8(12), CoordinateU8(5)]), Position3([CoordinateU8(19), CoordinateU8(12), CoordinateU8(6)]), Position3([CoordinateU8(19), CoordinateU8(12), CoordinateU8(7)]), Position3([CoordinateU8(19), CoordinateU8(12), CoordinateU8(8)]), Position3([CoordinateU8(19), CoordinateU8(12), CoordinateU8(9)]), Position3([CoordinateU8(19), CoordinateU8(12), CoordinateU8(10)]), Position3([CoordinateU8(19), CoordinateU8(12), CoordinateU8(11)]), Position3([CoordinateU8(19), CoordinateU8(12), CoordinateU8(12)]), Position3([CoordinateU8(19), CoordinateU8(12), CoordinateU8(13)]), Position3([CoordinateU8(19), CoordinateU8(12), CoordinateU8(14)]), Position3([CoordinateU8(19), CoordinateU8(12), CoordinateU8(15)]), Position3([CoordinateU8(19), CoordinateU8(12), CoordinateU8(16)]), Position3([CoordinateU8(19), CoordinateU8(12), CoordinateU8(17)]), Position3([CoordinateU8(19), CoordinateU8(12), CoordinateU8(18)]), Position3([CoordinateU8(19), CoordinateU8(12), CoordinateU8(19)]), Position3([CoordinateU8(19), CoordinateU8(12), CoordinateU8(20)]), Position3([CoordinateU8(19), CoordinateU8(12), CoordinateU8(21)]), Position3([CoordinateU8(19), CoordinateU8(12), CoordinateU8(22)]), Position3([CoordinateU8(19), CoordinateU8(13), CoordinateU8(5)]), Position3([CoordinateU8(19), CoordinateU8(13), CoordinateU8(6)]), Position3([CoordinateU8(19), CoordinateU8(13), CoordinateU8(7)]), Position3([CoordinateU8(19), CoordinateU8(13), CoordinateU8(8)]), Position3([CoordinateU8(19), CoordinateU8(13), CoordinateU8(9)]), Position3([CoordinateU8(19), CoordinateU8(13), CoordinateU8(10)]), Position3([CoordinateU8(19), CoordinateU8(13), CoordinateU8(11)]), Position3([CoordinateU8(19), CoordinateU8(13), CoordinateU8(12)]), Position3([CoordinateU8(19), CoordinateU8(13), CoordinateU8(13)]), Position3([CoordinateU8(19), CoordinateU8(13), CoordinateU8(14)]), Position3([CoordinateU8(19), CoordinateU8(13), CoordinateU8(15)]), Position3([CoordinateU8(19), CoordinateU8(13), CoordinateU8(16)]), Position3([CoordinateU8(19), CoordinateU8(13), CoordinateU8(17)]), Position3([CoordinateU8(19), CoordinateU8(13), CoordinateU8(18)]), Position3([CoordinateU8(19), CoordinateU8(13), CoordinateU8(19)]), Position3([CoordinateU8(19), CoordinateU8(13), CoordinateU8(20)]), Position3([CoordinateU8(19), CoordinateU8(13), CoordinateU8(21)]), Position3([CoordinateU8(19), CoordinateU8(13), CoordinateU8(22)]), Position3([CoordinateU8(19), CoordinateU8(14), CoordinateU8(5)]), Position3([CoordinateU8(19), CoordinateU8(14), CoordinateU8(6)]), Position3([CoordinateU8(19), CoordinateU8(14), CoordinateU8(7)]), Position3([CoordinateU8(19), CoordinateU8(14), CoordinateU8(8)]), Position3([CoordinateU8(19), CoordinateU8(14), CoordinateU8(9)]), Position3([CoordinateU8(19), CoordinateU8(14), CoordinateU8(10)]), Position3([CoordinateU8(19), CoordinateU8(14), CoordinateU8(11)]), Position3([CoordinateU8(19), CoordinateU8(14), CoordinateU8(12)]), Position3([CoordinateU8(19), CoordinateU8(14), CoordinateU8(13)]), Position3([CoordinateU8(19), CoordinateU8(14), CoordinateU8(14)]), Position3([CoordinateU8(19), CoordinateU8(14), CoordinateU8(15)]), Position3([CoordinateU8(19), CoordinateU8(14), CoordinateU8(16)]), Position3([CoordinateU8(19), CoordinateU8(14), CoordinateU8(17)]), Position3([CoordinateU8(19), CoordinateU8(14), CoordinateU8(18)]), Position3([CoordinateU8(19), CoordinateU8(14), CoordinateU8(19)]), Position3([CoordinateU8(19), CoordinateU8(14), CoordinateU8(20)]), Position3([CoordinateU8(19), CoordinateU8(14), CoordinateU8(21)]), Position3([CoordinateU8(19), CoordinateU8(14), CoordinateU8(22)]), Position3([CoordinateU8(19), CoordinateU8(15), CoordinateU8(5)]), Position3([CoordinateU8(19), CoordinateU8(15), CoordinateU8(6)]), Position3([CoordinateU8(19), CoordinateU8(15), CoordinateU8(7)]), Position3([CoordinateU8(19), CoordinateU8(15), CoordinateU8(8)]), Position3([CoordinateU8(19), CoordinateU8(15), CoordinateU8(9)]), Position3([CoordinateU8(19), CoordinateU8(15), CoordinateU8(10)]), Position3([CoordinateU8(19), CoordinateU8(15), CoordinateU8(11)]), Position3([CoordinateU8(19), CoordinateU8(15), CoordinateU8(12)]), Position3([CoordinateU8(19), CoordinateU8(15), CoordinateU8(13)]), Position3([CoordinateU8(19), CoordinateU8(15), CoordinateU8(14)]), Position3([CoordinateU8(19), CoordinateU8(15), CoordinateU8(15)]), Position3([CoordinateU8(19), CoordinateU8(15), CoordinateU8(16)]), Position3([CoordinateU8(19), CoordinateU8(15), CoordinateU8(17)]), Position3([CoordinateU8(19), CoordinateU8(15), CoordinateU8(18)]), Position3([CoordinateU8(19), CoordinateU8(15), CoordinateU8(19)]), Position3([CoordinateU8(19), CoordinateU8(15), CoordinateU8(20)]), Position3([CoordinateU8(19), CoordinateU8(15), CoordinateU8(21)]), Position3([CoordinateU8(19), CoordinateU8(15), CoordinateU8(22)]), Position3([CoordinateU8(19), CoordinateU8(16), CoordinateU8(5)]), Position3([CoordinateU8(19), CoordinateU8(16), CoordinateU8(6)]), Position3([CoordinateU8(19), CoordinateU8(16), CoordinateU8(7)]), Position3([CoordinateU8(19), CoordinateU8(16), CoordinateU8(8)]), Position3([CoordinateU8(19), CoordinateU8(16), CoordinateU8(9)]), Position3([CoordinateU8(19), CoordinateU8(16), CoordinateU8(10)]), Position3([CoordinateU8(19), CoordinateU8(16), CoordinateU8(11)]), Position3([CoordinateU8(19), CoordinateU8(16), CoordinateU8(12)]), Position3([CoordinateU8(19), CoordinateU8(16), CoordinateU8(13)]), Position3([CoordinateU8(19), CoordinateU8(16), CoordinateU8(14)]), Position3([CoordinateU8(19), CoordinateU8(16), CoordinateU8(15)]), Position3([CoordinateU8(19), CoordinateU8(16), CoordinateU8(16)]), Position3([CoordinateU8(19), CoordinateU8(16), CoordinateU8(17)]), Position3([CoordinateU8(19), CoordinateU8(16), CoordinateU8(18)]), Position3([CoordinateU8(19), CoordinateU8(16), CoordinateU8(19)]), Position3([CoordinateU8(19), CoordinateU8(16), CoordinateU8(20)]), Position3([CoordinateU8(19), CoordinateU8(16), CoordinateU8(21)]), Position3([CoordinateU8(19), CoordinateU8(16), CoordinateU8(22)]), Position3([CoordinateU8(19), CoordinateU8(17), CoordinateU8(5)]), Position3([CoordinateU8(19), CoordinateU8(17), CoordinateU8(6)]), Position3([CoordinateU8(19), CoordinateU8(17), CoordinateU8(7)]), Position3([CoordinateU8(19), CoordinateU8(17), CoordinateU8(8)]), Position3([CoordinateU8(19), CoordinateU8(17), CoordinateU8(9)]), Position3([CoordinateU8(19), CoordinateU8(17), CoordinateU8(10)]), Position3([CoordinateU8(19), CoordinateU8(17), CoordinateU8(11)]), Position3([CoordinateU8(19), CoordinateU8(17), CoordinateU8(12)]), Position3([CoordinateU8(19), CoordinateU8(17), CoordinateU8(13)]), Position3([CoordinateU8(19), CoordinateU8(17), CoordinateU8(14)]), Position3([CoordinateU8(19), CoordinateU8(17), CoordinateU8(15)]), Position3([CoordinateU8(19), CoordinateU8(17), CoordinateU8(16)]), Position3([CoordinateU8(19), CoordinateU8(17), CoordinateU8(17)]), Position3([CoordinateU8(19), CoordinateU8(17), CoordinateU8(18)]), Position3([CoordinateU8(19), CoordinateU8(17), CoordinateU8(19)]), Position3([CoordinateU8(19), CoordinateU8(17), CoordinateU8(20)]), Position3([CoordinateU8(19), CoordinateU8(17), CoordinateU8(21)]), Position3([CoordinateU8(19), CoordinateU8(17), CoordinateU8(22)]), Position3([CoordinateU8(19), CoordinateU8(18), CoordinateU8(5)]), Position3([CoordinateU8(19), CoordinateU8(18), CoordinateU8(6)]), Position3([CoordinateU8(19), CoordinateU8(18), CoordinateU8(7)]), Position3([CoordinateU8(19), CoordinateU8(18), CoordinateU8(8)]), Position3([CoordinateU8(19), CoordinateU8(18), CoordinateU8(9)]), Position3([CoordinateU8(19), CoordinateU8(18), CoordinateU8(10)]), Position3([CoordinateU8(19), CoordinateU8(18), CoordinateU8(11)]), Position3([CoordinateU8(19), CoordinateU8(18), CoordinateU8(12)]), Position3([CoordinateU8(19), CoordinateU8(18), CoordinateU8(13)]), Position3([CoordinateU8(19), CoordinateU8(18), CoordinateU8(14)]), Position3([CoordinateU8(19), CoordinateU8(18), CoordinateU8(15)]), Position3([CoordinateU8(19), CoordinateU8(18), CoordinateU8(16)]), Position3([CoordinateU8(19), CoordinateU8(18), CoordinateU8(17)]), Position3([CoordinateU8(19), CoordinateU8(18), CoordinateU8(18)]), Position3([CoordinateU8(19), CoordinateU8(18), CoordinateU8(19)]), Position3([CoordinateU8(19), CoordinateU8(18), CoordinateU8(20)]), Position3([CoordinateU8(19), CoordinateU8(18), CoordinateU8(21)]), Position3([CoordinateU8(19), CoordinateU8(18), CoordinateU8(22)]), Position3([CoordinateU8(19), CoordinateU8(19), CoordinateU8(5)]), Position3([CoordinateU8(19), CoordinateU8(19), CoordinateU8(6)]), Position3([CoordinateU8(19), CoordinateU8(19), CoordinateU8(7)]), Position3([CoordinateU8(19), CoordinateU8(19), CoordinateU8(8)]), Position3([CoordinateU8(19), CoordinateU8(19), CoordinateU8(9)]), Position3([CoordinateU8(19), CoordinateU8(19), CoordinateU8(10)]), Position3([CoordinateU8(19), CoordinateU8(19), CoordinateU8(11)]), Position3([CoordinateU8(19), CoordinateU8(19), CoordinateU8(12)]), Position3([CoordinateU8(19), CoordinateU8(19), CoordinateU8(13)]), Position3([CoordinateU8(19), CoordinateU8(19), CoordinateU8(14)]), Position3([CoordinateU8(19), CoordinateU8(19), CoordinateU8(15)]), Position3([CoordinateU8(19), CoordinateU8(19), CoordinateU8(16)]), Position3([CoordinateU8(19), CoordinateU8(19), CoordinateU8(17)]), Position3([CoordinateU8(19), CoordinateU8(19), CoordinateU8(18)]), Position3([CoordinateU8(19), CoordinateU8(19), CoordinateU8(19)]), Position3([CoordinateU8(19), CoordinateU8(19), CoordinateU8(20)]), Position3([CoordinateU8(19), CoordinateU8(19), CoordinateU8(21)]), Position3([CoordinateU8(19), CoordinateU8(19), CoordinateU8(22)]), Position3([CoordinateU8(19), CoordinateU8(20), CoordinateU8(5)]), Position3([CoordinateU8(19), CoordinateU8(20), CoordinateU8(6)]), Position3([CoordinateU8(19), CoordinateU8(20), CoordinateU8(7)]), Position3([CoordinateU8(19), CoordinateU8(20), CoordinateU8(8)]), Position3([CoordinateU8(19), CoordinateU8(20), CoordinateU8(9)]), Position3([CoordinateU8(19), CoordinateU8(20), CoordinateU8(10)]), Position3([CoordinateU8(19), CoordinateU8(20), CoordinateU8(11)]), Position3([CoordinateU8(19), CoordinateU8(20), CoordinateU8(12)]), Position3([CoordinateU8(19), CoordinateU8(20), CoordinateU8(13)]), Position3([CoordinateU8(19), CoordinateU8(20), CoordinateU8(14)]), Position3([CoordinateU8(19), CoordinateU8(20), CoordinateU8(15)]), Position3([CoordinateU8(19), CoordinateU8(20), CoordinateU8(16)]), Position3([CoordinateU8(19), CoordinateU8(20), CoordinateU8(17)]), Position3([CoordinateU8(19), CoordinateU8(20), CoordinateU8(18)]), Position3([CoordinateU8(19), CoordinateU8(20), CoordinateU8(19)]), Position3([CoordinateU8(19), CoordinateU8(20), CoordinateU8(20)]), Position3([CoordinateU8(19), CoordinateU8(20), CoordinateU8(21)]), Position3([CoordinateU8(19), CoordinateU8(20), CoordinateU8(22)]), Position3([CoordinateU8(19), CoordinateU8(21), CoordinateU8(5)]), Position3([CoordinateU8(19), CoordinateU8(21), CoordinateU8(6)]), Position3([CoordinateU8(19), CoordinateU8(21), CoordinateU8(7)]), Position3([CoordinateU8(19), CoordinateU8(21), CoordinateU8(8)]), Position3([CoordinateU8(19), CoordinateU8(21), CoordinateU8(9)]), Position3([CoordinateU8(19), CoordinateU8(21), CoordinateU8(10)]), Position3([CoordinateU8(19), CoordinateU8(21), CoordinateU8(11)]), Position3([CoordinateU8(19), CoordinateU8(21), CoordinateU8(12)]), Position3([CoordinateU8(19), CoordinateU8(21), CoordinateU8(13)]), Position3([CoordinateU8(19), CoordinateU8(21), CoordinateU8(14)]), Position3([CoordinateU8(19), CoordinateU8(21), CoordinateU8(15)]), Position3([CoordinateU8(19), CoordinateU8(21), CoordinateU8(16)]), Position3([CoordinateU8(19), CoordinateU8(21), CoordinateU8(17)]), Position3([CoordinateU8(19), CoordinateU8(21), CoordinateU8(18)]), Position3([CoordinateU8(19), CoordinateU8(21), CoordinateU8(19)]), Position3([CoordinateU8(19), CoordinateU8(21), CoordinateU8(20)]), Position3([CoordinateU8(19), CoordinateU8(21), CoordinateU8(21)]), Position3([CoordinateU8(19), CoordinateU8(21), CoordinateU8(22)]), Position3([CoordinateU8(19), CoordinateU8(22), CoordinateU8(5)]), Position3([CoordinateU8(19), CoordinateU8(22), CoordinateU8(6)]), Position3([CoordinateU8(19), CoordinateU8(22), CoordinateU8(7)]), Position3([CoordinateU8(19), CoordinateU8(22), CoordinateU8(8)]), Position3([CoordinateU8(19), CoordinateU8(22), CoordinateU8(9)]), Position3([CoordinateU8(19), CoordinateU8(22), CoordinateU8(10)]), Position3([CoordinateU8(19), CoordinateU8(22), CoordinateU8(11)]), Position3([CoordinateU8(19), CoordinateU8(22), CoordinateU8(12)]), Position3([CoordinateU8(19), CoordinateU8(22), CoordinateU8(13)]), Position3([CoordinateU8(19), CoordinateU8(22), CoordinateU8(14)]), Position3([CoordinateU8(19), CoordinateU8(22), CoordinateU8(15)]), Position3([CoordinateU8(19), CoordinateU8(22), CoordinateU8(16)]), Position3([CoordinateU8(19), CoordinateU8(22), CoordinateU8(17)]), Position3([CoordinateU8(19), CoordinateU8(22), CoordinateU8(18)]), Position3([CoordinateU8(19), CoordinateU8(22), CoordinateU8(19)]), Position3([CoordinateU8(19), CoordinateU8(22), CoordinateU8(20)]), Position3([CoordinateU8(19), CoordinateU8(22), CoordinateU8(21)]), Position3([CoordinateU8(19), CoordinateU8(22), CoordinateU8(22)]), Position3([CoordinateU8(20), CoordinateU8(3), CoordinateU8(5)]), Position3([CoordinateU8(20), CoordinateU8(3), CoordinateU8(6)]), Position3([CoordinateU8(20), CoordinateU8(3), CoordinateU8(7)]), Position3([CoordinateU8(20), CoordinateU8(3), CoordinateU8(8)]), Position3([CoordinateU8(20), CoordinateU8(3), CoordinateU8(9)]), Position3([CoordinateU8(20), CoordinateU8(3), CoordinateU8(10)]), Position3([CoordinateU8(20), CoordinateU8(3), CoordinateU8(11)]), Position3([CoordinateU8(20), CoordinateU8(3), CoordinateU8(12)]), Position3([CoordinateU8(20), CoordinateU8(3), CoordinateU8(13)]), Position3([CoordinateU8(20), CoordinateU8(3), CoordinateU8(14)]), Position3([CoordinateU8(20), CoordinateU8(3), CoordinateU8(15)]), Position3([CoordinateU8(20), CoordinateU8(3), CoordinateU8(16)]), Position3([CoordinateU8(20), CoordinateU8(3), CoordinateU8(17)]), Position3([CoordinateU8(20), CoordinateU8(3), CoordinateU8(18)]), Position3([CoordinateU8(20), CoordinateU8(3), CoordinateU8(19)]), Position3([CoordinateU8(20), CoordinateU8(3), CoordinateU8(20)]), Position3([CoordinateU8(20), CoordinateU8(3), CoordinateU8(21)]), Position3([CoordinateU8(20), CoordinateU8(3), CoordinateU8(22)]), Position3([CoordinateU8(20), CoordinateU8(4), CoordinateU8(5)]), Position3([CoordinateU8(20), CoordinateU8(4), CoordinateU8(6)]), Position3([CoordinateU8(20), CoordinateU8(4), CoordinateU8(7)]), Position3([CoordinateU8(20), CoordinateU8(4), CoordinateU8(8)]), Position3([CoordinateU8(20), CoordinateU8(4), CoordinateU8(9)]), Position3([CoordinateU8(20), CoordinateU8(4), CoordinateU8(10)]), Position3([CoordinateU8(20), CoordinateU8(4), CoordinateU8(11)]), Position3([CoordinateU8(20), CoordinateU8(4), CoordinateU8(12)]), Position3([CoordinateU8(20), CoordinateU8(4), CoordinateU8(13)]), Position3([CoordinateU8(20), CoordinateU8(4), CoordinateU8(14)]), Position3([CoordinateU8(20), CoordinateU8(4), CoordinateU8(15)]), Position3([CoordinateU8(20), CoordinateU8(4), CoordinateU8(16)]), Position3([CoordinateU8(20), CoordinateU8(4), CoordinateU8(17)]), Position3([CoordinateU8(20), CoordinateU8(4), CoordinateU8(18)]), Position3([CoordinateU8(20), CoordinateU8(4), CoordinateU8(19)]), Position3([CoordinateU8(20), CoordinateU8(4), CoordinateU8(20)]), Position3([CoordinateU8(20), CoordinateU8(4), CoordinateU8(21)]), Position3([CoordinateU8(20), CoordinateU8(4), CoordinateU8(22)]), Position3([CoordinateU8(20), CoordinateU8(5), CoordinateU8(5)]), Position3([CoordinateU8(20), CoordinateU8(5), CoordinateU8(6)]), Position3([CoordinateU8(20), CoordinateU8(5), CoordinateU8(7)]), Position3([CoordinateU8(20), CoordinateU8(5), CoordinateU8(8)]), Position3([CoordinateU8(20), CoordinateU8(5), CoordinateU8(9)]), Position3([CoordinateU8(20), CoordinateU8(5), CoordinateU8(10)]), Position3([CoordinateU8(20), CoordinateU8(5), CoordinateU8(11)]), Position3([CoordinateU8(20), CoordinateU8(5), CoordinateU8(12)]), Position3([CoordinateU8(20), CoordinateU8(5), CoordinateU8(13)]), Position3([CoordinateU8(20), CoordinateU8(5), CoordinateU8(14)]), Position3([CoordinateU8(20), CoordinateU8(5), CoordinateU8(15)]), Position3([CoordinateU8(20), CoordinateU8(5), CoordinateU8(16)]), Position3([CoordinateU8(20), CoordinateU8(5), CoordinateU8(17)]), Position3([CoordinateU8(20), CoordinateU8(5), CoordinateU8(18)]), Position3([CoordinateU8(20), CoordinateU8(5), CoordinateU8(19)]), Position3([CoordinateU8(20), CoordinateU8(5), CoordinateU8(20)]), Position3([CoordinateU8(20), CoordinateU8(5), CoordinateU8(21)]), Position3([CoordinateU8(20), CoordinateU8(5), CoordinateU8(22)]), Position3([CoordinateU8(20), CoordinateU8(6), CoordinateU8(5)]), Position3([CoordinateU8(20), CoordinateU8(6), CoordinateU8(6)]), Position3([CoordinateU8(20), CoordinateU8(6), CoordinateU8(7)]), Position3([CoordinateU8(20), CoordinateU8(6), CoordinateU8(8)]), Position3([CoordinateU8(20), CoordinateU8(6), CoordinateU8(9)]), Position3([CoordinateU8(20), CoordinateU8(6), CoordinateU8(10)]), Position3([CoordinateU8(20), CoordinateU8(6), CoordinateU8(11)]), Position3([CoordinateU8(20), CoordinateU8(6), CoordinateU8(12)]), Position3([CoordinateU8(20), CoordinateU8(6), CoordinateU8(13)]), Position3([CoordinateU8(20), CoordinateU8(6), CoordinateU8(14)]), Position3([CoordinateU8(20), CoordinateU8(6), CoordinateU8(15)]), Position3([CoordinateU8(20), CoordinateU8(6), CoordinateU8(16)]), Position3([CoordinateU8(20), CoordinateU8(6), CoordinateU8(17)]), Position3([CoordinateU8(20), CoordinateU8(6), CoordinateU8(18)]), Position3([CoordinateU8(20), CoordinateU8(6), CoordinateU8(19)]), Position3([CoordinateU8(20), CoordinateU8(6), CoordinateU8(20)]), Position3([CoordinateU8(20), CoordinateU8(6), CoordinateU8(21)]), Position3([CoordinateU8(20), CoordinateU8(6), CoordinateU8(22)]), Position3([CoordinateU8(20), CoordinateU8(7), CoordinateU8(5)]), Position3([CoordinateU8(20), CoordinateU8(7), CoordinateU8(6)]), Position3([CoordinateU8(20), CoordinateU8(7), CoordinateU8(7)]), Position3([CoordinateU8(20), CoordinateU8(7), CoordinateU8(8)]), Position3([CoordinateU8(20), CoordinateU8(7), CoordinateU8(9)]), Position3([CoordinateU8(20), CoordinateU8(7), CoordinateU8(10)]), Position3([CoordinateU8(20), CoordinateU8(7), CoordinateU8(11)]), Position3([CoordinateU8(20), CoordinateU8(7), CoordinateU8(12)]), Position3([CoordinateU8(20), CoordinateU8(7), CoordinateU8(13)]), Position3([CoordinateU8(20), CoordinateU8(7), CoordinateU8(14)]), Position3([CoordinateU8(20), CoordinateU8(7), CoordinateU8(15)]), Position3([CoordinateU8(20), CoordinateU8(7), CoordinateU8(16)]), Position3([CoordinateU8(20), CoordinateU8(7), CoordinateU8(17)]), Position3([CoordinateU8(20), CoordinateU8(7), CoordinateU8(18)]), Position3([CoordinateU8(20), CoordinateU8(7), CoordinateU8(19)]), Position3([CoordinateU8(20), CoordinateU8(7), CoordinateU8(20)]), Position3([CoordinateU8(20), CoordinateU8(7), CoordinateU8(21)]), Position3([CoordinateU8(20), CoordinateU8(7), CoordinateU8(22)]), Position3([CoordinateU8(20), CoordinateU8(8), CoordinateU8(5)]), Position3([CoordinateU8(20), CoordinateU8(8), CoordinateU8(6)]), Position3([CoordinateU8(20), CoordinateU8(8), CoordinateU8(7)]), Position3([CoordinateU8(20), CoordinateU8(8), CoordinateU8(8)]), Position3([CoordinateU8(20), CoordinateU8(8), CoordinateU8(9)]), Position3([CoordinateU8(20), CoordinateU8(8), CoordinateU8(10)]), Position3([CoordinateU8(20), CoordinateU8(8), CoordinateU8(11)]), Position3([CoordinateU8(20), CoordinateU8(8), CoordinateU8(12)]), Position3([CoordinateU8(20), CoordinateU8(8), CoordinateU8(13)]), Position3([CoordinateU8(20), CoordinateU8(8), CoordinateU8(14)]), Position3([CoordinateU8(20), CoordinateU8(8), CoordinateU8(15)]), Position3([CoordinateU8(20), CoordinateU8(8), CoordinateU8(16)]), Position3([CoordinateU8(20), CoordinateU8(8), CoordinateU8(17)]), Position3([CoordinateU8(20), CoordinateU8(8), CoordinateU8(18)]), Position3([CoordinateU8(20), CoordinateU8(8), CoordinateU8(19)]), Position3([CoordinateU8(20), CoordinateU8(8), CoordinateU8(20)]), Position3([CoordinateU8(20), CoordinateU8(8), CoordinateU8(21)]), Position3([CoordinateU8(20), CoordinateU8(8), CoordinateU8(22)]), Position3([CoordinateU8(20), CoordinateU8(9), CoordinateU8(5)]), Position3([CoordinateU8(20), CoordinateU8(9), CoordinateU8(6)]), Position3([CoordinateU8(20), CoordinateU8(9), CoordinateU8(7)]), Position3([CoordinateU8(20), CoordinateU8(9), CoordinateU8(8)]), Position3([CoordinateU8(20), CoordinateU8(9), CoordinateU8(9)]), Position3([CoordinateU8(20), CoordinateU8(9), CoordinateU8(10)]), Position3([CoordinateU8(20), CoordinateU8(9), CoordinateU8(11)]), Position3([CoordinateU8(20), CoordinateU8(9), CoordinateU8(12)]), Position3([CoordinateU8(20), CoordinateU8(9), CoordinateU8(13)]), Position3([CoordinateU8(20), CoordinateU8(9), CoordinateU8(14)]), Position3([CoordinateU8(20), CoordinateU8(9), CoordinateU8(15)]), Position3([CoordinateU8(20), CoordinateU8(9), CoordinateU8(16)]), Position3([CoordinateU8(20), CoordinateU8(9), CoordinateU8(17)]), Position3([CoordinateU8(20), CoordinateU8(9), CoordinateU8(18)]), Position3([CoordinateU8(20), CoordinateU8(9), CoordinateU8(19)]), Position3([CoordinateU8(20), CoordinateU8(9), CoordinateU8(20)]), Position3([CoordinateU8(20), CoordinateU8(9), CoordinateU8(21)]), Position3([CoordinateU8(20), CoordinateU8(9), CoordinateU8(22)]), Position3([CoordinateU8(20), CoordinateU8(10), CoordinateU8(5)]), Position3([CoordinateU8(20), CoordinateU8(10), CoordinateU8(6)]), Position3([CoordinateU8(20), CoordinateU8(10), CoordinateU8(7)]), Position3([CoordinateU8(20), CoordinateU8(10), CoordinateU8(8)]), Position3([CoordinateU8(20), CoordinateU8(10), CoordinateU8(9)]), Position3([CoordinateU8(20), CoordinateU8(10), CoordinateU8(10)]), Position3([CoordinateU8(20), CoordinateU8(10), CoordinateU8(11)]), Position3([CoordinateU8(20), CoordinateU8(10), CoordinateU8(12)]), Position3([CoordinateU8(20), CoordinateU8(10), CoordinateU8(13)]), Position3([CoordinateU8(20), CoordinateU8(10), CoordinateU8(14)]), Position3([CoordinateU8(20), CoordinateU8(10), CoordinateU8(15)]), Position3([CoordinateU8(20), CoordinateU8(10), CoordinateU8(16)]), Position3([CoordinateU8(20), CoordinateU8(10), CoordinateU8(17)]), Position3([CoordinateU8(20), CoordinateU8(10), CoordinateU8(18)]), Position3([CoordinateU8(20), CoordinateU8(10), CoordinateU8(19)]), Position3([CoordinateU8(20), CoordinateU8(10), CoordinateU8(20)]), Position3([CoordinateU8(20), CoordinateU8(10), CoordinateU8(21)]), Position3([CoordinateU8(20), CoordinateU8(10), CoordinateU8(22)]), Position3([CoordinateU8(20), CoordinateU8(11), CoordinateU8(5)]), Position3([CoordinateU8(20), CoordinateU8(11), CoordinateU8(6)]), Position3([CoordinateU8(20), CoordinateU8(11), CoordinateU8(7)]), Position3([CoordinateU8(20), CoordinateU8(11), CoordinateU8(8)]), Position3([CoordinateU8(20), CoordinateU8(11), CoordinateU8(9)]), Position3([CoordinateU8(20), CoordinateU8(11), CoordinateU8(10)]), Position3([CoordinateU8(20), CoordinateU8(11), CoordinateU8(11)]), Position3([CoordinateU8(20), CoordinateU8(11), CoordinateU8(12)]), Position3([CoordinateU8(20), CoordinateU8(11), CoordinateU8(13)]), Position3([CoordinateU8(20), CoordinateU8(11), CoordinateU8(14)]), Position3([CoordinateU8(20), CoordinateU8(11), CoordinateU8(15)]), Position3([CoordinateU8(20), CoordinateU8(11), CoordinateU8(16)]), Position3([CoordinateU8(20), CoordinateU8(11), CoordinateU8(17)]), Position3([CoordinateU8(20), CoordinateU8(11), CoordinateU8(18)]), Position3([CoordinateU8(20), CoordinateU8(11), CoordinateU8(19)]), Position3([CoordinateU8(20), CoordinateU8(11), CoordinateU8(20)]), Position3([CoordinateU8(20), CoordinateU8(11), CoordinateU8(21)]), Position3([CoordinateU8(20), CoordinateU8(11), CoordinateU8(22)]), Position3([CoordinateU8(20), CoordinateU8(12), CoordinateU8(5)]), Position3([CoordinateU8(20), CoordinateU8(12), CoordinateU8(6)]), Position3([CoordinateU8(20), CoordinateU8(12), CoordinateU8(7)]), Position3([CoordinateU8(20), CoordinateU8(12), CoordinateU8(8)]), Position3([CoordinateU8(20), CoordinateU8(12), CoordinateU8(9)]), Position3([CoordinateU8(20), CoordinateU8(12), CoordinateU8(10)]), Position3([CoordinateU8(20), CoordinateU8(12), CoordinateU8(11)]), Position3([CoordinateU8(20), CoordinateU8(12), CoordinateU8(12)]), Position3([CoordinateU8(20), CoordinateU8(12), CoordinateU8(13)]), Position3([CoordinateU8(20), CoordinateU8(12), CoordinateU8(14)]), Position3([CoordinateU8(20), CoordinateU8(12), CoordinateU8(15)]), Position3([CoordinateU8(20), CoordinateU8(12), CoordinateU8(16)]), Position3([CoordinateU8(20), CoordinateU8(12), CoordinateU8(17)]), Position3([CoordinateU8(20), CoordinateU8(12), CoordinateU8(18)]), Position3([CoordinateU8(20), CoordinateU8(12), CoordinateU8(19)]), Position3([CoordinateU8(20), CoordinateU8(12), CoordinateU8(20)]), Position3([CoordinateU8(20), CoordinateU8(12), CoordinateU8(21)]), Position3([CoordinateU8(20), CoordinateU8(12), CoordinateU8(22)]), Position3([CoordinateU8(20), CoordinateU8(13), CoordinateU8(5)]), Position3([CoordinateU8(20), CoordinateU8(13), CoordinateU8(6)]), Position3([CoordinateU8(20), CoordinateU8(13), CoordinateU8(7)]), Position3([CoordinateU8(20), CoordinateU8(13), CoordinateU8(8)]), Position3([CoordinateU8(20), CoordinateU8(13), CoordinateU8(9)]), Position3([CoordinateU8(20), CoordinateU8(13), CoordinateU8(10)]), Position3([CoordinateU8(20), CoordinateU8(13), CoordinateU8(11)]), Position3([CoordinateU8(20), CoordinateU8(13), CoordinateU8(12)]), Position3([CoordinateU8(20), CoordinateU8(13), CoordinateU8(13)]), Position3([CoordinateU8(20), CoordinateU8(13), CoordinateU8(14)]), Position3([CoordinateU8(20), CoordinateU8(13), CoordinateU8(15)]), Position3([CoordinateU8(20), CoordinateU8(13), CoordinateU8(16)]), Position3([CoordinateU8(20), CoordinateU8(13), CoordinateU8(17)]), Position3([CoordinateU8(20), CoordinateU8(13), CoordinateU8(18)]), Position3([CoordinateU8(20), CoordinateU8(13), CoordinateU8(19)]), Position3([CoordinateU8(20), CoordinateU8(13), CoordinateU8(20)]), Position3([CoordinateU8(20), CoordinateU8(13), CoordinateU8(21)]), Position3([CoordinateU8(20), CoordinateU8(13), CoordinateU8(22)]), Position3([CoordinateU8(20), CoordinateU8(14), CoordinateU8(5)]), Position3([CoordinateU8(20), CoordinateU8(14), CoordinateU8(6)]), Position3([CoordinateU8(20), CoordinateU8(14), CoordinateU8(7)]), Position3([CoordinateU8(20), CoordinateU8(14), CoordinateU8(8)]), Position3([CoordinateU8(20), CoordinateU8(14), CoordinateU8(9)]), Position3([CoordinateU8(20), CoordinateU8(14), CoordinateU8(10)]), Position3([CoordinateU8(20), CoordinateU8(14), CoordinateU8(11)]), Position3([CoordinateU8(20), CoordinateU8(14), CoordinateU8(12)]), Position3([CoordinateU8(20), CoordinateU8(14), CoordinateU8(13)]), Position3([CoordinateU8(20), CoordinateU8(14), CoordinateU8(14)]), Position3([CoordinateU8(20), CoordinateU8(14), CoordinateU8(15)]), Position3([CoordinateU8(20), CoordinateU8(14), CoordinateU8(16)]), Position3([CoordinateU8(20), CoordinateU8(14), CoordinateU8(17)]), Position3([CoordinateU8(20), CoordinateU8(14), CoordinateU8(18)]), Position3([CoordinateU8(20), CoordinateU8(14), CoordinateU8(19)]), Position3([CoordinateU8(20), CoordinateU8(14), CoordinateU8(20)]), Position3([CoordinateU8(20), CoordinateU8(14), CoordinateU8(21)]), Position3([CoordinateU8(20), CoordinateU8(14), CoordinateU8(22)]), Position3([CoordinateU8(20), CoordinateU8(15), CoordinateU8(5)]), Position3([CoordinateU8(20), CoordinateU8(15), CoordinateU8(6)]), Position3([CoordinateU8(20), CoordinateU8(15), CoordinateU8(7)]), Position3([CoordinateU8(20), CoordinateU8(15), CoordinateU8(8)]), Position3([CoordinateU8(20), CoordinateU8(15), CoordinateU8(9)]), Position3([CoordinateU8(20), CoordinateU8(15), CoordinateU8(10)]), Position3([CoordinateU8(20), CoordinateU8(15), CoordinateU8(11)]), Position3([CoordinateU8(20), CoordinateU8(15), CoordinateU8(12)]), Position3([CoordinateU8(20), CoordinateU8(15), CoordinateU8(13)]), Position3([CoordinateU8(20), CoordinateU8(15), CoordinateU8(14)]), Position3([CoordinateU8(20), CoordinateU8(15), CoordinateU8(15)]), Position3([CoordinateU8(20), CoordinateU8(15), CoordinateU8(16)]), Position3([CoordinateU8(20), CoordinateU8(15), CoordinateU8(17)]), Position3([CoordinateU8(20), CoordinateU8(15), CoordinateU8(18)]), Position3([CoordinateU8(20), CoordinateU8(15), CoordinateU8(19)]), Position3([CoordinateU8(20), CoordinateU8(15), CoordinateU8(20)]), Position3([CoordinateU8(20), CoordinateU8(15), CoordinateU8(21)]), Position3([CoordinateU8(20), CoordinateU8(15), CoordinateU8(22)]), Position3([CoordinateU8(20), CoordinateU8(16), CoordinateU8(5)]), Position3([CoordinateU8(20), CoordinateU8(16), CoordinateU8(6)]), Position3([CoordinateU8(20), CoordinateU8(16), CoordinateU8(7)]), Position3([CoordinateU8(20), CoordinateU8(16), CoordinateU8(8)]), Position3([CoordinateU8(20), CoordinateU8(16), CoordinateU8(9)]), Position3([CoordinateU8(20), CoordinateU8(16), CoordinateU8(10)]), Position3([CoordinateU8(20), CoordinateU8(16), CoordinateU8(11)]), Position3([CoordinateU8(20), CoordinateU8(16), CoordinateU8(12)]), Position3([CoordinateU8(20), CoordinateU8(16), CoordinateU8(13)]), Position3([CoordinateU8(20), CoordinateU8(16), CoordinateU8(14)]), Position3([CoordinateU8(20), CoordinateU8(16), CoordinateU8(15)]), Position3([CoordinateU8(20), CoordinateU8(16), CoordinateU8(16)]), Position3([CoordinateU8(20), CoordinateU8(16), CoordinateU8(17)]), Position3([CoordinateU8(20), CoordinateU8(16), CoordinateU8(18)]), Position3([CoordinateU8(20), CoordinateU8(16), CoordinateU8(19)]), Position3([CoordinateU8(20), CoordinateU8(16), CoordinateU8(20)]), Position3([CoordinateU8(20), CoordinateU8(16), CoordinateU8(21)]), Position3([CoordinateU8(20), CoordinateU8(16), CoordinateU8(22)]), Position3([CoordinateU8(20), CoordinateU8(17), CoordinateU8(5)]), Position3([CoordinateU8(20), CoordinateU8(17), CoordinateU8(6)]), Position3([CoordinateU8(20), CoordinateU8(17), CoordinateU8(7)]), Position3([CoordinateU8(20), CoordinateU8(17), CoordinateU8(8)]), Position3([CoordinateU8(20), CoordinateU8(17), CoordinateU8(9)]), Position3([CoordinateU8(20), CoordinateU8(17), CoordinateU8(10)]), Position3([CoordinateU8(20), CoordinateU8(17), CoordinateU8(11)]), Position3([CoordinateU8(20), CoordinateU8(17), CoordinateU8(12)]), Position3([CoordinateU8(20), CoordinateU8(17), CoordinateU8(13)]), Position3([CoordinateU8(20), CoordinateU8(17), CoordinateU8(14)]), Position3([CoordinateU8(20), CoordinateU8(17), CoordinateU8(15)]), Position3([CoordinateU8(20), CoordinateU8(17), CoordinateU8(16)]), Position3([CoordinateU8(20), CoordinateU8(17), CoordinateU8(17)]), Position3([CoordinateU8(20), CoordinateU8(17), CoordinateU8(18)]), Position3([CoordinateU8(20), CoordinateU8(17), CoordinateU8(19)]), Position3([CoordinateU8(20), CoordinateU8(17), CoordinateU8(20)]), Position3([CoordinateU8(20), CoordinateU8(17), CoordinateU8(21)]), Position3([CoordinateU8(20), CoordinateU8(17), CoordinateU8(22)]), Position3([CoordinateU8(20), CoordinateU8(18), CoordinateU8(5)]), Position3([CoordinateU8(20), CoordinateU8(18), CoordinateU8(6)]), Position3([CoordinateU8(20), CoordinateU8(18), CoordinateU8(7)]), Position3([CoordinateU8(20), CoordinateU8(18), CoordinateU8(8)]), Position3([CoordinateU8(20), CoordinateU8(18), CoordinateU8(9)]), Position3([CoordinateU8(20), CoordinateU8(18), CoordinateU8(10)]), Position3([CoordinateU8(20), CoordinateU8(18), CoordinateU8(11)]), Position3([CoordinateU8(20), CoordinateU8(18), CoordinateU8(12)]), Position3([CoordinateU8(20), CoordinateU8(18), CoordinateU8(13)]), Position3([CoordinateU8(20), CoordinateU8(18), CoordinateU8(14)]), Position3([CoordinateU8(20), CoordinateU8(18), CoordinateU8(15)]), Position3([CoordinateU8(20), CoordinateU8(18), CoordinateU8(16)]), Position3([CoordinateU8(20), CoordinateU8(18), CoordinateU8(17)]), Position3([CoordinateU8(20), CoordinateU8(18), CoordinateU8(18)]), Position3([CoordinateU8(20), CoordinateU8(18), CoordinateU8(19)]), Position3([CoordinateU8(20), CoordinateU8(18), CoordinateU8(20)]), Position3([CoordinateU8(20), CoordinateU8(18), CoordinateU8(21)]), Position3([CoordinateU8(20), CoordinateU8(18), CoordinateU8(22)]), Position3([CoordinateU8(20), CoordinateU8(19), CoordinateU8(5)]), Position3([CoordinateU8(20), CoordinateU8(19), CoordinateU8(6)]), Position3([CoordinateU8(20), CoordinateU8(19), CoordinateU8(7)]), Position3([CoordinateU8(20), CoordinateU8(19), CoordinateU8(8)]), Position3([CoordinateU8(20), CoordinateU8(19), CoordinateU8(9)]), Position3([CoordinateU8(20), CoordinateU8(19), CoordinateU8(10)]), Position3([CoordinateU8(20), CoordinateU8(19), CoordinateU8(11)]), Position3([CoordinateU8(20), CoordinateU8(19), CoordinateU8(12)]), Position3([CoordinateU8(20), CoordinateU8(19), CoordinateU8(13)]), Position3([CoordinateU8(20), CoordinateU8(19), CoordinateU8(14)]), Position3([CoordinateU8(20), CoordinateU8(19), CoordinateU8(15)]), Position3([CoordinateU8(20), CoordinateU8(19), CoordinateU8(16)]), Position3([CoordinateU8(20), CoordinateU8(19), CoordinateU8(17)]), Position3([CoordinateU8(20), CoordinateU8(19), CoordinateU8(18)]), Position3([CoordinateU8(20), CoordinateU8(19), CoordinateU8(19)]), Position3([CoordinateU8(20), CoordinateU8(19), CoordinateU8(20)]), Position3([CoordinateU8(20), CoordinateU8(19), CoordinateU8(21)]), Position3([CoordinateU8(20), CoordinateU8(19), CoordinateU8(22)]), Position3([CoordinateU8(20), CoordinateU8(20), CoordinateU8(5)]), Position3([CoordinateU8(20), CoordinateU8(20), CoordinateU8(6)]), Position3([CoordinateU8(20), CoordinateU8(20), CoordinateU8(7)]), Position3([CoordinateU8(20), CoordinateU8(20), CoordinateU8(8)]), Position3([CoordinateU8(20), CoordinateU8(20), CoordinateU8(9)]), Position3([CoordinateU8(20), CoordinateU8(20), CoordinateU8(10)]), Position3([CoordinateU8(20), CoordinateU8(20), CoordinateU8(11)]), Position3([CoordinateU8(20), CoordinateU8(20), CoordinateU8(12)]), Position3([CoordinateU8(20), CoordinateU8(20), CoordinateU8(13)]), Position3([CoordinateU8(20), CoordinateU8(20), CoordinateU8(14)]), Position3([CoordinateU8(20), CoordinateU8(20), CoordinateU8(15)]), Position3([CoordinateU8(20), CoordinateU8(20), CoordinateU8(16)]), Position3([CoordinateU8(20), CoordinateU8(20), CoordinateU8(17)]), Position3([CoordinateU8(20), CoordinateU8(20), CoordinateU8(18)]), Position3([CoordinateU8(20), CoordinateU8(20), CoordinateU8(19)]), Position3([CoordinateU8(20), CoordinateU8(20), CoordinateU8(20)]), Position3([CoordinateU8(20), CoordinateU8(20), CoordinateU8(21)]), Position3([CoordinateU8(20), CoordinateU8(20), CoordinateU8(22)]), Position3([CoordinateU8(20), CoordinateU8(21), CoordinateU8(5)]), Position3([CoordinateU8(20), CoordinateU8(21), CoordinateU8(6)]), Position3([CoordinateU8(20), CoordinateU8(21), CoordinateU8(7)]), Position3([CoordinateU8(20), CoordinateU8(21), CoordinateU8(8)]), Position3([CoordinateU8(20), CoordinateU8(21), CoordinateU8(9)]), Position3([CoordinateU8(20), CoordinateU8(21), CoordinateU8(10)]), Position3([CoordinateU8(20), CoordinateU8(21), CoordinateU8(11)]), Position3([CoordinateU8(20), CoordinateU8(21), CoordinateU8(12)]), Position3([CoordinateU8(20), CoordinateU8(21), CoordinateU8(13)]), Position3([CoordinateU8(20), CoordinateU8(21), CoordinateU8(14)]), Position3([CoordinateU8(20), CoordinateU8(21), CoordinateU8(15)]), Position3([CoordinateU8(20), CoordinateU8(21), CoordinateU8(16)]), Position3([CoordinateU8(20), CoordinateU8(21), CoordinateU8(17)]), Position3([CoordinateU8(20), CoordinateU8(21), CoordinateU8(18)]), Position3([CoordinateU8(20), CoordinateU8(21), CoordinateU8(19)]), Position3([CoordinateU8(20), CoordinateU8(21), CoordinateU8(20)]), Position3([CoordinateU8(20), CoordinateU8(21), CoordinateU8(21)]), Position3([CoordinateU8(20), CoordinateU8(21), CoordinateU8(22)]), Position3([CoordinateU8(20), CoordinateU8(22), CoordinateU8(5)]), Position3([CoordinateU8(20), CoordinateU8(22), CoordinateU8(6)]), Position3([CoordinateU8(20), CoordinateU8(22), CoordinateU8(7)]), Position3([CoordinateU8(20), CoordinateU8(22), CoordinateU8(8)]), Position3([CoordinateU8(20), CoordinateU8(22), CoordinateU8(9)]), Position3([CoordinateU8(20), CoordinateU8(22), CoordinateU8(10)]), Position3([CoordinateU8(20), CoordinateU8(22), CoordinateU8(11)]), Position3([CoordinateU8(20), CoordinateU8(22), CoordinateU8(12)]), Position3([CoordinateU8(20), CoordinateU8(22), CoordinateU8(13)]), Position3([CoordinateU8(20), CoordinateU8(22), CoordinateU8(14)]), Position3([CoordinateU8(20), CoordinateU8(22), CoordinateU8(15)]), Position3([CoordinateU8(20), CoordinateU8(22), CoordinateU8(16)]), Position3([CoordinateU8(20), CoordinateU8(22), CoordinateU8(17)]), Position3([CoordinateU8(20), CoordinateU8(22), CoordinateU8(18)]), Position3([CoordinateU8(20), CoordinateU8(22), CoordinateU8(19)]), Position3([CoordinateU8(20), CoordinateU8(22), CoordinateU8(20)]), Position3([CoordinateU8(20), CoordinateU8(22), CoordinateU8(21)]), Position3([CoordinateU8(20), CoordinateU8(22), CoordinateU8(22)]), Position3([CoordinateU8(21), CoordinateU8(3), CoordinateU8(5)]), Position3([CoordinateU8(21), CoordinateU8(3), CoordinateU8(6)]), Position3([CoordinateU8(21), CoordinateU8(3), CoordinateU8(7)]), Position3([CoordinateU8(21), CoordinateU8(3), CoordinateU8(8)]), Position3([CoordinateU8(21), CoordinateU8(3), CoordinateU8(9)]), Position3([CoordinateU8(21), CoordinateU8(3), CoordinateU8(10)]), Position3([CoordinateU8(21), CoordinateU8(3), CoordinateU8(11)]), Position3([CoordinateU8(21), CoordinateU8(3), CoordinateU8(12)]), Position3([CoordinateU8(21), CoordinateU8(3), CoordinateU8(13)]), Position3([CoordinateU8(21), CoordinateU8(3), CoordinateU8(14)]), Position3([CoordinateU8(21), CoordinateU8(3), CoordinateU8(15)]), Position3([CoordinateU8(21), CoordinateU8(3), CoordinateU8(16)]), Position3([CoordinateU8(21), CoordinateU8(3), CoordinateU8(17)]), Position3([CoordinateU8(21), CoordinateU8(3), CoordinateU8(18)]), Position3([CoordinateU8(21), CoordinateU8(3), CoordinateU8(19)]), Position3([CoordinateU8(21), CoordinateU8(3), CoordinateU8(20)]), Position3([CoordinateU8(21), CoordinateU8(3), CoordinateU8(21)]), Position3([CoordinateU8(21), CoordinateU8(3), CoordinateU8(22)]), Position3([CoordinateU8(21), CoordinateU8(4), CoordinateU8(5)]), Position3([CoordinateU8(21), CoordinateU8(4), CoordinateU8(6)]), Position3([CoordinateU8(21), CoordinateU8(4), CoordinateU8(7)]), Position3([CoordinateU8(21), CoordinateU8(4), CoordinateU8(8)]), Position3([CoordinateU8(21), CoordinateU8(4), CoordinateU8(9)]), Position3([CoordinateU8(21), CoordinateU8(4), CoordinateU8(10)]), Position3([CoordinateU8(21), CoordinateU8(4), CoordinateU8(11)]), Position3([CoordinateU8(21), CoordinateU8(4), CoordinateU8(12)]), Position3([CoordinateU8(21), CoordinateU8(4), CoordinateU8(13)]), Position3([CoordinateU8(21), CoordinateU8(4), CoordinateU8(14)]), Position3([CoordinateU8(21), CoordinateU8(4), CoordinateU8(15)]), Position3([CoordinateU8(21), CoordinateU8(4), CoordinateU8(16)]), Position3([CoordinateU8(21), CoordinateU8(4), CoordinateU8(17)]), Position3([CoordinateU8(21), CoordinateU8(4), CoordinateU8(18)]), Position3([CoordinateU8(21), CoordinateU8(4), CoordinateU8(19)]), Position3([CoordinateU8(21), CoordinateU8(4), CoordinateU8(20)]), Position3([CoordinateU8(21), CoordinateU8(4), CoordinateU8(21)]), Position3([CoordinateU8(21), CoordinateU8(4), CoordinateU8(22)]), Position3([CoordinateU8(21), CoordinateU8(5), CoordinateU8(5)]), Position3([CoordinateU8(21), CoordinateU8(5), CoordinateU8(6)]), Position3([CoordinateU8(21), CoordinateU8(5), CoordinateU8(7)]), Position3([CoordinateU8(21), CoordinateU8(5), CoordinateU8(8)]), Position3([CoordinateU8(21), CoordinateU8(5), CoordinateU8(9)]), Position3([CoordinateU8(21), CoordinateU8(5), CoordinateU8(10)]), Position3([CoordinateU8(21), CoordinateU8(5), CoordinateU8(11)]), Position3([CoordinateU8(21), CoordinateU8(5), CoordinateU8(12)]), Position3([CoordinateU8(21), CoordinateU8(5), CoordinateU8(13)]), Position3([CoordinateU8(21), CoordinateU8(5), CoordinateU8(14)]), Position3([CoordinateU8(21), CoordinateU8(5), CoordinateU8(15)]), Position3([CoordinateU8(21), CoordinateU8(5), CoordinateU8(16)]), Position3([CoordinateU8(21), CoordinateU8(5), CoordinateU8(17)]), Position3([CoordinateU8(21), CoordinateU8(5), CoordinateU8(18)]), Position3([CoordinateU8(21), CoordinateU8(5), CoordinateU8(19)]), Position3([CoordinateU8(21), CoordinateU8(5), CoordinateU8(20)]), Position3([CoordinateU8(21), CoordinateU8(5), CoordinateU8(21)]), Position3([CoordinateU8(21), CoordinateU8(5), CoordinateU8(22)]), Position3([CoordinateU8(21), CoordinateU8(6), CoordinateU8(5)]), Position3([CoordinateU8(21), CoordinateU8(6), CoordinateU8(6)]), Position3([CoordinateU8(21), CoordinateU8(6), CoordinateU8(7)]), Position3([CoordinateU8(21), CoordinateU8(6), CoordinateU8(8)]), Position3([CoordinateU8(21), CoordinateU8(6), CoordinateU8(9)]), Position3([CoordinateU8(21), CoordinateU8(6), CoordinateU8(10)]), Position3([CoordinateU8(21), CoordinateU8(6), CoordinateU8(11)]), Position3([CoordinateU8(21), CoordinateU8(6), CoordinateU8(12)]), Position3([CoordinateU8(21), CoordinateU8(6), CoordinateU8(13)]), Position3([CoordinateU8(21), CoordinateU8(6), CoordinateU8(14)]), Position3([CoordinateU8(21), CoordinateU8(6), CoordinateU8(15)]), Position3([CoordinateU8(21), CoordinateU8(6), CoordinateU8(16)]), Position3([CoordinateU8(21), CoordinateU8(6), CoordinateU8(17)]), Position3([CoordinateU8(21), CoordinateU8(6), CoordinateU8(18)]), Position3([CoordinateU8(21), CoordinateU8(6), CoordinateU8(19)]), Position3([CoordinateU8(21), CoordinateU8(6), CoordinateU8(20)]), Position3([CoordinateU8(21), CoordinateU8(6), CoordinateU8(21)]), Position3([CoordinateU8(21), CoordinateU8(6), CoordinateU8(22)]), Position3([CoordinateU8(21), CoordinateU8(7), CoordinateU8(5)]), Position3([CoordinateU8(21), CoordinateU8(7), CoordinateU8(6)]), Position3([CoordinateU8(21), CoordinateU8(7), CoordinateU8(7)]), Position3([CoordinateU8(21), CoordinateU8(7), CoordinateU8(8)]), Position3([CoordinateU8(21), CoordinateU8(7), CoordinateU8(9)]), Position3([CoordinateU8(21), CoordinateU8(7), CoordinateU8(10)]), Position3([CoordinateU8(21), CoordinateU8(7), CoordinateU8(11)]), Position3([CoordinateU8(21), CoordinateU8(7), CoordinateU8(12)]), Position3([CoordinateU8(21), CoordinateU8(7), CoordinateU8(13)]), Position3([CoordinateU8(21), CoordinateU8(7), CoordinateU8(14)]), Position3([CoordinateU8(21), CoordinateU8(7), CoordinateU8(15)]), Position3([CoordinateU8(21), CoordinateU8(7), CoordinateU8(16)]), Position3([CoordinateU8(21), CoordinateU8(7), CoordinateU8(17)]), Position3([CoordinateU8(21), CoordinateU8(7), CoordinateU8(18)]), Position3([CoordinateU8(21), CoordinateU8(7), CoordinateU8(19)]), Position3([CoordinateU8(21), CoordinateU8(7), CoordinateU8(20)]), Position3([CoordinateU8(21), CoordinateU8(7), CoordinateU8(21)]), Position3([CoordinateU8(21), CoordinateU8(7), CoordinateU8(22)]), Position3([CoordinateU8(21), CoordinateU8(8), CoordinateU8(5)]), Position3([CoordinateU8(21), CoordinateU8(8), CoordinateU8(6)]), Position3([CoordinateU8(21), CoordinateU8(8), CoordinateU8(7)]), Position3([CoordinateU8(21), CoordinateU8(8), CoordinateU8(8)]), Position3([CoordinateU8(21), CoordinateU8(8), CoordinateU8(9)]), Position3([CoordinateU8(21), CoordinateU8(8), CoordinateU8(10)]), Position3([CoordinateU8(21), CoordinateU8(8), CoordinateU8(11)]), Position3([CoordinateU8(21), CoordinateU8(8), CoordinateU8(12)]), Position3([CoordinateU8(21), CoordinateU8(8), CoordinateU8(13)]), Position3([CoordinateU8(21), CoordinateU8(8), CoordinateU8(14)]), Position3([CoordinateU8(21), CoordinateU8(8), CoordinateU8(15)]), Position3([CoordinateU8(21), CoordinateU8(8), CoordinateU8(16)]), Position3([CoordinateU8(21), CoordinateU8(8), CoordinateU8(17)]), Position3([CoordinateU8(21), CoordinateU8(8), CoordinateU8(18)]), Position3([CoordinateU8(21), CoordinateU8(8), CoordinateU8(19)]), Position3([CoordinateU8(21), CoordinateU8(8), CoordinateU8(20)]), Position3([CoordinateU8(21), CoordinateU8(8), CoordinateU8(21)]), Position3([CoordinateU8(21), CoordinateU8(8), CoordinateU8(22)]), Position3([CoordinateU8(21), CoordinateU8(9), CoordinateU8(5)]), Position3([CoordinateU8(21), CoordinateU8(9), CoordinateU8(6)]), Position3([CoordinateU8(21), CoordinateU8(9), CoordinateU8(7)]), Position3([CoordinateU8(21), CoordinateU8(9), CoordinateU8(8)]), Position3([CoordinateU8(21), CoordinateU8(9), CoordinateU8(9)]), Position3([CoordinateU8(21), CoordinateU8(9), CoordinateU8(10)]), Position3([CoordinateU8(21), CoordinateU8(9), CoordinateU8(11)]), Position3([CoordinateU8(21), CoordinateU8(9), CoordinateU8(12)]), Position3([CoordinateU8(21), CoordinateU8(9), CoordinateU8(13)]), Position3([CoordinateU8(21), CoordinateU8(9), CoordinateU8(14)]), Position3([CoordinateU8(21), CoordinateU8(9), CoordinateU8(15)]), Position3([CoordinateU8(21), CoordinateU8(9), CoordinateU8(16)]), Position3([CoordinateU8(21), CoordinateU8(9), CoordinateU8(17)]), Position3([CoordinateU8(21), CoordinateU8(9), CoordinateU8(18)]), Position3([CoordinateU8(21), CoordinateU8(9), CoordinateU8(19)]), Position3([CoordinateU8(21), CoordinateU8(9), CoordinateU8(20)]), Position3([CoordinateU8(21), CoordinateU8(9), CoordinateU8(21)]), Position3([CoordinateU8(21), CoordinateU8(9), CoordinateU8(22)]), Position3([CoordinateU8(21), CoordinateU8(10), CoordinateU8(5)]), Position3([CoordinateU8(21), CoordinateU8(10), CoordinateU8(6)]), Position3([CoordinateU8(21), CoordinateU8(10), CoordinateU8(7)]), Position3([CoordinateU8(21), CoordinateU8(10), CoordinateU8(8)]), Position3([CoordinateU8(21), CoordinateU8(10), CoordinateU8(9)]), Position3([CoordinateU8(21), CoordinateU8(10), CoordinateU8(10)]), Position3([CoordinateU8(21), CoordinateU8(10), CoordinateU8(11)]), Position3([CoordinateU8(21), CoordinateU8(10), CoordinateU8(12)]), Position3([CoordinateU8(21), CoordinateU8(10), CoordinateU8(13)]), Position3([CoordinateU8(21), CoordinateU8(10), CoordinateU8(14)]), Position3([CoordinateU8(21), CoordinateU8(10), CoordinateU8(15)]), Position3([CoordinateU8(21), CoordinateU8(10), CoordinateU8(16)]), Position3([CoordinateU8(21), CoordinateU8(10), CoordinateU8(17)]), Position3([CoordinateU8(21), CoordinateU8(10), CoordinateU8(18)]), Position3([CoordinateU8(21), CoordinateU8(10), CoordinateU8(19)]), Position3([CoordinateU8(21), CoordinateU8(10), CoordinateU8(20)]), Position3([CoordinateU8(21), CoordinateU8(10), CoordinateU8(21)]), Position3([CoordinateU8(21), CoordinateU8(10), CoordinateU8(22)]), Position3([CoordinateU8(21), CoordinateU8(11), CoordinateU8(5)]), Position3([CoordinateU8(21), CoordinateU8(11), CoordinateU8(6)]), Position3([CoordinateU8(21), CoordinateU8(11), CoordinateU8(7)]), Position3([CoordinateU8(21), CoordinateU8(11), CoordinateU8(8)]), Position3([CoordinateU8(21), CoordinateU8(11), CoordinateU8(9)]), Position3([CoordinateU8(21), CoordinateU8(11), CoordinateU8(10)]), Position3([CoordinateU8(21), CoordinateU8(11), CoordinateU8(11)]), Position3([CoordinateU8(21), CoordinateU8(11), CoordinateU8(12)]), Position3([CoordinateU8(21), CoordinateU8(11), CoordinateU8(13)]), Position3([CoordinateU8(21), CoordinateU8(11), CoordinateU8(14)]), Position3([CoordinateU8(21), CoordinateU8(11), CoordinateU8(15)]), Position3([CoordinateU8(21), CoordinateU8(11), CoordinateU8(16)]), Position3([CoordinateU8(21), CoordinateU8(11), CoordinateU8(17)]), Position3([CoordinateU8(21), CoordinateU8(11), CoordinateU8(18)]), Position3([CoordinateU8(21), CoordinateU8(11), CoordinateU8(19)]), Position3([CoordinateU8(21), CoordinateU8(11), CoordinateU8(20)]), Position3([CoordinateU8(21), CoordinateU8(11), CoordinateU8(21)]), Position3([CoordinateU8(21), CoordinateU8(11), CoordinateU8(22)]), Position3([CoordinateU8(21), CoordinateU8(12), CoordinateU8(5)]), Position3([CoordinateU8(21), CoordinateU8(12), CoordinateU8(6)]), Position3([CoordinateU8(21), CoordinateU8(12), CoordinateU8(7)]), Position3([CoordinateU8(21), CoordinateU8(12), CoordinateU8(8)]), Position3([CoordinateU8(21), CoordinateU8(12), CoordinateU8(9)]), Position3([CoordinateU8(21), CoordinateU8(12), CoordinateU8(10)]), Position3([CoordinateU8(21), CoordinateU8(12), CoordinateU8(11)]), Position3([CoordinateU8(21), CoordinateU8(12), CoordinateU8(12)]), Position3([CoordinateU8(21), CoordinateU8(12), CoordinateU8(13)]), Position3([CoordinateU8(21), CoordinateU8(12), CoordinateU8(14)]), Position3([CoordinateU8(21), CoordinateU8(12), CoordinateU8(15)]), Position3([CoordinateU8(21), CoordinateU8(12), CoordinateU8(16)]), Position3([CoordinateU8(21), CoordinateU8(12), CoordinateU8(17)]), Position3([CoordinateU8(21), CoordinateU8(12), CoordinateU8(18)]), Position3([CoordinateU8(21), CoordinateU8(12), CoordinateU8(19)]), Position3([CoordinateU8(21), CoordinateU8(12), CoordinateU8(20)]), Position3([CoordinateU8(21), CoordinateU8(12), CoordinateU8(21)]), Position3([CoordinateU8(21), CoordinateU8(12), CoordinateU8(22)]), Position3([CoordinateU8(21), CoordinateU8(13), CoordinateU8(5)]), Position3([CoordinateU8(21), CoordinateU8(13), CoordinateU8(6)]), Position3([CoordinateU8(21), CoordinateU8(13), CoordinateU8(7)]), Position3([CoordinateU8(21), CoordinateU8(13), CoordinateU8(8)]), Position3([CoordinateU8(21), CoordinateU8(13), CoordinateU8(9)]), Position3([CoordinateU8(21), CoordinateU8(13), CoordinateU8(10)]), Position3([CoordinateU8(21), CoordinateU8(13), CoordinateU8(11)]), Position3([CoordinateU8(21), CoordinateU8(13), CoordinateU8(12)]), Position3([CoordinateU8(21), CoordinateU8(13), CoordinateU8(13)]), Position3([CoordinateU8(21), CoordinateU8(13), CoordinateU8(14)]), Position3([CoordinateU8(21), CoordinateU8(13), CoordinateU8(15)]), Position3([CoordinateU8(21), CoordinateU8(13), CoordinateU8(16)]), Position3([CoordinateU8(21), CoordinateU8(13), CoordinateU8(17)]), Position3([CoordinateU8(21), CoordinateU8(13), CoordinateU8(18)]), Position3([CoordinateU8(21), CoordinateU8(13), CoordinateU8(19)]), Position3([CoordinateU8(21), CoordinateU8(13), CoordinateU8(20)]), Position3([CoordinateU8(21), CoordinateU8(13), CoordinateU8(21)]), Position3([CoordinateU8(21), CoordinateU8(13), CoordinateU8(22)]), Position3([CoordinateU8(21), CoordinateU8(14), CoordinateU8(5)]), Position3([CoordinateU8(21), CoordinateU8(14), CoordinateU8(6)]), Position3([CoordinateU8(21), CoordinateU8(14), CoordinateU8(7)]), Position3([CoordinateU8(21), CoordinateU8(14), CoordinateU8(8)]), Position3([CoordinateU8(21), CoordinateU8(14), CoordinateU8(9)]), Position3([CoordinateU8(21), CoordinateU8(14), CoordinateU8(10)]), Position3([CoordinateU8(21), CoordinateU8(14), CoordinateU8(11)]), Position3([CoordinateU8(21), CoordinateU8(14), CoordinateU8(12)]), Position3([CoordinateU8(21), CoordinateU8(14), CoordinateU8(13)]), Position3([CoordinateU8(21), CoordinateU8(14), CoordinateU8(14)]), Position3([CoordinateU8(21), CoordinateU8(14), CoordinateU8(15)]), Position3([CoordinateU8(21), CoordinateU8(14), CoordinateU8(16)]), Position3([CoordinateU8(21), CoordinateU8(14), CoordinateU8(17)]), Position3([CoordinateU8(21), CoordinateU8(14), CoordinateU8(18)]), Position3([CoordinateU8(21), CoordinateU8(14), CoordinateU8(19)]), Position3([CoordinateU8(21), CoordinateU8(14), CoordinateU8(20)]), Position3([CoordinateU8(21), CoordinateU8(14), CoordinateU8(21)]), Position3([CoordinateU8(21), CoordinateU8(14), CoordinateU8(22)]), Position3([CoordinateU8(21), CoordinateU8(15), CoordinateU8(5)]), Position3([CoordinateU8(21), CoordinateU8(15), CoordinateU8(6)]), Position3([CoordinateU8(21), CoordinateU8(15), CoordinateU8(7)]), Position3([CoordinateU8(21), CoordinateU8(15), CoordinateU8(8)]), Position3([CoordinateU8(21), CoordinateU8(15), CoordinateU8(9)]), Position3([CoordinateU8(21), CoordinateU8(15), CoordinateU8(10)]), Position3([CoordinateU8(21), CoordinateU8(15), CoordinateU8(11)]), Position3([CoordinateU8(21), CoordinateU8(15), CoordinateU8(12)]), Position3([CoordinateU8(21), CoordinateU8(15), CoordinateU8(13)]), Position3([CoordinateU8(21), CoordinateU8(15), CoordinateU8(14)]), Position3([CoordinateU8(21), CoordinateU8(15), CoordinateU8(15)]), Position3([CoordinateU8(21), CoordinateU8(15), CoordinateU8(16)]), Position3([CoordinateU8(21), CoordinateU8(15), CoordinateU8(17)]), Position3([CoordinateU8(21), CoordinateU8(15), CoordinateU8(18)]), Position3([CoordinateU8(21), CoordinateU8(15), CoordinateU8(19)]), Position3([CoordinateU8(21), CoordinateU8(15), CoordinateU8(20)]), Position3([CoordinateU8(21), CoordinateU8(15), CoordinateU8(21)]), Position3([CoordinateU8(21), CoordinateU8(15), CoordinateU8(22)]), Position3([CoordinateU8(21), CoordinateU8(16), CoordinateU8(5)]), Position3([CoordinateU8(21), CoordinateU8(16), CoordinateU8(6)]), Position3([CoordinateU8(21), CoordinateU8(16), CoordinateU8(7)]), Position3([CoordinateU8(21), CoordinateU8(16), CoordinateU8(8)]), Position3([CoordinateU8(21), CoordinateU8(16), CoordinateU8(9)]), Position3([CoordinateU8(21), CoordinateU8(16), CoordinateU8(10)]), Position3([CoordinateU8(21), CoordinateU8(16), CoordinateU8(11)]), Position3([CoordinateU8(21), CoordinateU8(16), CoordinateU8(12)]), Position3([CoordinateU8(21), CoordinateU8(16), CoordinateU8(13)]), Position3([CoordinateU8(21), CoordinateU8(16), CoordinateU8(14)]), Position3([CoordinateU8(21), CoordinateU8(16), CoordinateU8(15)]), Position3([CoordinateU8(21), CoordinateU8(16), CoordinateU8(16)]), Position3([CoordinateU8(21), CoordinateU8(16), CoordinateU8(17)]), Position3([CoordinateU8(21), CoordinateU8(16), CoordinateU8(18)]), Position3([CoordinateU8(21), CoordinateU8(16), CoordinateU8(19)]), Position3([CoordinateU8(21), CoordinateU8(16), CoordinateU8(20)]), Position3([CoordinateU8(21), CoordinateU8(16), CoordinateU8(21)]), Position3([CoordinateU8(21), CoordinateU8(16), CoordinateU8(22)]), Position3([CoordinateU8(21), CoordinateU8(17), CoordinateU8(5)]), Position3([CoordinateU8(21), CoordinateU8(17), CoordinateU8(6)]), Position3([CoordinateU8(21), CoordinateU8(17), CoordinateU8(7)]), Position3([CoordinateU8(21), CoordinateU8(17), CoordinateU8(8)]), Position3([CoordinateU8(21), CoordinateU8(17), CoordinateU8(9)]), Position3([CoordinateU8(21), CoordinateU8(17), CoordinateU8(10)]), Position3([CoordinateU8(21), CoordinateU8(17), CoordinateU8(11)]), Position3([CoordinateU8(21), CoordinateU8(17), CoordinateU8(12)]), Position3([CoordinateU8(21), CoordinateU8(17), CoordinateU8(13)]), Position3([CoordinateU8(21), CoordinateU8(17), CoordinateU8(14)]), Position3([CoordinateU8(21), CoordinateU8(17), CoordinateU8(15)]), Position3([CoordinateU8(21), CoordinateU8(17), CoordinateU8(16)]), Position3([CoordinateU8(21), CoordinateU8(17), CoordinateU8(17)]), Position3([CoordinateU8(21), CoordinateU8(17), CoordinateU8(18)]), Position3([CoordinateU8(21), CoordinateU8(17), CoordinateU8(19)]), Position3([CoordinateU8(21), CoordinateU8(17), CoordinateU8(20)]), Position3([CoordinateU8(21), CoordinateU8(17), CoordinateU8(21)]), Position3([CoordinateU8(21), CoordinateU8(17), CoordinateU8(22)]), Position3([CoordinateU8(21), CoordinateU8(18), CoordinateU8(5)]), Position3([CoordinateU8(21), CoordinateU8(18), CoordinateU8(6)]), Position3([CoordinateU8(21), CoordinateU8(18), CoordinateU8(7)]), Position3([CoordinateU8(21), CoordinateU8(18), CoordinateU8(8)]), Position3([CoordinateU8(21), CoordinateU8(18), CoordinateU8(9)]), Position3([CoordinateU8(21), CoordinateU8(18), CoordinateU8(10)]), Position3([CoordinateU8(21), CoordinateU8(18), CoordinateU8(11)]), Position3([CoordinateU8(21), CoordinateU8(18), CoordinateU8(12)]), Position3([CoordinateU8(21), CoordinateU8(18), CoordinateU8(13)]), Position3([CoordinateU8(21), CoordinateU8(18), CoordinateU8(14)]), Position3([CoordinateU8(21), CoordinateU8(18), CoordinateU8(15)]), Position3([CoordinateU8(21), CoordinateU8(18), CoordinateU8(16)]), Position3([CoordinateU8(21), CoordinateU8(18), CoordinateU8(17)]), Position3([CoordinateU8(21), CoordinateU8(18), CoordinateU8(18)]), Position3([CoordinateU8(21), CoordinateU8(18), CoordinateU8(19)]), Position3([CoordinateU8(21), CoordinateU8(18), CoordinateU8(20)]), Position3([CoordinateU8(21), CoordinateU8(18), CoordinateU8(21)]), Position3([CoordinateU8(21), CoordinateU8(18), CoordinateU8(22)]), Position3([CoordinateU8(21), CoordinateU8(19), CoordinateU8(5)]), Position3([CoordinateU8(21), CoordinateU8(19), CoordinateU8(6)]), Position3([CoordinateU8(21), CoordinateU8(19), CoordinateU8(7)]), Position3([CoordinateU8(21), CoordinateU8(19), CoordinateU8(8)]), Position3([CoordinateU8(21), CoordinateU8(19), CoordinateU8(9)]), Position3([CoordinateU8(21), CoordinateU8(19), CoordinateU8(10)]), Position3([CoordinateU8(21), CoordinateU8(19), CoordinateU8(11)]), Position3([CoordinateU8(21), CoordinateU8(19), CoordinateU8(12)]), Position3([CoordinateU8(21), CoordinateU8(19), CoordinateU8(13)]), Position3([CoordinateU8(21), CoordinateU8(19), CoordinateU8(14)]), Position3([CoordinateU8(21), CoordinateU8(19), CoordinateU8(15)]), Position3([CoordinateU8(21), CoordinateU8(19), CoordinateU8(16)]), Position3([CoordinateU8(21), CoordinateU8(19), CoordinateU8(17)]), Position3([CoordinateU8(21), CoordinateU8(19), CoordinateU8(18)]), Position3([CoordinateU8(21), CoordinateU8(19), CoordinateU8(19)]), Position3([CoordinateU8(21), CoordinateU8(19), CoordinateU8(20)]), Position3([CoordinateU8(21), CoordinateU8(19), CoordinateU8(21)]), Position3([CoordinateU8(21), CoordinateU8(19), CoordinateU8(22)]), Position3([CoordinateU8(21), CoordinateU8(20), CoordinateU8(5)]), Position3([CoordinateU8(21), CoordinateU8(20), CoordinateU8(6)]), Position3([CoordinateU8(21), CoordinateU8(20), CoordinateU8(7)]), Position3([CoordinateU8(21), CoordinateU8(20), CoordinateU8(8)]), Position3([CoordinateU8(21), CoordinateU8(20), CoordinateU8(9)]), Position3([CoordinateU8(21), CoordinateU8(20), CoordinateU8(10)]), Position3([CoordinateU8(21), CoordinateU8(20), CoordinateU8(11)]), Position3([CoordinateU8(21), CoordinateU8(20), CoordinateU8(12)]), Position3([CoordinateU8(21), CoordinateU8(20), CoordinateU8(13)]), Position3([CoordinateU8(21), CoordinateU8(20), CoordinateU8(14)]), Position3([CoordinateU8(21), CoordinateU8(20), CoordinateU8(15)]), Position3([CoordinateU8(21), CoordinateU8(20), CoordinateU8(16)]), Position3([CoordinateU8(21), CoordinateU8(20), CoordinateU8(17)]), Position3([CoordinateU8(21), CoordinateU8(20), CoordinateU8(18)]), Position3([CoordinateU8(21), CoordinateU8(20), CoordinateU8(19)]), Position3([CoordinateU8(21), CoordinateU8(20), CoordinateU8(20)]), Position3([CoordinateU8(21), CoordinateU8(20), CoordinateU8(21)]), Position3([CoordinateU8(21), CoordinateU8(20), CoordinateU8(22)]), Position3([CoordinateU8(21), CoordinateU8(21), CoordinateU8(5)]), Position3([CoordinateU8(21), CoordinateU8(21), CoordinateU8(6)]), Position3([CoordinateU8(21), CoordinateU8(21), CoordinateU8(7)]), Position3([CoordinateU8(21), CoordinateU8(21), CoordinateU8(8)]), Position3([CoordinateU8(21), CoordinateU8(21), CoordinateU8(9)]), Position3([CoordinateU8(21), CoordinateU8(21), CoordinateU8(10)]), Position3([CoordinateU8(21), CoordinateU8(21), CoordinateU8(11)]), Position3([CoordinateU8(21), CoordinateU8(21), CoordinateU8(12)]), Position3([CoordinateU8(21), CoordinateU8(21), CoordinateU8(13)]), Position3([CoordinateU8(21), CoordinateU8(21), CoordinateU8(14)]), Position3([CoordinateU8(21), CoordinateU8(21), CoordinateU8(15)]), Position3([CoordinateU8(21), CoordinateU8(21), CoordinateU8(16)]), Position3([CoordinateU8(21), CoordinateU8(21), CoordinateU8(17)]), Position3([CoordinateU8(21), CoordinateU8(21), CoordinateU8(18)]), Position3([CoordinateU8(21), CoordinateU8(21), CoordinateU8(19)]), Position3([CoordinateU8(21), CoordinateU8(21), CoordinateU8(20)]), Position3([CoordinateU8(21), CoordinateU8(21), CoordinateU8(21)]), Position3([CoordinateU8(21), CoordinateU8(21), CoordinateU8(22)]), Position3([CoordinateU8(21), CoordinateU8(22), CoordinateU8(5)]), Position3([CoordinateU8(21), CoordinateU8(22), CoordinateU8(6)]), Position3([CoordinateU8(21), CoordinateU8(22), CoordinateU8(7)]), Position3([CoordinateU8(21), CoordinateU8(22), CoordinateU8(8)]), Position3([CoordinateU8(21), CoordinateU8(22), CoordinateU8(9)]), Position3([CoordinateU8(21), CoordinateU8(22), CoordinateU8(10)]), Position3([CoordinateU8(21), CoordinateU8(22), CoordinateU8(11)]), Position3([CoordinateU8(21), CoordinateU8(22), CoordinateU8(12)]), Position3([CoordinateU8(21), CoordinateU8(22), CoordinateU8(13)]), Position3([CoordinateU8(21), CoordinateU8(22), CoordinateU8(14)]), Position3([CoordinateU8(21), CoordinateU8(22), CoordinateU8(15)]), Position3([CoordinateU8(21), CoordinateU8(22), CoordinateU8(16)]), Position3([CoordinateU8(21), CoordinateU8(22), CoordinateU8(17)]), Position3([CoordinateU8(21), CoordinateU8(22), CoordinateU8(18)]), Position3([CoordinateU8(21), CoordinateU8(22), CoordinateU8(19)]), Position3([CoordinateU8(21), CoordinateU8(22), CoordinateU8(20)]), Position3([CoordinateU8(21), CoordinateU8(22), CoordinateU8(21)]), Position3([CoordinateU8(21), CoordinateU8(22), CoordinateU8(22)]), Position3([CoordinateU8(22), CoordinateU8(3), CoordinateU8(5)]), Position3([CoordinateU8(22), CoordinateU8(3), CoordinateU8(6)]), Position3([CoordinateU8(22), CoordinateU8(3), CoordinateU8(7)]), Position3([CoordinateU8(22), CoordinateU8(3), CoordinateU8(8)]), Position3([CoordinateU8(22), CoordinateU8(3), CoordinateU8(9)]), Position3([CoordinateU8(22), CoordinateU8(3), CoordinateU8(10)]), Position3([CoordinateU8(22), CoordinateU8(3), CoordinateU8(11)]), Position3([CoordinateU8(22), CoordinateU8(3), CoordinateU8(12)]), Position3([CoordinateU8(22), CoordinateU8(3), CoordinateU8(13)]), Position3([CoordinateU8(22), CoordinateU8(3), CoordinateU8(14)]), Position3([CoordinateU8(22), CoordinateU8(3), CoordinateU8(15)]), Position3([CoordinateU8(22), CoordinateU8(3), CoordinateU8(16)]), Position3([CoordinateU8(22), CoordinateU8(3), CoordinateU8(17)]), Position3([CoordinateU8(22), CoordinateU8(3), CoordinateU8(18)]), Position3([CoordinateU8(22), CoordinateU8(3), CoordinateU8(19)]), Position3([CoordinateU8(22), CoordinateU8(3), CoordinateU8(20)]), Position3([CoordinateU8(22), CoordinateU8(3), CoordinateU8(21)]), Position3([CoordinateU8(22), CoordinateU8(3), CoordinateU8(22)]), Position3([CoordinateU8(22), CoordinateU8(4), CoordinateU8(5)]), Position3([CoordinateU8(22), CoordinateU8(4), CoordinateU8(6)]), Position3([CoordinateU8(22), CoordinateU8(4), CoordinateU8(7)]), Position3([CoordinateU8(22), CoordinateU8(4), CoordinateU8(8)]), Position3([CoordinateU8(22), CoordinateU8(4), CoordinateU8(9)]), Position3([CoordinateU8(22), CoordinateU8(4), CoordinateU8(10)]), Position3([CoordinateU8(22), CoordinateU8(4), CoordinateU8(11)]), Position3([CoordinateU8(22), CoordinateU8(4), CoordinateU8(12)]), Position3([CoordinateU8(22), CoordinateU8(4), CoordinateU8(13)]), Position3([CoordinateU8(22), CoordinateU8(4), CoordinateU8(14)]), Position3([CoordinateU8(22), CoordinateU8(4), CoordinateU8(15)]), Position3([CoordinateU8(22), CoordinateU8(4), CoordinateU8(16)]), Position3([CoordinateU8(22), CoordinateU8(4), CoordinateU8(17)]), Position3([CoordinateU8(22), CoordinateU8(4), CoordinateU8(18)]), Position3([CoordinateU8(22), CoordinateU8(4), CoordinateU8(19)]), Position3([CoordinateU8(22), CoordinateU8(4), CoordinateU8(20)]), Position3([CoordinateU8(22), CoordinateU8(4), CoordinateU8(21)]), Position3([CoordinateU8(22), CoordinateU8(4), CoordinateU8(22)]), Position3([CoordinateU8(22), CoordinateU8(5), CoordinateU8(5)]), Position3([CoordinateU8(22), CoordinateU8(5), CoordinateU8(6)]), Position3([CoordinateU8(22), CoordinateU8(5), CoordinateU8(7)]), Position3([CoordinateU8(22), CoordinateU8(5), CoordinateU8(8)]), Position3([CoordinateU8(22), CoordinateU8(5), CoordinateU8(9)]), Position3([CoordinateU8(22), CoordinateU8(5), CoordinateU8(10)]), Position3([CoordinateU8(22), CoordinateU8(5), CoordinateU8(11)]), Position3([CoordinateU8(22), CoordinateU8(5), CoordinateU8(12)]), Position3([CoordinateU8(22), CoordinateU8(5), CoordinateU8(13)]), Position3([CoordinateU8(22), CoordinateU8(5), CoordinateU8(14)]), Position3([CoordinateU8(22), CoordinateU8(5), CoordinateU8(15)]), Position3([CoordinateU8(22), CoordinateU8(5), CoordinateU8(16)]), Position3([CoordinateU8(22), CoordinateU8(5), CoordinateU8(17)]), Position3([CoordinateU8(22), CoordinateU8(5), CoordinateU8(18)]), Position3([CoordinateU8(22), CoordinateU8(5), CoordinateU8(19)]), Position3([CoordinateU8(22), CoordinateU8(5), CoordinateU8(20)]), Position3([CoordinateU8(22), CoordinateU8(5), CoordinateU8(21)]), Position3([CoordinateU8(22), CoordinateU8(5), CoordinateU8(22)]), Position3([CoordinateU8(22), CoordinateU8(6), CoordinateU8(5)]), Position3([CoordinateU8(22), CoordinateU8(6), CoordinateU8(6)]), Position3([CoordinateU8(22), CoordinateU8(6), CoordinateU8(7)]), Position3([CoordinateU8(22), CoordinateU8(6), CoordinateU8(8)]), Position3([CoordinateU8(22), CoordinateU8(6), CoordinateU8(9)]), Position3([CoordinateU8(22), CoordinateU8(6), CoordinateU8(10)]), Position3([CoordinateU8(22), CoordinateU8(6), CoordinateU8(11)]), Position3([CoordinateU8(22), CoordinateU8(6), CoordinateU8(12)]), Position3([CoordinateU8(22), CoordinateU8(6), CoordinateU8(13)]), Position3([CoordinateU8(22), CoordinateU8(6), CoordinateU8(14)]), Position3([CoordinateU8(22), CoordinateU8(6), CoordinateU8(15)]), Position3([CoordinateU8(22), CoordinateU8(6), CoordinateU8(16)]), Position3([CoordinateU8(22), CoordinateU8(6), CoordinateU8(17)]), Position3([CoordinateU8(22), CoordinateU8(6), CoordinateU8(18)]), Position3([CoordinateU8(22), CoordinateU8(6), CoordinateU8(19)]), Position3([CoordinateU8(22), CoordinateU8(6), CoordinateU8(20)]), Position3([CoordinateU8(22), CoordinateU8(6), CoordinateU8(21)]), Position3([CoordinateU8(22), CoordinateU8(6), CoordinateU8(22)]), Position3([CoordinateU8(22), CoordinateU8(7), CoordinateU8(5)]), Position3([CoordinateU8(22), CoordinateU8(7), CoordinateU8(6)]), Position3([CoordinateU8(22), CoordinateU8(7), CoordinateU8(7)]), Position3([CoordinateU8(22), CoordinateU8(7), CoordinateU8(8)]), Position3([CoordinateU8(22), CoordinateU8(7), CoordinateU8(9)]), Position3([CoordinateU8(22), CoordinateU8(7), CoordinateU8(10)]), Position3([CoordinateU8(22), CoordinateU8(7), CoordinateU8(11)]), Position3([CoordinateU8(22), CoordinateU8(7), CoordinateU8(12)]), Position3([CoordinateU8(22), CoordinateU8(7), CoordinateU8(13)]), Position3([CoordinateU8(22), CoordinateU8(7), CoordinateU8(14)]), Position3([CoordinateU8(22), CoordinateU8(7), CoordinateU8(15)]), Position3([CoordinateU8(22), CoordinateU8(7), CoordinateU8(16)]), Position3([CoordinateU8(22), CoordinateU8(7), CoordinateU8(17)]), Position3([CoordinateU8(22), CoordinateU8(7), CoordinateU8(18)]), Position3([CoordinateU8(22), CoordinateU8(7), CoordinateU8(19)]), Position3([CoordinateU8(22), CoordinateU8(7), CoordinateU8(20)]), Position3([CoordinateU8(22), CoordinateU8(7), CoordinateU8(21)]), Position3([CoordinateU8(22), CoordinateU8(7), CoordinateU8(22)]), Position3([CoordinateU8(22), CoordinateU8(8), CoordinateU8(5)]), Position3([CoordinateU8(22), CoordinateU8(8), CoordinateU8(6)]), Position3([CoordinateU8(22), CoordinateU8(8), CoordinateU8(7)]), Position3([CoordinateU8(22), CoordinateU8(8), CoordinateU8(8)]), Position3([CoordinateU8(22), CoordinateU8(8), CoordinateU8(9)]), Position3([CoordinateU8(22), CoordinateU8(8), CoordinateU8(10)]), Position3([CoordinateU8(22), CoordinateU8(8), CoordinateU8(11)]), Position3([CoordinateU8(22), CoordinateU8(8), CoordinateU8(12)]), Position3([CoordinateU8(22), CoordinateU8(8), CoordinateU8(13)]), Position3([CoordinateU8(22), CoordinateU8(8), CoordinateU8(14)]), Position3([CoordinateU8(22), CoordinateU8(8), CoordinateU8(15)]), Position3([CoordinateU8(22), CoordinateU8(8), CoordinateU8(16)]), Position3([CoordinateU8(22), CoordinateU8(8), CoordinateU8(17)]), Position3([CoordinateU8(22), CoordinateU8(8), CoordinateU8(18)]), Position3([CoordinateU8(22), CoordinateU8(8), CoordinateU8(19)]), Position3([CoordinateU8(22), CoordinateU8(8), CoordinateU8(20)]), Position3([CoordinateU8(22), CoordinateU8(8), CoordinateU8(21)]), Position3([CoordinateU8(22), CoordinateU8(8), CoordinateU8(22)]), Position3([CoordinateU8(22), CoordinateU8(9), CoordinateU8(5)]), Position3([CoordinateU8(22), CoordinateU8(9), CoordinateU8(6)]), Position3([CoordinateU8(22), CoordinateU8(9), CoordinateU8(7)]), Position3([CoordinateU8(22), CoordinateU8(9), CoordinateU8(8)]), Position3([CoordinateU8(22), CoordinateU8(9), CoordinateU8(9)]), Position3([CoordinateU8(22), CoordinateU8(9), CoordinateU8(10)]), Position3([CoordinateU8(22), CoordinateU8(9), CoordinateU8(11)]), Position3([CoordinateU8(22), CoordinateU8(9), CoordinateU8(12)]), Position3([CoordinateU8(22), CoordinateU8(9), CoordinateU8(13)]), Position3([CoordinateU8(22), CoordinateU8(9), CoordinateU8(14)]), Position3([CoordinateU8(22), CoordinateU8(9), CoordinateU8(15)]), Position3([CoordinateU8(22), CoordinateU8(9), CoordinateU8(16)]), Position3([CoordinateU8(22), CoordinateU8(9), CoordinateU8(17)]), Position3([CoordinateU8(22), CoordinateU8(9), CoordinateU8(18)]), Position3([CoordinateU8(22), CoordinateU8(9), CoordinateU8(19)]), Position3([CoordinateU8(22), CoordinateU8(9), CoordinateU8(20)]), Position3([CoordinateU8(22), CoordinateU8(9), CoordinateU8(21)]), Position3([CoordinateU8(22), CoordinateU8(9), CoordinateU8(22)]), Position3([CoordinateU8(22), CoordinateU8(10), CoordinateU8(5)]), Position3([CoordinateU8(22), CoordinateU8(10), CoordinateU8(6)]), Position3([CoordinateU8(22), CoordinateU8(10), CoordinateU8(7)]), Position3([CoordinateU8(22), CoordinateU8(10), CoordinateU8(8)]), Position3([CoordinateU8(22), CoordinateU8(10), CoordinateU8(9)]), Position3([CoordinateU8(22), CoordinateU8(10), CoordinateU8(10)]), Position3([CoordinateU8(22), CoordinateU8(10), CoordinateU8(11)]), Position3([CoordinateU8(22), CoordinateU8(10), CoordinateU8(12)]), Position3([CoordinateU8(22), CoordinateU8(10), CoordinateU8(13)]), Position3([CoordinateU8(22), CoordinateU8(10), CoordinateU8(14)]), Position3([CoordinateU8(22), CoordinateU8(10), CoordinateU8(15)]), Position3([CoordinateU8(22), CoordinateU8(10), CoordinateU8(16)]), Position3([CoordinateU8(22), CoordinateU8(10), CoordinateU8(17)]), Position3([CoordinateU8(22), CoordinateU8(10), CoordinateU8(18)]), Position3([CoordinateU8(22), CoordinateU8(10), CoordinateU8(19)]), Position3([CoordinateU8(22), CoordinateU8(10), CoordinateU8(20)]), Position3([CoordinateU8(22), CoordinateU8(10), CoordinateU8(21)]), Position3([CoordinateU8(22), CoordinateU8(10), CoordinateU8(22)]), Position3([CoordinateU8(22), CoordinateU8(11), CoordinateU8(5)]), Position3([CoordinateU8(22), CoordinateU8(11), CoordinateU8(6)]), Position3([CoordinateU8(22), CoordinateU8(11), CoordinateU8(7)]), Position3([CoordinateU8(22), CoordinateU8(11), CoordinateU8(8)]), Position3([CoordinateU8(22), CoordinateU8(11), CoordinateU8(9)]), Position3([CoordinateU8(22), CoordinateU8(11), CoordinateU8(10)]), Position3([CoordinateU8(22), CoordinateU8(11), CoordinateU8(11)]), Position3([CoordinateU8(22), CoordinateU8(11), CoordinateU8(12)]), Position3([CoordinateU8(22), CoordinateU8(11), CoordinateU8(13)]), Position3([CoordinateU8(22), CoordinateU8(11), CoordinateU8(14)]), Position3([CoordinateU8(22), CoordinateU8(11), CoordinateU8(15)]), Position3([CoordinateU8(22), CoordinateU8(11), CoordinateU8(16)]), Position3([CoordinateU8(22), CoordinateU8(11), CoordinateU8(17)]), Position3([CoordinateU8(22), CoordinateU8(11), CoordinateU8(18)]), Position3([CoordinateU8(22), CoordinateU8(11), CoordinateU8(19)]), Position3([CoordinateU8(22), CoordinateU8(11), CoordinateU8(20)]), Position3([CoordinateU8(22), CoordinateU8(11), CoordinateU8(21)]), Position3([CoordinateU8(22), CoordinateU8(11), CoordinateU8(22)]), Position3([CoordinateU8(22), CoordinateU8(12), CoordinateU8(5)]), Position3([CoordinateU8(22), CoordinateU8(12), CoordinateU8(6)]), Position3([CoordinateU8(22), CoordinateU8(12), CoordinateU8(7)]), Position3([CoordinateU8(22), CoordinateU8(12), CoordinateU8(8)]), Position3([CoordinateU8(22), CoordinateU8(12), CoordinateU8(9)]), Position3([CoordinateU8(22), CoordinateU8(12), CoordinateU8(10)]), Position3([CoordinateU8(22), CoordinateU8(12), CoordinateU8(11)]), Position3([CoordinateU8(22), CoordinateU8(12), CoordinateU8(12)]), Position3([CoordinateU8(22), CoordinateU8(12), CoordinateU8(13)]), Position3([CoordinateU8(22), CoordinateU8(12), CoordinateU8(14)]), Position3([CoordinateU8(22), CoordinateU8(12), CoordinateU8(15)]), Position3([CoordinateU8(22), CoordinateU8(12), CoordinateU8(16)]), Position3([CoordinateU8(22), CoordinateU8(12), CoordinateU8(17)]), Position3([CoordinateU8(22), CoordinateU8(12), CoordinateU8(18)]), Position3([CoordinateU8(22), CoordinateU8(12), CoordinateU8(19)]), Position3([CoordinateU8(22), CoordinateU8(12), CoordinateU8(20)]), Position3([CoordinateU8(22), CoordinateU8(12), CoordinateU8(21)]), Position3([CoordinateU8(22), CoordinateU8(12), CoordinateU8(22)]), Position3([CoordinateU8(22), CoordinateU8(13), CoordinateU8(5)]), Position3([CoordinateU8(22), CoordinateU8(13), CoordinateU8(6)]), Position3([CoordinateU8(22), CoordinateU8(13), CoordinateU8(7)]), Position3([CoordinateU8(22), CoordinateU8(13), CoordinateU8(8)]), Position3([CoordinateU8(22), CoordinateU8(13), CoordinateU8(9)]), Position3([CoordinateU8(22), CoordinateU8(13), CoordinateU8(10)]), Position3([CoordinateU8(22), CoordinateU8(13), CoordinateU8(11)]), Position3([CoordinateU8(22), CoordinateU8(13), CoordinateU8(12)]), Position3([CoordinateU8(22), CoordinateU8(13), CoordinateU8(13)]), Position3([CoordinateU8(22), CoordinateU8(13), CoordinateU8(14)]), Position3([CoordinateU8(22), CoordinateU8(13), CoordinateU8(15)]), Position3([CoordinateU8(22), CoordinateU8(13), CoordinateU8(16)]), Position3([CoordinateU8(22), CoordinateU8(13), CoordinateU8(17)]), Position3([CoordinateU8(22), CoordinateU8(13), CoordinateU8(18)]), Position3([CoordinateU8(22), CoordinateU8(13), CoordinateU8(19)]), Position3([CoordinateU8(22), CoordinateU8(13), CoordinateU8(20)]), Position3([CoordinateU8(22), CoordinateU8(13), CoordinateU8(21)]), Position3([CoordinateU8(22), CoordinateU8(13), CoordinateU8(22)]), Position3([CoordinateU8(22), CoordinateU8(14), CoordinateU8(5)]), Position3([CoordinateU8(22), CoordinateU8(14), CoordinateU8(6)]), Position3([CoordinateU8(22), CoordinateU8(14), CoordinateU8(7)]), Position3([CoordinateU8(22), CoordinateU8(14), CoordinateU8(8)]), Position3([CoordinateU8(22), CoordinateU8(14), CoordinateU8(9)]), Position3([CoordinateU8(22), CoordinateU8(14), CoordinateU8(10)]), Position3([CoordinateU8(22), CoordinateU8(14), CoordinateU8(11)]), Position3([CoordinateU8(22), CoordinateU8(14), CoordinateU8(12)]), Position3([CoordinateU8(22), CoordinateU8(14), CoordinateU8(13)]), Position3([CoordinateU8(22), CoordinateU8(14), CoordinateU8(14)]), Position3([CoordinateU8(22), CoordinateU8(14), CoordinateU8(15)]), Position3([CoordinateU8(22), CoordinateU8(14), CoordinateU8(16)]), Position3([CoordinateU8(22), CoordinateU8(14), CoordinateU8(17)]), Position3([CoordinateU8(22), CoordinateU8(14), CoordinateU8(18)]), Position3([CoordinateU8(22), CoordinateU8(14), CoordinateU8(19)]), Position3([CoordinateU8(22), CoordinateU8(14), CoordinateU8(20)]), Position3([CoordinateU8(22), CoordinateU8(14), CoordinateU8(21)]), Position3([CoordinateU8(22), CoordinateU8(14), CoordinateU8(22)]), Position3([CoordinateU8(22), CoordinateU8(15), CoordinateU8(5)]), Position3([CoordinateU8(22), CoordinateU8(15), CoordinateU8(6)]), Position3([CoordinateU8(22), CoordinateU8(15), CoordinateU8(7)]), Position3([CoordinateU8(22), CoordinateU8(15), CoordinateU8(8)]), Position3([CoordinateU8(22), CoordinateU8(15), CoordinateU8(9)]), Position3([CoordinateU8(22), CoordinateU8(15), CoordinateU8(10)]), Position3([CoordinateU8(22), CoordinateU8(15), CoordinateU8(11)]), Position3([CoordinateU8(22), CoordinateU8(15), CoordinateU8(12)]), Position3([CoordinateU8(22), CoordinateU8(15), CoordinateU8(13)]), Position3([CoordinateU8(22), CoordinateU8(15), CoordinateU8(14)]), Position3([CoordinateU8(22), CoordinateU8(15), CoordinateU8(15)]), Position3([CoordinateU8(22), CoordinateU8(15), CoordinateU8(16)]), Position3([CoordinateU8(22), CoordinateU8(15), CoordinateU8(17)]), Position3([CoordinateU8(22), CoordinateU8(15), CoordinateU8(18)]), Position3([CoordinateU8(22), CoordinateU8(15), CoordinateU8(19)]), Position3([CoordinateU8(22), CoordinateU8(15), CoordinateU8(20)]), Position3([CoordinateU8(22), CoordinateU8(15), CoordinateU8(21)]), Position3([CoordinateU8(22), CoordinateU8(15), CoordinateU8(22)]), Position3([CoordinateU8(22), CoordinateU8(16), CoordinateU8(5)]), Position3([CoordinateU8(22), CoordinateU8(16), CoordinateU8(6)]), Position3([CoordinateU8(22), CoordinateU8(16), CoordinateU8(7)]), Position3([CoordinateU8(22), CoordinateU8(16), CoordinateU8(8)]), Position3([CoordinateU8(22), CoordinateU8(16), CoordinateU8(9)]), Position3([CoordinateU8(22), CoordinateU8(16), CoordinateU8(10)]), Position3([CoordinateU8(22), CoordinateU8(16), CoordinateU8(11)]), Position3([CoordinateU8(22), CoordinateU8(16), CoordinateU8(12)]), Position3([CoordinateU8(22), CoordinateU8(16), CoordinateU8(13)]), Position3([CoordinateU8(22), CoordinateU8(16), CoordinateU8(14)]), Position3([CoordinateU8(22), CoordinateU8(16), CoordinateU8(15)]), Position3([CoordinateU8(22), CoordinateU8(16), CoordinateU8(16)]), Position3([CoordinateU8(22), CoordinateU8(16), CoordinateU8(17)]), Position3([CoordinateU8(22), CoordinateU8(16), CoordinateU8(18)]), Position3([CoordinateU8(22), CoordinateU8(16), CoordinateU8(19)]), Position3([CoordinateU8(22), CoordinateU8(16), CoordinateU8(20)]), Position3([CoordinateU8(22), CoordinateU8(16), CoordinateU8(21)]), Position3([CoordinateU8(22), CoordinateU8(16), CoordinateU8(22)]), Position3([CoordinateU8(22), CoordinateU8(17), CoordinateU8(5)]), Position3([CoordinateU8(22), CoordinateU8(17), CoordinateU8(6)]), Position3([CoordinateU8(22), CoordinateU8(17), CoordinateU8(7)]), Position3([CoordinateU8(22), CoordinateU8(17), CoordinateU8(8)]), Position3([CoordinateU8(22), CoordinateU8(17), CoordinateU8(9)]), Position3([CoordinateU8(22), CoordinateU8(17), CoordinateU8(10)]), Position3([CoordinateU8(22), CoordinateU8(17), CoordinateU8(11)]), Position3([CoordinateU8(22), CoordinateU8(17), CoordinateU8(12)]), Position3([CoordinateU8(22), CoordinateU8(17), CoordinateU8(13)]), Position3([CoordinateU8(22), CoordinateU8(17), CoordinateU8(14)]), Position3([CoordinateU8(22), CoordinateU8(17), CoordinateU8(15)]), Position3([CoordinateU8(22), CoordinateU8(17), CoordinateU8(16)]), Position3([CoordinateU8(22), CoordinateU8(17), CoordinateU8(17)]), Position3([CoordinateU8(22), CoordinateU8(17), CoordinateU8(18)]), Position3([CoordinateU8(22), CoordinateU8(17), CoordinateU8(19)]), Position3([CoordinateU8(22), CoordinateU8(17), CoordinateU8(20)]), Position3([CoordinateU8(22), CoordinateU8(17), CoordinateU8(21)]), Position3([CoordinateU8(22), CoordinateU8(17), CoordinateU8(22)]), Position3([CoordinateU8(22), CoordinateU8(18), CoordinateU8(5)]), Position3([CoordinateU8(22), CoordinateU8(18), CoordinateU8(6)]), Position3([CoordinateU8(22), CoordinateU8(18), CoordinateU8(7)]), Position3([CoordinateU8(22), CoordinateU8(18), CoordinateU8(8)]), Position3([CoordinateU8(22), CoordinateU8(18), CoordinateU8(9)]), Position3([CoordinateU8(22), CoordinateU8(18), CoordinateU8(10)]), Position3([CoordinateU8(22), CoordinateU8(18), CoordinateU8(11)]), Position3([CoordinateU8(22), CoordinateU8(18), CoordinateU8(12)]), Position3([CoordinateU8(22), CoordinateU8(18), CoordinateU8(13)]), Position3([CoordinateU8(22), CoordinateU8(18), CoordinateU8(14)]), Position3([CoordinateU8(22), CoordinateU8(18), CoordinateU8(15)]), Position3([CoordinateU8(22), CoordinateU8(18), CoordinateU8(16)]), Position3([CoordinateU8(22), CoordinateU8(18), CoordinateU8(17)]), Position3([CoordinateU8(22), CoordinateU8(18), CoordinateU8(18)]), Position3([CoordinateU8(22), CoordinateU8(18), CoordinateU8(19)]), Position3([CoordinateU8(22), CoordinateU8(18), CoordinateU8(20)]), Position3([CoordinateU8(22), CoordinateU8(18), CoordinateU8(21)]), Position3([CoordinateU8(22), CoordinateU8(18), CoordinateU8(22)]), Position3([CoordinateU8(22), CoordinateU8(19), CoordinateU8(5)]), Position3([CoordinateU8(22), CoordinateU8(19), CoordinateU8(6)]), Position3([CoordinateU8(22), CoordinateU8(19), CoordinateU8(7)]), Position3([CoordinateU8(22), CoordinateU8(19), CoordinateU8(8)]), Position3([CoordinateU8(22), CoordinateU8(19), CoordinateU8(9)]), Position3([CoordinateU8(22), CoordinateU8(19), CoordinateU8(10)]), Position3([CoordinateU8(22), CoordinateU8(19), CoordinateU8(11)]), Position3([CoordinateU8(22), CoordinateU8(19), CoordinateU8(12)]), Position3([CoordinateU8(22), CoordinateU8(19), CoordinateU8(13)]), Position3([CoordinateU8(22), CoordinateU8(19), CoordinateU8(14)]), Position3([CoordinateU8(22), CoordinateU8(19), CoordinateU8(15)]), Position3([CoordinateU8(22), CoordinateU8(19), CoordinateU8(16)]), Position3([CoordinateU8(22), CoordinateU8(19), CoordinateU8(17)]), Position3([CoordinateU8(22), CoordinateU8(19), CoordinateU8(18)]), Position3([CoordinateU8(22), CoordinateU8(19), CoordinateU8(19)]), Position3([CoordinateU8(22), CoordinateU8(19), CoordinateU8(20)]), Position3([CoordinateU8(22), CoordinateU8(19), CoordinateU8(21)]), Position3([CoordinateU8(22), CoordinateU8(19), CoordinateU8(22)]), Position3([CoordinateU8(22), CoordinateU8(20), CoordinateU8(5)]), Position3([CoordinateU8(22), CoordinateU8(20), CoordinateU8(6)]), Position3([CoordinateU8(22), CoordinateU8(20), CoordinateU8(7)]), Position3([CoordinateU8(22), CoordinateU8(20), CoordinateU8(8)]), Position3([CoordinateU8(22), CoordinateU8(20), CoordinateU8(9)]), Position3([CoordinateU8(22), CoordinateU8(20), CoordinateU8(10)]), Position3([CoordinateU8(22), CoordinateU8(20), CoordinateU8(11)]), Position3([CoordinateU8(22), CoordinateU8(20), CoordinateU8(12)]), Position3([CoordinateU8(22), CoordinateU8(20), CoordinateU8(13)]), Position3([CoordinateU8(22), CoordinateU8(20), CoordinateU8(14)]), Position3([CoordinateU8(22), CoordinateU8(20), CoordinateU8(15)]), Position3([CoordinateU8(22), CoordinateU8(20), CoordinateU8(16)]), Position3([CoordinateU8(22), CoordinateU8(20), CoordinateU8(17)]), Position3([CoordinateU8(22), CoordinateU8(20), CoordinateU8(18)]), Position3([CoordinateU8(22), CoordinateU8(20), CoordinateU8(19)]), Position3([CoordinateU8(22), CoordinateU8(20), CoordinateU8(20)]), Position3([CoordinateU8(22), CoordinateU8(20), CoordinateU8(21)]), Position3([CoordinateU8(22), CoordinateU8(20), CoordinateU8(22)]), Position3([CoordinateU8(22), CoordinateU8(21), CoordinateU8(5)]), Position3([CoordinateU8(22), CoordinateU8(21), CoordinateU8(6)]), Position3([CoordinateU8(22), CoordinateU8(21), CoordinateU8(7)]), Position3([CoordinateU8(22), CoordinateU8(21), CoordinateU8(8)]), Position3([CoordinateU8(22), CoordinateU8(21), CoordinateU8(9)]), Position3([CoordinateU8(22), CoordinateU8(21), CoordinateU8(10)]), Position3([CoordinateU8(22), CoordinateU8(21), CoordinateU8(11)]), Position3([CoordinateU8(22), CoordinateU8(21), CoordinateU8(12)]), Position3([CoordinateU8(22), CoordinateU8(21), CoordinateU8(13)]), Position3([CoordinateU8(22), CoordinateU8(21), CoordinateU8(14)]), Position3([CoordinateU8(22), CoordinateU8(21), CoordinateU8(15)]), Position3([CoordinateU8(22), CoordinateU8(21), CoordinateU8(16)]), Position3([CoordinateU8(22), CoordinateU8(21), CoordinateU8(17)]), Position3([CoordinateU8(22), CoordinateU8(21), CoordinateU8(18)]), Position3([CoordinateU8(22), CoordinateU8(21), CoordinateU8(19)]), Position3([CoordinateU8(22), CoordinateU8(21), CoordinateU8(20)]), Position3([CoordinateU8(22), CoordinateU8(21), CoordinateU8(21)]), Position3([CoordinateU8(22), CoordinateU8(21), CoordinateU8(22)]), Position3([CoordinateU8(22), CoordinateU8(22), CoordinateU8(5)]), Position3([CoordinateU8(22), CoordinateU8(22), CoordinateU8(6)]), Position3([CoordinateU8(22), CoordinateU8(22), CoordinateU8(7)]), Position3([CoordinateU8(22), CoordinateU8(22), CoordinateU8(8)]), Position3([CoordinateU8(22), CoordinateU8(22), CoordinateU8(9)]), Position3([CoordinateU8(22), CoordinateU8(22), CoordinateU8(10)]), Position3([CoordinateU8(22), CoordinateU8(22), CoordinateU8(11)]), Position3([CoordinateU8(22), CoordinateU8(22), CoordinateU8(12)]), Position3([CoordinateU8(22), CoordinateU8(22), CoordinateU8(13)]), Position3([CoordinateU8(22), CoordinateU8(22), CoordinateU8(14)]), Position3([CoordinateU8(22), CoordinateU8(22), CoordinateU8(15)]), Position3([CoordinateU8(22), CoordinateU8(22), CoordinateU8(16)]), Position3([CoordinateU8(22), CoordinateU8(22), CoordinateU8(17)]), Position3([CoordinateU8(22), CoordinateU8(22), CoordinateU8(18)]), Position3([CoordinateU8(22), CoordinateU8(22), CoordinateU8(19)]), Position3([CoordinateU8(22), CoordinateU8(22), CoordinateU8(20)]), Position3([CoordinateU8(22), CoordinateU8(22), CoordinateU8(21)]), Position3([CoordinateU8(22), CoordinateU8(22), CoordinateU8(22)])]");
 }
+
+#[test]
+pub fn check_mesh() {
+    // Tetrahedron with its right angle at the origin.
+    let vertices: Vec<Position> = vec![
+        vec![0.0, 0.0, 0.0].into(),
+        vec![6.0, 0.0, 0.0].into(),
+        vec![0.0, 6.0, 0.0].into(),
+        vec![0.0, 0.0, 6.0].into(),
+    ];
+    let faces = vec![[0, 2, 1], [0, 1, 3], [0, 3, 2], [1, 2, 3]];
+    let s = Shape::Mesh(vertices, faces);
+
+    assert_eq!(s.volume(), 36.0);
+    assert!(s.contains(&vec![1.0, 1.0, 1.0].into()));
+    assert!(!s.contains(&vec![3.0, 3.0, 3.0].into()));
+    assert!(!s.contains(&vec![-1.0, 1.0, 1.0].into()));
+
+    let (lower, higher) = s.get_mbb();
+    assert_eq!(lower, vec![0.0, 0.0, 0.0].into());
+    assert_eq!(higher, vec![6.0, 6.0, 6.0].into());
+    assert!(s.validate().is_ok());
+
+    let invalid = |shape: Shape| matches!(shape.validate(), Err(Error::InvalidShape(_)));
+    let triangle = || -> Vec<Position> {
+        vec![
+            vec![0.0, 0.0, 0.0].into(),
+            vec![6.0, 0.0, 0.0].into(),
+            vec![0.0, 6.0, 0.0].into(),
+        ]
+    };
+
+    assert!(invalid(Shape::Mesh(vec![], vec![])));
+    assert!(invalid(Shape::Mesh(triangle(), vec![])));
+    assert!(invalid(Shape::Mesh(triangle(), vec![[0, 1, 3]])));
+
+    let mut flat = triangle();
+    flat[2] = vec![0.0, 6.0].into();
+    assert!(invalid(Shape::Mesh(flat, vec![[0, 1, 2]])));
+
+    // Meshes are checked within composite shapes as well.
+    let empty = Shape::Mesh(vec![], vec![]);
+    assert!(invalid(Shape::Union(vec![s.clone(), empty.clone()])));
+    assert!(invalid(Shape::Difference(Box::new(s), Box::new(empty))));
+}
+
+#[test]
+pub fn check_rotated_shapes() {
+    let s_1 = get1();
+    let s_3 = get3();
+
+    // Sphere of 4cm of radius at [0, 0, 0] cm in space1, which becomes an
+    // ellipsoid in space3, as its axes are rotated and have different
+    // resolutions.
+    let sphere = Shape::HyperSphere(vec![40u64, 40, 40].into(), Coordinate::CoordinateU8(4));
+    let ellipsoid = sphere.rebase(&s_1, &s_3).unwrap();
+    match &ellipsoid {
+        Shape::Ellipsoid(center, _) => assert_eq!(center, &vec![4000u64, 400, 40].into()),
+        s => panic!("expected an ellipsoid, found {:?}", s),
+    }
+
+    // Volume in ticks of space3, each being 0.001 cm^3.
+    let volume = 4.0 / 3.0 * std::f64::consts::PI * 64.0 * 1000.0;
+    assert!((ellipsoid.volume() - volume).abs() < 1e-6 * volume);
+
+    // 3.9cm and 4.1cm away from the center along the X axis of space1.
+    let d = 3.9 / std::f64::consts::SQRT_2;
+    assert!(ellipsoid.contains(&vec![4000.0 + d * 100.0, 400.0 - d * 10.0, 40.0].into()));
+    let d = 4.1 / std::f64::consts::SQRT_2;
+    assert!(!ellipsoid.contains(&vec![4000.0 + d * 100.0, 400.0 - d * 10.0, 40.0].into()));
+
+    // Converting back collapses into a sphere again.
+    match ellipsoid.rebase(&s_3, &s_1).unwrap() {
+        Shape::HyperSphere(center, radius) => {
+            assert_eq!(center, vec![40u64, 40, 40].into());
+            assert_eq!(radius, Coordinate::CoordinateU8(4));
+        }
+        s => panic!("expected a sphere, found {:?}", s),
+    }
+
+    // Same for boxes.
+    let b = Shape::BoundingBox(vec![38u64, 38, 38].into(), vec![42u64, 42, 42].into());
+    let oriented = b.rebase(&s_1, &s_3).unwrap();
+    match &oriented {
+        Shape::OrientedBox(_, vectors) => assert_eq!(vectors.len(), 3),
+        s => panic!("expected an oriented box, found {:?}", s),
+    }
+    assert!((oriented.volume() - 64.0 * 1000.0).abs() < 1e-6 * 64.0 * 1000.0);
+
+    let (lower, higher) = oriented.rebase(&s_3, &s_1).unwrap().get_mbb();
+    assert_eq!(lower, vec![38u64, 38, 38].into());
+    assert_eq!(higher, vec![42u64, 42, 42].into());
+}
+
+#[test]
+pub fn check_oriented_shapes() {
+    let center: Position = vec![0.0, 0.0].into();
+    let vectors = |v: [[f64; 2]; 2]| v.iter().map(|v| v.to_vec().into()).collect::<Vec<_>>();
+    let invalid = |shape: Shape| matches!(shape.validate(), Err(Error::InvalidShape(_)));
+
+    let shape = Shape::OrientedBox(center.clone(), vectors([[2.0, 0.0], [0.0, 1.0]]));
+    assert!(shape.validate().is_ok());
+    assert!(shape.contains(&vec![1.5, 0.5].into()));
+
+    for v in &[
+        [[f64::NAN, 0.0], [0.0, 1.0]],
+        [[f64::INFINITY, 0.0], [0.0, 1.0]],
+        [[1.0, 1.0], [2.0, 2.0]],
+        [[0.0, 0.0], [0.0, 1.0]],
+    ] {
+        assert!(invalid(Shape::OrientedBox(center.clone(), vectors(*v))));
+        assert!(invalid(Shape::Ellipsoid(center.clone(), vectors(*v))));
+    }
+    assert!(invalid(Shape::Ellipsoid(
+        center.clone(),
+        vec![vec![1.0, 0.0].into()]
+    )));
+    assert!(invalid(Shape::Ellipsoid(
+        center.clone(),
+        vec![vec![1.0].into(), vec![0.0, 1.0].into()]
+    )));
+
+    // Unchecked shapes do not panic on values which are not numbers.
+    let shape = Shape::Ellipsoid(center, vectors([[f64::NAN, 0.0], [0.0, 1.0]]));
+    assert!(!shape.contains(&vec![0.5, 0.5].into()));
+}
+
+#[test]
+pub fn check_composite() {
+    let a = Shape::BoundingBox(vec![10u64].into(), vec![15u64].into());
+    let b = Shape::BoundingBox(vec![13u64].into(), vec![18u64].into());
+
+    let union = Shape::Union(vec![a.clone(), b.clone()]);
+    assert_eq!(format!("{:?}", union.rasterise().unwrap()), "[Position1(CoordinateU8(10)), Position1(CoordinateU8(11)), Position1(CoordinateU8(12)), Position1(CoordinateU8(13)), Position1(CoordinateU8(14)), Position1(CoordinateU8(15)), Position1(CoordinateU8(16)), Position1(CoordinateU8(17))]");
+
+    let intersection = Shape::Intersection(vec![a.clone(), b.clone()]);
+    assert_eq!(
+        format!("{:?}", intersection.rasterise().unwrap()),
+        "[Position1(CoordinateU8(13)), Position1(CoordinateU8(14))]"
+    );
+
+    let difference = Shape::Difference(Box::new(a), Box::new(b));
+    assert_eq!(
+        format!("{:?}", difference.rasterise().unwrap()),
+        "[Position1(CoordinateU8(10)), Position1(CoordinateU8(11)), Position1(CoordinateU8(12))]"
+    );
+    assert!(difference.validate().is_ok());
+
+    // Empty lists have no bounding box.
+    for empty in &[Shape::Union(vec![]), Shape::Intersection(vec![])] {
+        assert!(matches!(empty.validate(), Err(Error::InvalidShape(_))));
+    }
+}
+
+#[test]
+pub fn check_mask() {
+    let mut voxels = Voxels::new(vec![4, 3]);
+    voxels.insert(&[1, 0]).unwrap();
+    voxels.insert(&[2, 1]).unwrap();
+    assert!(voxels.insert(&[4, 0]).is_err());
+    assert_eq!(voxels.len(), 2);
+
+    // Voxels of 2 by 1 ticks, starting at [10, 20].
+    let vectors = vec![vec![2.0, 0.0].into(), vec![0.0, 1.0].into()];
+    let mask = Shape::Mask(vec![10u64, 20].into(), vectors, voxels);
+
+    assert_eq!(mask.volume(), 4.0);
+    assert!(mask.contains(&vec![12u64, 20].into()));
+    assert!(mask.contains(&vec![15u64, 21].into()));
+    assert!(!mask.contains(&vec![10u64, 20].into()));
+    assert!(!mask.contains(&vec![16u64, 21].into()));
+
+    let (lower, higher) = mask.get_mbb();
+    assert_eq!(lower, vec![12u64, 20].into());
+    assert_eq!(higher, vec![16u64, 22].into());
+    assert_eq!(mask.rasterise().unwrap().len(), 4);
+    assert!(mask.validate().is_ok());
+
+    // The grid and the vectors must agree.
+    let mut voxels = Voxels::new(vec![4, 3, 2]);
+    voxels.insert(&[1, 0, 1]).unwrap();
+    let vectors = vec![vec![2.0, 0.0].into(), vec![0.0, 1.0].into()];
+    let mask = Shape::Mask(vec![10u64, 20].into(), vectors, voxels);
+    assert!(matches!(mask.validate(), Err(Error::InvalidShape(_))));
+
+    // The bits must cover the grid exactly.
+    let voxels: Voxels = serde_json::from_str(r#"{"dimensions":[8,8],"bits":[3]}"#).unwrap();
+    assert_eq!(voxels.len(), 2);
+    for invalid in &[
+        r#"{"dimensions":[8,9],"bits":[3]}"#,
+        r#"{"dimensions":[8,8],"bits":[]}"#,
+        r#"{"dimensions":[8,8],"bits":[3,0]}"#,
+    ] {
+        assert!(serde_json::from_str::<Voxels>(invalid).is_err());
+    }
+}
+
+#[test]
+pub fn check_dimensions() {
+    let axis = |v: Vec<f64>| Axis::new("mm", v, NumberSet::R, 0.0, 100.0, 100).unwrap();
+    let s_2 = Space::new(
+        "section",
+        CoordinateSystem::new(
+            vec![10.0, 20.0],
+            vec![axis(vec![1.0, 0.0]), axis(vec![0.0, 1.0])],
+        ),
+    );
+    let s_4 = Space::new(
+        "time",
+        CoordinateSystem::new(
+            vec![0.0, 0.0, 0.0, 0.0],
+            vec![
+                axis(vec![1.0, 0.0, 0.0, 0.0]),
+                axis(vec![0.0, 1.0, 0.0, 0.0]),
+                axis(vec![0.0, 0.0, 1.0, 0.0]),
+                axis(vec![0.0, 0.0, 0.0, 1.0]),
+            ],
+        ),
+    );
+    let s_u = Space::universe();
+
+    let p_2: Position = vec![5u64, 7].into();
+    let u = Space::change_base(&p_2, &s_2, s_u).unwrap();
+    assert_eq!(u.dimensions(), 2);
+    assert_eq!(Space::change_base(&u, s_u, &s_2).unwrap(), p_2);
+
+    let p_4: Position = vec![5u64, 7, 11, 13].into();
+    let u = Space::change_base(&p_4, &s_4, s_u).unwrap();
+    assert_eq!(Space::change_base(&u, s_u, &s_4).unwrap(), p_4);
+
+    // Spaces with different number of dimensions can not be connected.
+    assert!(Space::change_base(&p_2, &s_2, &s_4).is_err());
+}
+
+#[test]
+pub fn check_affine() {
+    let graduations = || vec![Graduation::new(NumberSet::R, 0.0, 100.0, 100).unwrap(); 3];
+    let close = |a: &Position, b: &[f64]| (0..b.len()).all(|k| (a[k].f64() - b[k]).abs() < 1e-9);
+
+    // Shear of x along y, scaling of x and z, and translation, in mm.
+    let matrix = vec![
+        vec![2.0, 1.0, 0.0, 10.0],
+        vec![0.0, 1.0, 0.0, -5.0],
+        vec![0.0, 0.0, 0.5, 3.0],
+        vec![0.0, 0.0, 0.0, 1.0],
+    ];
+    let s_a = Space::new(
+        "affine",
+        CoordinateSystem::from_matrix(&matrix, "mm", graduations()).unwrap(),
+    );
+    let s_u = Space::universe();
+
+    let p: Position = vec![10u64, 20, 30].into();
+    let u = Space::change_base(&p, &s_a, s_u).unwrap();
+    assert!(close(&u, &[0.050, 0.015, 0.018]));
+
+    // Center of the same voxel.
+    let u: Position = vec![0.0515, 0.0155, 0.01825].into();
+    assert_eq!(Space::change_base(&u, s_u, &s_a).unwrap(), p);
+
+    let (low, high) = s_a.bounding_box();
+    assert!(close(&low, &[0.010, -0.005, 0.003]));
+    assert!(close(&high, &[0.310, 0.095, 0.053]));
+    assert!((s_a.volume() - 1.0e6).abs() < 1e-6);
+
+    // The transformation must be invertible.
+    let mut singular = matrix;
+    singular[1] = vec![2.0, 1.0, 0.0, 0.0];
+    assert!(CoordinateSystem::from_matrix(&singular, "mm", graduations()).is_err());
+
+    // As well as made of numbers.
+    singular[1] = vec![f64::NAN, 1.0, 0.0, 0.0];
+    match CoordinateSystem::from_matrix(&singular, "mm", graduations()) {
+        Err(Error::InvalidDefinition(_)) => (),
+        r => panic!("Unexpected result: {:?}", r),
+    }
+}
+
+#[test]
+pub fn check_deformed() {
+    let axis = |v: Vec<f64>| Axis::new("mm", v, NumberSet::R, 0.0, 100.0, 100).unwrap();
+    let affine = CoordinateSystem::new(
+        vec![0.0, 0.0, 0.0],
+        vec![
+            axis(vec![1.0, 0.0, 0.0]),
+            axis(vec![0.0, 1.0, 0.0]),
+            axis(vec![0.0, 0.0, 1.0]),
+        ],
+    );
+    let close = |a: &Position, b: &[f64]| (0..b.len()).all(|k| (a[k].f64() - b[k]).abs() < 1e-12);
+
+    // Displacement along x, growing from 0 to 2 mm over the first 100 mm.
+    let forward = DisplacementField::new(
+        vec![0.0, 0.0, 0.0],
+        vec![0.1, 0.1, 0.1],
+        vec![2, 1, 1],
+        vec![0.0, 0.0, 0.0, 0.002, 0.0, 0.0],
+    )
+    .unwrap();
+
+    // Interpolated between samples, and extended outside of the grid.
+    let d = forward.displacement(&vec![0.05, 0.5, -1.0].into()).unwrap();
+    assert!(close(&d, &[0.001, 0.0, 0.0]));
+    let d = forward.displacement(&vec![0.5, 0.0, 0.0].into()).unwrap();
+    assert!(close(&d, &[0.002, 0.0, 0.0]));
+
+    // The fields must match the number of dimensions of the system.
+    let planar =
+        DisplacementField::new(vec![0.0; 2], vec![0.1; 2], vec![1; 2], vec![0.0; 2]).unwrap();
+    assert!(CoordinateSystem::deformed(affine.clone(), planar.clone(), planar).is_err());
+
+    let inverse = forward.inverse();
+    let s_d = Space::new(
+        "deformed",
+        CoordinateSystem::deformed(affine, forward, inverse).unwrap(),
+    );
+    let s_u = Space::universe();
+
+    let p: Position = vec![50u64, 20, 30].into();
+    let u = Space::change_base(&p, &s_d, s_u).unwrap();
+    assert!(close(&u, &[0.051, 0.020, 0.030]));
+
+    // Center of the same voxel, once displaced.
+    let u: Position = vec![0.0505 * 1.02, 0.0205, 0.0305].into();
+    assert_eq!(Space::change_base(&u, s_u, &s_d).unwrap(), p);
+
+    let (low, high) = s_d.bounding_box();
+    assert!(close(&low, &[0.0, 0.0, 0.0]));
+    assert!(close(&high, &[0.102, 0.1, 0.1]));
+}
+
+#[test]
+pub fn check_transform() {
+    let axis = |v: Vec<f64>| Axis::new("mm", v, NumberSet::R, 0.0, 100.0, 100).unwrap();
+    let system = |origin: Vec<f64>| {
+        CoordinateSystem::new(
+            origin,
+            vec![
+                axis(vec![1.0, 0.0, 0.0]),
+                axis(vec![0.0, 1.0, 0.0]),
+                axis(vec![0.0, 0.0, 1.0]),
+            ],
+        )
+    };
+    let close = |a: &[f64], b: &[f64]| (0..b.len()).all(|k| (a[k] - b[k]).abs() < 1e-12);
+
+    // Rotation around z, followed by a translation.
+    let t = Transform::new(vec![
+        vec![0.0, -1.0, 0.0, 50.0],
+        vec![1.0, 0.0, 0.0, 0.0],
+        vec![0.0, 0.0, 1.0, 10.0],
+    ])
+    .unwrap();
+    assert!(close(
+        &t.apply(&[10.0, 20.0, 30.0]).unwrap(),
+        &[30.0, 10.0, 40.0]
+    ));
+    assert!(close(
+        &t.apply_vector(&[10.0, 20.0, 30.0]).unwrap(),
+        &[-20.0, 10.0, 30.0]
+    ));
+    assert!(t.apply(&[10.0, 20.0]).is_err());
+
+    let i = t.inverse().unwrap();
+    assert!(close(
+        &i.apply(&[30.0, 10.0, 40.0]).unwrap(),
+        &[10.0, 20.0, 30.0]
+    ));
+    let identity = t.then(&i).unwrap();
+    for (row, expected) in identity
+        .matrix()
+        .iter()
+        .zip(Transform::identity(3).matrix())
+    {
+        assert!(close(row, expected));
+    }
+    assert!(Transform::new(vec![vec![1.0, 0.0], vec![1.0, 0.0]])
+        .unwrap()
+        .inverse()
+        .is_err());
+
+    // The target space is placed arbitrarily in the Universe, so the
+    // direct transformation is the only correct one.
+    let s_a = Space::new("a", system(vec![0.0, 0.0, 0.0]));
+    let s_b = Space::new("b", system(vec![1.0, 1.0, 1.0]));
+    let conversion = Conversion::new(&s_a, &s_b, Some(std::sync::Arc::new(t)));
+
+    let p = s_a.encode(&[10.0, 20.0, 30.0]).unwrap();
+    assert_eq!(
+        conversion.position(&p).unwrap(),
+        s_b.encode(&[30.0, 10.0, 40.0]).unwrap()
+    );
+    assert_eq!(
+        conversion.vector(&vec![10.0, 20.0, 30.0].into()).unwrap(),
+        s_b.encode_vector(&[-20.0, 10.0, 30.0]).unwrap()
+    );
+
+    match Shape::Point(p.clone()).convert(&conversion).unwrap() {
+        Shape::Point(position) => assert_eq!(position, conversion.position(&p).unwrap()),
+        shape => panic!("Unexpected shape: {:?}", shape),
+    }
+
+    // Without transformation, the Universe is used.
+    let conversion = Conversion::new(&s_a, &s_b, None);
+    assert_eq!(
+        conversion.position(&p).unwrap(),
+        Space::change_base(&p, &s_a, &s_b).unwrap()
+    );
+}
+
+#[test]
+pub fn check_out_of_bounds() {
+    let axis = |v: Vec<f64>| Axis::new("mm", v, NumberSet::R, 0.0, 100.0, 100).unwrap();
+    let s_a = Space::new(
+        "a",
+        CoordinateSystem::new(
+            vec![0.0, 0.0],
+            vec![axis(vec![1.0, 0.0]), axis(vec![0.0, 1.0])],
+        ),
+    );
+    let s_u = Space::universe();
+
+    // Outside of the space along its first axis.
+    let u: Position = vec![0.150, 0.050].into();
+    let clipped = s_a.encode(&[100.0, 50.0]).unwrap();
+
+    assert_eq!(Space::change_base(&u, s_u, &s_a).unwrap(), clipped);
+
+    let conversion = Conversion::new(s_u, &s_a, None);
+    assert_eq!(
+        conversion
+            .clone()
+            .with_bounds(OutOfBounds::Clip)
+            .position(&u)
+            .unwrap(),
+        clipped
+    );
+    for bounds in &[OutOfBounds::Reject, OutOfBounds::Drop] {
+        match conversion.clone().with_bounds(*bounds).position(&u) {
+            Err(Error::OutOfRange {
+                space, axis, value, ..
+            }) => {
+                assert_eq!((space.as_str(), axis), ("a", 0));
+                assert!((value - 150.0).abs() < 1e-9);
+            }
+            r => panic!("Unexpected result: {:?}", r),
+        }
+    }
+    match s_a.encode(&[50.0, 150.0]) {
+        Err(Error::OutOfRange { space, axis, .. }) => assert_eq!((space.as_str(), axis), ("a", 1)),
+        r => panic!("Unexpected result: {:?}", r),
+    }
+
+    // Positions within the space are not affected.
+    let u: Position = vec![0.050, 0.050].into();
+    let conversion = conversion.with_bounds(OutOfBounds::Reject);
+    assert_eq!(
+        conversion.position(&u).unwrap(),
+        s_a.encode(&[50.0, 50.0]).unwrap()
+    );
+
+    let a = &s_a.axes()[0];
+    assert!(a.encode(-1.0).is_err());
+    assert_eq!(
+        a.encode_bounded(-1.0, OutOfBounds::Clip).unwrap(),
+        a.encode(0.0).unwrap()
+    );
+    assert!(a.encode_bounded(-1.0, OutOfBounds::Reject).is_err());
+}
+
+#[test]
+pub fn check_number_set() {
+    for set in &[NumberSet::N, NumberSet::Z, NumberSet::Q, NumberSet::R] {
+        let name: String = set.into();
+        assert_eq!(&NumberSet::from(name.as_str()), set);
+    }
+
+    // Axes of integers whose bounds or ticks are not integers are
+    // rejected, unless they predate the enforcement of the sets, in
+    // which case they are treated as axes of real numbers.
+    let invalid = [
+        (NumberSet::N, -10.0, 20),
+        (NumberSet::Z, -10.5, 20),
+        (NumberSet::Z, -10.0, 40),
+    ];
+    for (set, minimum, steps) in invalid.iter().cloned() {
+        match Graduation::new(set.clone(), minimum, 10.0, steps) {
+            Err(Error::InvalidDefinition(_)) => (),
+            r => panic!("Unexpected result: {:?}", r),
+        }
+
+        let legacy = Graduation::legacy(set, minimum, 10.0, steps).unwrap();
+        assert_eq!(legacy.set, NumberSet::R);
+    }
+
+    let set = |set, minimum, steps| Graduation::new(set, minimum, 10.0, steps).unwrap().set;
+    assert_eq!(set(NumberSet::Z, -10.0, 10), NumberSet::Z);
+    assert_eq!(set(NumberSet::N, 0.0, 10), NumberSet::N);
+    assert!(Graduation::new(NumberSet::R, f64::NAN, 10.0, 40).is_err());
+
+    // The same applies to graduations read from index files.
+    let stored = Graduation {
+        set: NumberSet::N,
+        minimum: 0.0,
+        maximum: 1.0,
+        steps: 1000,
+        epsilon: 0.001,
+    };
+    let stored = bincode::serialize(&stored).unwrap();
+    let graduation = bincode::deserialize::<Graduation>(&stored).unwrap();
+    assert_eq!(graduation.set, NumberSet::R);
+    assert_eq!(graduation.epsilon, 0.001);
+
+    let axis = |set| Axis::new("mm", vec![1.0], set, 0.0, 100.0, 100).unwrap();
+    let n = axis(NumberSet::N);
+    let r = axis(NumberSet::R);
+
+    match n.encode(2.5) {
+        Err(Error::NotInSet { value, set }) => {
+            assert_eq!(value, 2.5);
+            assert_eq!(set, "N");
+        }
+        c => panic!("Unexpected result: {:?}", c),
+    }
+    assert_eq!(n.encode(3.0 - 1e-12).unwrap(), n.encode(3.0).unwrap());
+    assert_eq!(
+        n.encode_bounded(2.6, OutOfBounds::Clip).unwrap(),
+        n.encode(3.0).unwrap()
+    );
+    assert_eq!(n.decode(&n.encode(3.0).unwrap()).unwrap(), 3.0);
+    assert!(n.encode(-1.0).is_err());
+
+    assert!((r.decode(&r.encode(2.5).unwrap()).unwrap() - 2.0).abs() < 1e-12);
+}
+
+#[test]
+pub fn check_reduce_precision() {
+    let p: Position = vec![17u64, 17, 17].into();
+
+    assert_eq!(p.reduce_precision(&[0, 0, 0]), p);
+    assert_eq!(p.reduce_precision(&[1, 1, 1]), vec![8u64, 8, 8].into());
+    assert_eq!(p.reduce_precision(&[0, 1, 4]), vec![17u64, 8, 1].into());
+}
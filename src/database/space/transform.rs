@@ -0,0 +1,319 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::axis::OutOfBounds;
+use super::position::Position;
+use super::shape::Shape;
+use super::Space;
+use crate::Error;
+
+/// Affine transformation between the decoded coordinates of two
+/// reference spaces.
+///
+/// This allows positions to be converted directly from one space to
+/// another, without going through the Universe.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Transform {
+    // One row per dimension of the target space, with one column per
+    // dimension of the source space, followed by the translation.
+    matrix: Vec<Vec<f64>>,
+}
+
+impl Transform {
+    /// Instantiate a new transformation.
+    ///
+    /// # Parameters
+    ///
+    ///  * `matrix`:
+    ///      Row-major matrix, with one row per dimension of the target
+    ///      space. Each row contains one coefficient per dimension of
+    ///      the source space, followed by the translation.
+    pub fn new(matrix: Vec<Vec<f64>>) -> Result<Self, Error> {
+        let columns = match matrix.first() {
+            Some(row) => row.len(),
+            None => 0,
+        };
+
+        if columns < 2 || matrix.iter().any(|row| row.len() != columns) {
+            return Err(Error::InvalidDefinition(format!(
+                "Transformation matrix rows must have the same number of columns, at least 2: {:?}",
+                matrix
+            )));
+        }
+
+        if matrix.iter().flatten().any(|v| !v.is_finite()) {
+            return Err(Error::InvalidDefinition(format!(
+                "Transformation matrix with non-finite values: {:?}",
+                matrix
+            )));
+        }
+
+        Ok(Transform { matrix })
+    }
+
+    /// The transformation leaving positions unchanged.
+    ///
+    /// # Parameters
+    ///
+    ///  * `dimensions`:
+    ///      Number of dimensions of the positions.
+    pub fn identity(dimensions: usize) -> Self {
+        let matrix = (0..dimensions)
+            .map(|i| {
+                (0..=dimensions)
+                    .map(|k| if i == k { 1.0 } else { 0.0 })
+                    .collect()
+            })
+            .collect();
+
+        Transform { matrix }
+    }
+
+    /// Number of dimensions of the source space.
+    pub fn inputs(&self) -> usize {
+        self.matrix[0].len() - 1
+    }
+
+    /// Number of dimensions of the target space.
+    pub fn outputs(&self) -> usize {
+        self.matrix.len()
+    }
+
+    /// The transformation matrix, see [`new`](#method.new).
+    pub fn matrix(&self) -> &Vec<Vec<f64>> {
+        &self.matrix
+    }
+
+    /// Compose this transformation with the one applied after it.
+    ///
+    /// # Parameters
+    ///
+    ///  * `next`:
+    ///      Transformation from the target space of `self` to another
+    ///      space.
+    pub fn then(&self, next: &Transform) -> Result<Transform, Error> {
+        if next.inputs() != self.outputs() {
+            return Err(Error::DimensionMismatch {
+                expected: self.outputs(),
+                found: next.inputs(),
+            });
+        }
+
+        let n = self.inputs();
+        let matrix = next
+            .matrix
+            .iter()
+            .map(|row| {
+                (0..=n)
+                    .map(|k| {
+                        let mut v = self
+                            .matrix
+                            .iter()
+                            .zip(row)
+                            .map(|(inner, c)| c * inner[k])
+                            .sum::<f64>();
+                        if k == n {
+                            v += row[self.outputs()];
+                        }
+                        v
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Ok(Transform { matrix })
+    }
+
+    /// Compute the transformation in the opposite direction.
+    ///
+    /// Only transformations between spaces with the same number of
+    /// dimensions, which are not singular, can be inverted.
+    pub fn inverse(&self) -> Result<Transform, Error> {
+        let n = self.inputs();
+        if self.outputs() != n {
+            return Err(Error::DimensionMismatch {
+                expected: n,
+                found: self.outputs(),
+            });
+        }
+
+        let zero: Position = vec![0.0; n].into();
+        let columns = (0..n)
+            .map(|k| self.matrix.iter().map(|row| row[k]).collect())
+            .collect::<Vec<Position>>();
+
+        // Solve for each unit vector, then for the translation.
+        let mut solutions = vec![];
+        for k in 0..=n {
+            let target: Position = (0..n)
+                .map(|i| {
+                    if k == n {
+                        -self.matrix[i][n]
+                    } else if k == i {
+                        1.0
+                    } else {
+                        0.0
+                    }
+                })
+                .collect();
+
+            match Shape::coefficients(&zero, &columns, &target) {
+                Some(solution) => solutions.push(solution),
+                None => {
+                    return Err(Error::InvalidDefinition(format!(
+                        "Singular transformation matrix: {:?}",
+                        self.matrix
+                    )))
+                }
+            }
+        }
+
+        let matrix = (0..n)
+            .map(|i| solutions.iter().map(|s| s[i]).collect())
+            .collect();
+
+        Ok(Transform { matrix })
+    }
+
+    /// Transform a position.
+    ///
+    /// # Parameters
+    ///
+    ///  * `position`:
+    ///      expressed in decoded coordinates of the source space.
+    pub fn apply(&self, position: &[f64]) -> Result<Vec<f64>, Error> {
+        let n = self.inputs();
+
+        Ok(self
+            .apply_vector(position)?
+            .into_iter()
+            .zip(&self.matrix)
+            .map(|(v, row)| v + row[n])
+            .collect())
+    }
+
+    /// Transform a vector, which is not translated.
+    ///
+    /// # Parameters
+    ///
+    ///  * `vector`:
+    ///      expressed in decoded lengths of the source space.
+    pub fn apply_vector(&self, vector: &[f64]) -> Result<Vec<f64>, Error> {
+        if vector.len() != self.inputs() {
+            return Err(Error::DimensionMismatch {
+                expected: self.inputs(),
+                found: vector.len(),
+            });
+        }
+
+        Ok(self
+            .matrix
+            .iter()
+            .map(|row| row.iter().zip(vector).map(|(c, v)| c * v).sum())
+            .collect())
+    }
+}
+
+/// Conversion of encoded coordinates from one reference space to
+/// another.
+///
+/// When a direct transformation between the two spaces is known, it is
+/// used, otherwise the coordinates are converted through the Universe,
+/// see [`Space::change_base`](struct.Space.html#method.change_base).
+///
+/// Positions outside of the target space are clipped to its boundaries,
+/// unless specified otherwise with [`with_bounds`](#method.with_bounds).
+#[derive(Clone, Debug)]
+pub struct Conversion<'s> {
+    from: &'s Space,
+    to: &'s Space,
+    transform: Option<Arc<Transform>>,
+    bounds: OutOfBounds,
+}
+
+impl<'s> Conversion<'s> {
+    /// Instantiate a new conversion.
+    ///
+    /// # Parameters
+    ///
+    ///  * `from`:
+    ///      Space in which the coordinates are defined.
+    ///
+    ///  * `to`:
+    ///      Target space in which the coordinates should be expressed.
+    ///
+    ///  * `transform`:
+    ///      Direct transformation from `from` to `to`, if any.
+    pub fn new(from: &'s Space, to: &'s Space, transform: Option<Arc<Transform>>) -> Self {
+        Conversion {
+            from,
+            to,
+            transform,
+            bounds: OutOfBounds::Clip,
+        }
+    }
+
+    /// Set how positions outside of the target space are handled.
+    ///
+    /// # Parameters
+    ///
+    ///  * `bounds`:
+    ///      The policy to apply to converted positions.
+    pub fn with_bounds(self, bounds: OutOfBounds) -> Conversion<'s> {
+        Conversion { bounds, ..self }
+    }
+
+    /// Space in which the coordinates are defined.
+    pub fn from(&self) -> &'s Space {
+        self.from
+    }
+
+    /// Target space of the conversion.
+    pub fn to(&self) -> &'s Space {
+        self.to
+    }
+
+    /// How positions outside of the target space are handled.
+    pub fn bounds(&self) -> OutOfBounds {
+        self.bounds
+    }
+
+    /// Direct transformation used, if any.
+    pub fn transform(&self) -> Option<&Transform> {
+        self.transform.as_deref()
+    }
+
+    /// Convert a position.
+    ///
+    /// # Parameters
+    ///
+    ///  * `position`:
+    ///      expressed as encoded coordinates in the source space.
+    pub fn position(&self, position: &Position) -> Result<Position, Error> {
+        match &self.transform {
+            None => self
+                .to
+                .rebase(&self.from.absolute_position(position)?, self.bounds),
+            Some(transform) => self
+                .to
+                .encode_bounded(&transform.apply(&self.from.decode(position)?)?, self.bounds),
+        }
+    }
+
+    /// Convert a vector.
+    ///
+    /// # Parameters
+    ///
+    ///  * `vector`:
+    ///      expressed as encoded lengths in the source space.
+    pub fn vector(&self, vector: &Position) -> Result<Position, Error> {
+        match &self.transform {
+            None => Space::change_base_vector(vector, self.from, self.to),
+            Some(transform) => self
+                .to
+                .encode_vector(&transform.apply_vector(&self.from.decode_vector(vector)?)?),
+        }
+    }
+}
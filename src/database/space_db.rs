@@ -1,22 +1,27 @@
-use std::cmp::Ordering;
 use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
-use std::convert::TryInto;
 use std::hash::Hash;
 use std::hash::Hasher;
+use std::path::Path;
 
 use serde::Deserialize;
 use serde::Serialize;
 
+use super::external_sort::Run;
+use super::external_sort::Sorter;
+use super::space::Conversion;
+use super::space::Coordinate;
 use super::space::Position;
 use super::space::Shape;
 use super::space::Space;
-use super::space_index::SpaceFields;
 use super::space_index::SpaceIndex;
+use super::space_index::SpaceIndexV1;
 use super::space_index::SpaceSetIndex;
 use super::space_index::SpaceSetObject;
+use super::space_index::CELL_BITS;
 use super::CoreQueryParameters;
 use super::IterPositions;
+use crate::Error;
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SpaceDB {
@@ -24,48 +29,56 @@ pub struct SpaceDB {
     resolutions: Vec<SpaceIndex>,
 }
 
+// Layout of `SpaceDB` in index files of format 1 and older.
+#[derive(Deserialize)]
+pub struct SpaceDBV1 {
+    reference_space: String,
+    resolutions: Vec<SpaceIndexV1>,
+}
+
+impl From<SpaceDBV1> for SpaceDB {
+    fn from(v1: SpaceDBV1) -> Self {
+        SpaceDB {
+            reference_space: v1.reference_space,
+            resolutions: v1.resolutions.into_iter().map(|r| r.into()).collect(),
+        }
+    }
+}
+
 impl SpaceDB {
     pub fn new(
         reference_space: &Space,
         mut space_objects: Vec<SpaceSetObject>,
         scales: Option<Vec<Vec<u32>>>,
         max_elements: Option<usize>,
-    ) -> Self {
-        //FIXME: Remove hard-coded constants for dimensions & bit length of morton codes.
-        const DIMENSIONS: usize = 3;
-        const CELL_BITS: usize = 10;
+    ) -> Result<Self, Error> {
+        let dimensions = reference_space.axes().len();
 
         // Build the set of SpaceIndices.
-        let mut resolutions = vec![];
         let mut indices = vec![];
 
-        if let Some(mut scales) = scales {
-            // We optimize scaling, by iteratively building coarser and coarser
-            // indexes. Powers holds a list of bit shift to apply based on the
-            // previous value.
-            let mut powers = Vec::with_capacity(scales.len());
+        if let Some(scales) = scales {
+            let powers = scale_powers(scales, dimensions)?;
 
-            // Limit temporary values lifetimes
-            {
-                scales.sort_unstable_by_key(|v| v[0]);
-
-                let mut previous = 0u32;
-                for scale in scales {
-                    // FIXME: Remove these assertions ASAP, and support multi-factor scaling
-                    assert_eq!(scale.len(), DIMENSIONS);
-                    assert!(scale[0] == scale[1] && scale[0] == scale[2]);
+            // Keep the full resolution objects only if we need to start over.
+            let full = if powers.iter().any(|(_, _, restart)| *restart) {
+                Some(space_objects.clone())
+            } else {
+                None
+            };
 
-                    powers.push((scale[0], scale[0] - previous));
-                    previous = scale[0];
+            // Apply fixed scales
+            for (scale, power, restart) in powers.iter() {
+                if *restart {
+                    if let Some(full) = &full {
+                        space_objects = full.clone();
+                    }
                 }
-            }
 
-            // Apply fixed scales
-            for (count, power) in powers.iter().enumerate() {
                 space_objects = space_objects
                     .into_iter()
                     .map(|mut o| {
-                        let p = o.position().reduce_precision(power.1);
+                        let p = o.position().reduce_precision(power);
                         let mut hasher = DefaultHasher::new();
                         o.set_position(p);
 
@@ -79,18 +92,16 @@ impl SpaceDB {
                     .map(|(_k, v)| v)
                     .collect();
 
-                // Make sure we do not shift more position than available
-                let shift: u32 = if count >= 31 { 31 } else { count.try_into().unwrap() };
                 indices.push((
-                    SpaceSetIndex::new(space_objects.iter(), DIMENSIONS, CELL_BITS),
-                    vec![power.0, power.0, power.0],
-                    shift,
+                    SpaceSetIndex::new(space_objects.iter(), dimensions, CELL_BITS),
+                    scale.clone(),
                 ));
             }
         } else {
             // Generate scales, following max_elements
             if let Some(max_elements) = max_elements {
                 let mut count = 0;
+                let one = vec![1; dimensions];
 
                 // The next index should contain at most half the number of
                 // elements of the current index.
@@ -98,9 +109,8 @@ impl SpaceDB {
 
                 // Insert Full resolution index.
                 indices.push((
-                    SpaceSetIndex::new(space_objects.iter(), DIMENSIONS, CELL_BITS),
-                    vec![count, count, count],
-                    0, // Smallest value => highest resolution
+                    SpaceSetIndex::new(space_objects.iter(), dimensions, CELL_BITS),
+                    vec![count; dimensions],
                 ));
 
                 // Generate coarser indices, until we reach the expect max_element
@@ -110,13 +120,12 @@ impl SpaceDB {
                     if space_objects.len() <= max_elements || count > 31 {
                         break;
                     }
-                    let shift = count;
 
                     count += 1;
                     space_objects = space_objects
                         .into_iter()
                         .map(|mut o| {
-                            let p = o.position().reduce_precision(1);
+                            let p = o.position().reduce_precision(&one);
                             let mut hasher = DefaultHasher::new();
                             o.set_position(p);
 
@@ -141,9 +150,8 @@ impl SpaceDB {
                     }
 
                     indices.push((
-                        SpaceSetIndex::new(space_objects.iter(), DIMENSIONS, CELL_BITS),
-                        vec![count, count, count],
-                        shift,
+                        SpaceSetIndex::new(space_objects.iter(), dimensions, CELL_BITS),
+                        vec![count; dimensions],
                     ));
                 }
 
@@ -152,25 +160,136 @@ impl SpaceDB {
             } else {
                 // Generate only full-scale.
                 indices.push((
-                    SpaceSetIndex::new(space_objects.iter(), DIMENSIONS, CELL_BITS),
-                    vec![0, 0, 0],
-                    0,
+                    SpaceSetIndex::new(space_objects.iter(), dimensions, CELL_BITS),
+                    vec![0; dimensions],
                 ));
             }
         }
 
-        // When done, go over the array, and set the threshold_volumes with Volume total / 8 * i in reverse order
-        let space_volume = reference_space.volume();
-        let max_shift = match indices.last() {
-            None => 31,
-            Some((_, _, x)) => *x,
-        };
+        Ok(SpaceDB::from_indices(reference_space, indices))
+    }
 
-        for (index, scale, shift) in indices {
-            // Compute threshold volume as Vt = V / 2^(max_shift) * 2^shift
-            //  => the smaller shift is, the smaller the threshold is and the higher
-            //     the resolution is.
-            let volume = space_volume / f64::from(1 << (max_shift - shift));
+    // Build the same set of resolutions as `new`, without holding the
+    // positions in memory.
+    //
+    // `space_objects` holds the positions of this space, already encoded,
+    // and linked to provisional values. `remap` provides for each of those
+    // values its final offset in the list of properties.
+    //
+    // Each resolution is computed by sorting the positions of the previous
+    // one on disk, in chunks of `chunk_size` elements within `directory`.
+    // Only the SFC index of each resolution is built in memory.
+    pub fn new_external(
+        reference_space: &Space,
+        space_objects: Sorter,
+        remap: &[usize],
+        scales: Option<Vec<Vec<u32>>>,
+        max_elements: Option<usize>,
+        directory: &Path,
+        chunk_size: usize,
+    ) -> Result<Self, Error> {
+        let dimensions = reference_space.axes().len();
+        let space_id = reference_space.name();
+
+        let full = space_objects.finish(|(key, value)| (key, remap[value]))?;
+
+        // Build the set of SpaceIndices.
+        let mut indices = vec![];
+
+        if let Some(scales) = scales {
+            let powers = scale_powers(scales, dimensions)?;
+            let mut current: Option<Run> = None;
+
+            // Apply fixed scales
+            for (scale, power, restart) in powers.iter() {
+                let previous = match &current {
+                    Some(run) if !*restart => run,
+                    _ => &full,
+                };
+                let run = previous.reduce_precision(power, directory, chunk_size)?;
+
+                indices.push((index_run(&run, space_id, dimensions)?, scale.clone()));
+
+                current = Some(run);
+            }
+        } else {
+            // Generate scales, following max_elements
+            if let Some(max_elements) = max_elements {
+                let mut count = 0;
+                let one = vec![1; dimensions];
+
+                // The next index should contain at most half the number of
+                // elements of the current index.
+                let mut element_count_target = full.len() / 2;
+
+                // Insert Full resolution index.
+                indices.push((
+                    index_run(&full, space_id, dimensions)?,
+                    vec![count; dimensions],
+                ));
+
+                let mut current = full;
+
+                // Generate coarser indices, until we reach the expect max_element
+                // values or we can't define bigger bit shift.
+                loop {
+                    // Make sure we do not shift more position than available as well.
+                    if current.len() <= max_elements || count > 31 {
+                        break;
+                    }
+
+                    count += 1;
+                    current = current.reduce_precision(&one, directory, chunk_size)?;
+
+                    // Skip a resolution if it does not bring down enough the
+                    // number of points. It would be a waste of space to store it.
+                    if element_count_target < current.len() {
+                        continue;
+                    } else {
+                        // The next index should contain at most half the number of
+                        // elements of the current index.
+                        element_count_target = current.len() / 2;
+                    }
+
+                    indices.push((
+                        index_run(&current, space_id, dimensions)?,
+                        vec![count; dimensions],
+                    ));
+                }
+            } else {
+                // Generate only full-scale.
+                indices.push((
+                    index_run(&full, space_id, dimensions)?,
+                    vec![0; dimensions],
+                ));
+            }
+        }
+
+        Ok(SpaceDB::from_indices(reference_space, indices))
+    }
+
+    fn from_indices(reference_space: &Space, indices: Vec<(SpaceSetIndex, Vec<u32>)>) -> Self {
+        let mut resolutions = vec![];
+
+        // Each coarser bit on an axis doubles the length of the cells of
+        // the index along that axis, so the volume of a cell is
+        // proportional to 2^(sum of the scale).
+        let cell_bits = |scale: &[u32]| scale.iter().map(|s| i64::from(*s)).sum::<i64>();
+        let max_bits = indices
+            .iter()
+            .map(|(_, scale)| cell_bits(scale))
+            .max()
+            .unwrap_or(0);
+
+        let space_volume = reference_space.volume();
+        for (index, scale) in indices {
+            // Compute threshold volume as Vt = V * Vc / Vc_max, where Vc is
+            // the volume of a cell of this resolution, and Vc_max the one
+            // of the coarsest resolution.
+            //  => the finer the cells are, the smaller the threshold is and
+            //     the higher the resolution is.
+            let bits = (cell_bits(&scale) - max_bits).max(i64::from(i32::MIN));
+            let volume = space_volume * 2f64.powi(bits as i32);
 
             resolutions.push(SpaceIndex::new(volume, scale, index));
         }
@@ -178,9 +297,10 @@ impl SpaceDB {
         // Make sure the vector is sorted by threshold volumes, smallest to largest.
         // this means indices are sorted form highest resolution to lowest resolution.
         // default_resolution() relies on this to find the correct index.
-        resolutions.sort_unstable_by(|a, b| match a.threshold().partial_cmp(&b.threshold()) {
-            Some(o) => o,
-            None => Ordering::Less, // FIXME: This is most likely incorrect...
+        resolutions.sort_unstable_by(|a, b| {
+            a.threshold()
+                .total_cmp(&b.threshold())
+                .then_with(|| a.scale().cmp(b.scale()))
         });
 
         SpaceDB {
@@ -193,6 +313,51 @@ impl SpaceDB {
         &self.reference_space
     }
 
+    // Decode every resolution, if this was not yet done.
+    pub fn load(&self) -> Result<(), Error> {
+        for resolution in &self.resolutions {
+            resolution.load()?;
+        }
+
+        Ok(())
+    }
+
+    // Update every resolution of the index, without recomputing the set
+    // of resolutions.
+    //
+    // `space_objects` are added to the index. They are expressed in
+    // encoded space coordinates, and their values are expected to be
+    // already valid offsets in the updated list of properties.
+    //
+    // `remap` provides for each value currently stored its new offset in
+    // that list, or `None` if the positions linked to it are to be
+    // removed.
+    //
+    // See `SpaceIndex::update` for the cost of the changes.
+    pub fn update(
+        &mut self,
+        reference_space: &Space,
+        space_objects: &[SpaceSetObject],
+        remap: &[Option<usize>],
+    ) -> Result<(), Error> {
+        for resolution in self.resolutions.iter_mut() {
+            let scale = resolution.scale().clone();
+
+            let objects = space_objects
+                .iter()
+                .map(|o| {
+                    let mut o = o.clone();
+                    o.set_position(o.position().reduce_precision(&scale));
+                    o
+                })
+                .collect();
+
+            resolution.update(reference_space, objects, remap)?;
+        }
+
+        Ok(())
+    }
+
     /* Comment this for now, as this is not yet used.
     // The smallest volume threshold, which is the highest resolution,  will
     // be at position 0
@@ -230,15 +395,23 @@ impl SpaceDB {
         self.lowest_resolution()
     }
 
+    // Select the index with exactly the requested scale, or if there is
+    // none, the finest index which is at least as coarse on every axis.
     fn resolution_from_scale(&self, scale: &[u32]) -> usize {
+        if let Some(i) = self
+            .resolutions
+            .iter()
+            .position(|r| r.scale().as_slice() == scale)
+        {
+            debug!("Selected {:?} -> {:?}", i, scale);
+            return i;
+        }
+
         for i in 0..self.resolutions.len() {
-            if scale <= self.resolutions[i].scale() {
-                debug!(
-                    "Selected {:?} -> {:?} vs {:?}",
-                    i,
-                    self.resolutions[i].scale(),
-                    scale
-                );
+            let current = self.resolutions[i].scale();
+
+            if current.len() == scale.len() && current.iter().zip(scale).all(|(c, s)| c >= s) {
+                debug!("Selected {:?} -> {:?} vs {:?}", i, current, scale);
 
                 return i;
             }
@@ -283,7 +456,7 @@ impl SpaceDB {
         &'s self,
         id: usize,
         parameters: &CoreQueryParameters,
-    ) -> Result<IterPositions<'s>, String> {
+    ) -> Result<IterPositions<'s>, Error> {
         // Is that ID referenced in the current space?
         let index = self.resolution(parameters);
 
@@ -292,20 +465,12 @@ impl SpaceDB {
         let view_port = parameters.view_port(space);
 
         // Select the objects
-        // FIXME: How to return an iterator instead of instantiating all
-        //        the points here? Needed because of &SpaceFields.
-        let objects = self.resolutions[index]
-            .find_by_value(&SpaceFields::new(self.name(), id))
-            .collect::<Vec<_>>();
+        let objects = self.resolutions[index].find_by_value(self.name(), id)?;
 
         let results: IterPositions<'s> = if let Some(view_port) = view_port {
-            Box::new(
-                objects
-                    .into_iter()
-                    .filter(move |position| view_port.contains(position)),
-            )
+            Box::new(objects.filter(move |position| view_port.contains(position)))
         } else {
-            Box::new(objects.into_iter())
+            objects
         };
 
         Ok(results)
@@ -317,7 +482,7 @@ impl SpaceDB {
         &'s self,
         positions: impl Iterator<Item = Position> + 's,
         parameters: &CoreQueryParameters,
-    ) -> Result<Box<dyn Iterator<Item = (Position, &SpaceFields)> + 's>, String> {
+    ) -> Result<Box<dyn Iterator<Item = (Position, usize)> + 's>, Error> {
         let index = self.resolution(parameters);
 
         // FIXME: Should I do it here, or add the assumption this is a clean list?
@@ -325,16 +490,143 @@ impl SpaceDB {
         //let space = parameters.db.space(&self.reference_space)?;
         //let view_port = parameters.view_port(space);
 
+        // Decode the index beforehand, so that lookups cannot fail.
+        let resolution = &self.resolutions[index];
+        resolution.load()?;
+
         // Select the objects
         let results = positions.flat_map(move |position| {
-            self.resolutions[index]
+            resolution
                 .find(&position)
-                .map(move |fields| (position.clone(), fields))
+                .into_iter()
+                .flatten()
+                .map(move |value| (position.clone(), value))
         });
 
         Ok(Box::new(results))
     }
 
+    // Search the `k` nearest objects to a position.
+    //
+    // `center` is the position expressed in encoded coordinates of
+    // `space`, and `origin` in Universe coordinates. Results are the
+    // values of distinct objects, along with the distance of their
+    // nearest position to `origin` in the Universe, and are ordered by
+    // increasing distance.
+    //
+    // When `through` is set, the positions of the index are converted
+    // with it before being expressed in the Universe, and the ones it
+    // cannot convert are ignored.
+    //
+    // Starting from the cell of the index containing `center`, we query
+    // ever larger boxes of cells, until enough objects are found within the
+    // distance covered in all directions by the box.
+    // FIXME: The distance covered by the box is exact only for orthogonal
+    //        axes.
+    pub fn get_nearest(
+        &self,
+        space: &Space,
+        center: &Position,
+        through: Option<&Conversion>,
+        origin: &Position,
+        k: usize,
+        parameters: &CoreQueryParameters,
+    ) -> Result<Vec<(f64, usize)>, Error> {
+        let index = self.resolution(parameters);
+        let resolution = &self.resolutions[index];
+        let scale = resolution.scale();
+
+        // Convert the view port to the encoded space coordinates
+        let view_port = parameters.view_port(space);
+
+        // Compute the smallest length of a cell along any of the axes, as
+        // well as the largest number of cells along any axis.
+        let mut cell = f64::MAX;
+        let mut cells = 0;
+        for (k, (axis, shift)) in space.axes().iter().zip(scale.iter()).enumerate() {
+            let length = match through {
+                None => {
+                    let zero = axis.project_out(&Coordinate::from(0u64))?;
+                    let one = axis.project_out(&Coordinate::from(1u64))?;
+
+                    (one - zero).norm() * (1u64 << shift) as f64
+                }
+                Some(conversion) => {
+                    let vector = (0..space.dimensions())
+                        .map(|j| Coordinate::from(if j == k { 1u64 << shift } else { 0 }))
+                        .collect::<Position>();
+                    let vector = conversion.vector(&vector)?;
+
+                    Space::change_base_vector(&vector, conversion.to(), Space::universe())?.norm()
+                }
+            };
+
+            cell = cell.min(length);
+            cells = cells.max(axis.graduation().steps >> shift);
+        }
+
+        // Position of the center, within the index.
+        let center = (0..center.dimensions())
+            .map(|k| center[k].u64() >> scale[k])
+            .collect::<Vec<_>>();
+
+        // Position in the Universe of a position of the index.
+        let absolute = |position: &Position| match through {
+            None => Space::change_base(position, space, Space::universe()),
+            Some(conversion) => Space::change_base(
+                &conversion.position(position)?,
+                conversion.to(),
+                Space::universe(),
+            ),
+        };
+
+        let mut radius = 1u64;
+        loop {
+            let lower = center
+                .iter()
+                .map(|c| c.saturating_sub(radius))
+                .collect::<Vec<_>>();
+            let higher = center
+                .iter()
+                .map(|c| c.saturating_add(radius))
+                .collect::<Vec<_>>();
+
+            let candidates = resolution
+                .find_range(&lower.into(), &higher.into())?
+                .map(|(position, value)| {
+                    // Bring back the position to the full resolution.
+                    let position = (0..position.dimensions())
+                        .map(|k| Coordinate::from(position[k].u64() << scale[k]))
+                        .collect::<Position>();
+
+                    (position, value)
+                })
+                .filter(|(position, _)| match &view_port {
+                    None => true,
+                    Some(view_port) => view_port.contains(position),
+                })
+                .filter_map(|(position, value)| match absolute(&position) {
+                    Err(_) => None,
+                    Ok(absolute) => Some(((&absolute - origin).norm(), value)),
+                });
+            let mut nearest = nearest(candidates);
+
+            // Only the objects within this distance are sure to be the
+            // nearest ones, as the box might not contain objects closer
+            // than the others found, but outside of it.
+            let reach = radius as f64 * cell;
+            let found = nearest.iter().take_while(|(d, _)| *d <= reach).count();
+
+            if found >= k || radius > cells {
+                nearest.truncate(k);
+
+                return Ok(nearest);
+            }
+
+            radius *= 2;
+        }
+    }
+
     // Search by Shape defining a volume:
     // * Hyperrectangle (MBB),
     // * HyperSphere (radius around a point),
@@ -345,7 +637,7 @@ impl SpaceDB {
         &'s self,
         shape: Shape,
         parameters: &CoreQueryParameters,
-    ) -> Result<Box<dyn Iterator<Item = (Position, &SpaceFields)> + 's>, String> {
+    ) -> Result<Box<dyn Iterator<Item = (Position, usize)> + 's>, Error> {
         let index = self.resolution(parameters);
 
         // Convert the view port to the encoded space coordinates
@@ -358,3 +650,87 @@ impl SpaceDB {
         Ok(results)
     }
 }
+
+// Bit shifts to apply to build a scale, see `scale_powers`.
+type ScalePower = (Vec<u32>, Vec<u32>, bool);
+
+// Compute the list of bit shifts to apply to build each of `scales`.
+//
+// We optimize scaling, by iteratively building coarser and coarser
+// indexes. Powers holds a list of bit shift to apply per axis based
+// on the previous value, or based on the full resolution when the
+// scale is not coarser than the previous one on every axis.
+//
+// Returns a list of `(scale, power, restart)`, where `restart` is true
+// when `power` applies to the full resolution.
+fn scale_powers(mut scales: Vec<Vec<u32>>, dimensions: usize) -> Result<Vec<ScalePower>, Error> {
+    let mut powers = Vec::with_capacity(scales.len());
+
+    if let Some(scale) = scales.iter().find(|scale| scale.len() != dimensions) {
+        return Err(Error::DimensionMismatch {
+            expected: dimensions,
+            found: scale.len(),
+        });
+    }
+
+    scales.sort_unstable_by_key(|v| (v.iter().sum::<u32>(), v.clone()));
+
+    let mut previous = vec![0u32; dimensions];
+    for scale in scales {
+        let coarser = scale.iter().zip(&previous).all(|(s, p)| s >= p);
+        let power = if coarser {
+            scale.iter().zip(&previous).map(|(s, p)| s - p).collect()
+        } else {
+            scale.clone()
+        };
+
+        powers.push((scale.clone(), power, !coarser));
+        previous = scale;
+    }
+
+    Ok(powers)
+}
+
+// Keep the smallest distance found for each value, ordered by increasing
+// distance.
+pub fn nearest<I>(candidates: I) -> Vec<(f64, usize)>
+where
+    I: IntoIterator<Item = (f64, usize)>,
+{
+    let mut distances = HashMap::new();
+    for (distance, value) in candidates {
+        let d = distances.entry(value).or_insert(distance);
+        if distance < *d {
+            *d = distance;
+        }
+    }
+
+    let mut nearest = distances
+        .into_iter()
+        .map(|(value, distance)| (distance, value))
+        .collect::<Vec<_>>();
+
+    // Unknown distances come last.
+    nearest.sort_unstable_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+
+    nearest
+}
+
+// Build the SFC index of the positions stored in `run`.
+fn index_run(run: &Run, space_id: &str, dimensions: usize) -> Result<SpaceSetIndex, Error> {
+    let mut error = None;
+
+    let objects = run.iter()?.map_while(|record| match record {
+        Ok((key, value)) => Some(SpaceSetObject::new(space_id, key.into(), value)),
+        Err(e) => {
+            error = Some(e);
+            None
+        }
+    });
+    let index = SpaceSetIndex::new(objects, dimensions, CELL_BITS);
+
+    match error {
+        Some(e) => Err(e),
+        None => Ok(index),
+    }
+}
@@ -1,15 +1,21 @@
 use std::cmp::Ord;
+use std::collections::HashSet;
 
 use ironsea_index::IndexedDestructured;
+use serde::ser::SerializeStruct;
 use serde::Deserialize;
 use serde::Serialize;
+use serde::Serializer;
 
+use super::mapped::Mapped;
 use super::space::Coordinate;
 use super::space::Position;
 use super::space::Shape;
+use super::space::Space;
 use super::IterPositions;
+use crate::Error;
 
-#[derive(Clone, Debug, Hash)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct SpaceSetObject {
     space_id: String,
     position: Position,
@@ -98,22 +104,102 @@ impl ironsea_index::RecordFields<SpaceFields> for &SpaceSetObject {
     }
 }
 
+// Owned records allow building an index from a stream of objects, without
+// keeping them around.
+impl ironsea_index::Record<Position> for SpaceSetObject {
+    fn key(&self) -> Position {
+        self.position.clone()
+    }
+}
+
+impl ironsea_index::RecordFields<SpaceFields> for SpaceSetObject {
+    fn fields(&self) -> SpaceFields {
+        SpaceFields {
+            space_id: self.space_id().clone(),
+            value: self.value,
+        }
+    }
+}
+
 pub type SpaceSetIndex = ironsea_index_sfc_dbc::IndexOwned<SpaceFields, Position, Coordinate>;
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+//FIXME: Remove hard-coded constant for the bit length of morton codes.
+pub const CELL_BITS: usize = 10;
+
+// Minimum number of positions kept aside before they are merged into the
+// index, see `SpaceIndex::update`.
+const DELTA_SIZE: usize = 4096;
+
+#[derive(Clone, Debug, Deserialize)]
 pub struct SpaceIndex {
     threshold_volume: f64,
     // lookup_ rounds up, so reverse sort of the list on thresholds and check for last index.
     scale: Vec<u32>,
+    // Decoded on first use, when loaded from an index file.
+    index: Mapped<SpaceSetIndex>,
+    // Changes not yet merged into `index`.
+    #[serde(skip)]
+    delta: Option<Delta>,
+}
+
+// Changes applied to an index since it was built.
+#[derive(Clone, Debug)]
+struct Delta {
+    // Current value of each value stored in the index, or `None` when the
+    // positions linked to it have been removed.
+    values: Vec<Option<usize>>,
+    // Highest encoded position of the space, at the scale of the index.
+    higher: Position,
+    // Positions added since, with their current value.
+    objects: Vec<SpaceSetObject>,
+    index: SpaceSetIndex,
+}
+
+// The pending changes are merged before the index is written, so that
+// they do not change the layout of index files.
+impl Serialize for SpaceIndex {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let merged;
+        let index = match &self.delta {
+            None => &self.index,
+            Some(delta) => {
+                merged = Mapped::new(self.merge(delta).map_err(serde::ser::Error::custom)?);
+                &merged
+            }
+        };
+
+        let mut state = serializer.serialize_struct("SpaceIndex", 3)?;
+        state.serialize_field("threshold_volume", &self.threshold_volume)?;
+        state.serialize_field("scale", &self.scale)?;
+        state.serialize_field("index", index)?;
+        state.end()
+    }
+}
+
+// Layout of `SpaceIndex` in index files of format 1 and older.
+#[derive(Deserialize)]
+pub struct SpaceIndexV1 {
+    threshold_volume: f64,
+    scale: Vec<u32>,
     index: SpaceSetIndex,
 }
 
+impl From<SpaceIndexV1> for SpaceIndex {
+    fn from(v1: SpaceIndexV1) -> Self {
+        SpaceIndex::new(v1.threshold_volume, v1.scale, v1.index)
+    }
+}
+
 impl SpaceIndex {
     pub fn new(threshold_volume: f64, scale: Vec<u32>, index: SpaceSetIndex) -> Self {
         SpaceIndex {
             threshold_volume,
             scale,
-            index,
+            index: Mapped::new(index),
+            delta: None,
         }
     }
 
@@ -125,23 +211,242 @@ impl SpaceIndex {
         &self.scale
     }
 
-    // Inputs and Results are expressed in encoded space coordinates.
-    pub fn find<'s>(&'s self, key: &Position) -> Box<dyn Iterator<Item = &SpaceFields> + 's> {
-        self.index.find(key)
+    // Decode the index, if this was not yet done.
+    pub fn load(&self) -> Result<(), Error> {
+        self.index.get().map(|_| ())
+    }
+
+    // Current value of positions stored in the index with `fields`, if
+    // they have not been removed since.
+    fn value(&self, fields: &SpaceFields) -> Option<usize> {
+        match &self.delta {
+            None => Some(fields.value),
+            Some(delta) => delta.values.get(fields.value).cloned().flatten(),
+        }
+    }
+
+    // Inputs are expressed in encoded space coordinates.
+    //
+    // Results are the values linked to `key`.
+    pub fn find<'s>(
+        &'s self,
+        key: &Position,
+    ) -> Result<Box<dyn Iterator<Item = usize> + 's>, Error> {
+        let results = self
+            .index
+            .get()?
+            .find(key)
+            .filter_map(move |fields| self.value(fields));
+
+        match &self.delta {
+            None => Ok(Box::new(results)),
+            Some(delta) => Ok(Box::new(
+                results.chain(delta.index.find(key).map(|fields| fields.value)),
+            )),
+        }
     }
 
     // Inputs and Results are expressed in encoded space coordinates.
-    fn find_range<'s>(
+    pub fn find_range<'s>(
         &'s self,
         start: &Position,
         end: &Position,
-    ) -> Box<dyn Iterator<Item = (Position, &SpaceFields)> + 's> {
-        self.index.find_range(start, end)
+    ) -> Result<Box<dyn Iterator<Item = (Position, usize)> + 's>, Error> {
+        let results = self
+            .index
+            .get()?
+            .find_range(start, end)
+            .filter_map(move |(position, fields)| Some((position, self.value(fields)?)));
+
+        match &self.delta {
+            None => Ok(Box::new(results)),
+            Some(delta) => Ok(Box::new(
+                results.chain(
+                    delta
+                        .index
+                        .find_range(start, end)
+                        .map(|(position, fields)| (position, fields.value)),
+                ),
+            )),
+        }
+    }
+
+    // Highest encoded position of `space`, at the scale of this index.
+    fn higher(&self, space: &Space) -> Position {
+        space
+            .axes()
+            .iter()
+            .zip(self.scale.iter())
+            .map(|(axis, scale)| axis.graduation().steps >> scale)
+            .collect::<Vec<_>>()
+            .into()
     }
 
     // Inputs and Results are expressed in encoded space coordinates.
-    pub fn find_by_value<'s>(&'s self, id: &'s SpaceFields) -> IterPositions<'s> {
-        self.index.find_by_value(id)
+    //
+    // Retrieve the positions linked to the value `id` in the space
+    // `space_id`.
+    pub fn find_by_value<'s>(
+        &'s self,
+        space_id: &str,
+        id: usize,
+    ) -> Result<IterPositions<'s>, Error> {
+        let index = self.index.get()?;
+
+        // Value under which the positions are stored in the index.
+        let stored = match &self.delta {
+            None => Some(id),
+            Some(delta) => delta.values.iter().position(|value| *value == Some(id)),
+        };
+
+        let mut positions = vec![];
+        if let Some(stored) = stored {
+            positions.extend(index.find_by_value(&SpaceFields::new(space_id, stored)));
+        }
+        if let Some(delta) = &self.delta {
+            positions.extend(delta.index.find_by_value(&SpaceFields::new(space_id, id)));
+        }
+
+        Ok(Box::new(positions.into_iter()))
+    }
+
+    // Add `space_objects`, at the scale of this index, and change the
+    // values already stored following `remap`, see `SpaceDB::update`.
+    //
+    // The SFC index is immutable, so changes are kept aside in a smaller
+    // index, queried along with it. They are merged into the index once
+    // they grow beyond a fraction of its size, or when it is stored.
+    pub fn update(
+        &mut self,
+        space: &Space,
+        space_objects: Vec<SpaceSetObject>,
+        remap: &[Option<usize>],
+    ) -> Result<(), Error> {
+        let mut delta = match self.delta.take() {
+            Some(delta) => delta,
+            None => Delta {
+                values: (0..remap.len()).map(Some).collect(),
+                higher: self.higher(space),
+                objects: vec![],
+                index: SpaceSetIndex::new(
+                    std::iter::empty::<SpaceSetObject>(),
+                    space.dimensions(),
+                    CELL_BITS,
+                ),
+            },
+        };
+
+        delta.values = delta
+            .values
+            .iter()
+            .map(|value| value.and_then(|value| remap.get(value).cloned().flatten()))
+            .collect();
+
+        let index = self.index.get()?;
+        let mut objects = delta
+            .objects
+            .drain(..)
+            .filter_map(|mut object| {
+                let value = remap.get(object.value).cloned().flatten()?;
+                object.set_value(value);
+                Some(object)
+            })
+            .collect::<HashSet<_>>();
+
+        // Skip the positions already stored in the index.
+        for object in space_objects {
+            let stored = index.find(object.position()).any(|fields| {
+                delta.values.get(fields.value).cloned().flatten() == Some(object.value)
+            });
+
+            if !stored {
+                objects.insert(object);
+            }
+        }
+        delta.objects = objects.into_iter().collect();
+
+        if delta.objects.len() > DELTA_SIZE && delta.objects.len() > self.indexed(&delta)? / 4 {
+            self.index = Mapped::new(self.merge(&delta)?);
+        } else {
+            delta.index = SpaceSetIndex::new(delta.objects.iter(), space.dimensions(), CELL_BITS);
+            self.delta = Some(delta);
+        }
+
+        Ok(())
+    }
+
+    // Number of positions of `index` still linked to a value, after the
+    // changes of `delta`.
+    fn indexed(&self, delta: &Delta) -> Result<usize, Error> {
+        let lower = vec![0; self.scale.len()].into();
+
+        Ok(self
+            .index
+            .get()?
+            .find_range(&lower, &delta.higher)
+            .filter(|(_, fields)| delta.values.get(fields.value).cloned().flatten().is_some())
+            .count())
+    }
+
+    // Build the index holding both the content of `index`, and the
+    // changes of `delta`.
+    fn merge(&self, delta: &Delta) -> Result<SpaceSetIndex, Error> {
+        let lower = vec![0; self.scale.len()].into();
+
+        let objects = self
+            .index
+            .get()?
+            .find_range(&lower, &delta.higher)
+            .filter_map(|(position, fields)| {
+                let value = delta.values.get(fields.value).cloned().flatten()?;
+                Some(SpaceSetObject::new(&fields.space_id, position, value))
+            })
+            .chain(delta.objects.iter().cloned());
+
+        Ok(SpaceSetIndex::new(objects, self.scale.len(), CELL_BITS))
+    }
+
+    // Inputs and Results are expressed in encoded space coordinates.
+    //
+    // Range query over the minimum bounding box of `shape`, restricted to
+    // the view port, if any. The results are not filtered by the shape.
+    fn find_mbb<'s>(
+        &'s self,
+        shape: &Shape,
+        view_port: &Option<Shape>,
+    ) -> Result<Box<dyn Iterator<Item = (Position, usize)> + 's>, Error> {
+        let (mut lower, mut higher) = shape.get_mbb();
+
+        match view_port {
+            None => self.find_range(&lower, &higher),
+            Some(view_port) => {
+                // Compute the intersection of the two boxes, one axis at a
+                // time, as positions are only partially ordered.
+                let (vl, vh) = view_port.get_mbb();
+                for k in 0..lower.dimensions() {
+                    if vl[k] > lower[k] {
+                        lower[k] = vl[k];
+                    }
+                    if vh[k] < higher[k] {
+                        higher[k] = vh[k];
+                    }
+                }
+
+                if (0..lower.dimensions()).any(|k| lower[k] > higher[k]) {
+                    return Ok(Box::new(std::iter::empty()));
+                }
+
+                let results = self.find_range(&lower, &higher)?;
+                if let Shape::BoundingBox(_, _) = view_port {
+                    Ok(results)
+                } else {
+                    let view_port = view_port.clone();
+                    Ok(Box::new(
+                        results.filter(move |(position, _)| view_port.contains(position)),
+                    ))
+                }
+            }
+        }
     }
 
     // Inputs and Results are also in encoded space coordinates.
@@ -149,20 +454,20 @@ impl SpaceIndex {
         &'s self,
         shape: Shape,
         view_port: &Option<Shape>,
-    ) -> Result<Box<dyn Iterator<Item = (Position, &SpaceFields)> + 's>, String> {
+    ) -> Result<Box<dyn Iterator<Item = (Position, usize)> + 's>, Error> {
         match shape {
             Shape::Point(position) => {
                 if let Some(mbb) = view_port {
                     if !mbb.contains(&position) {
-                        return Err(format!(
+                        return Err(Error::InvalidShape(format!(
                             "View port '{:?}' does not contain '{:?}'",
                             mbb, position
-                        ));
+                        )));
                     }
                 }
                 Ok(Box::new(
-                    self.find(&position)
-                        .map(move |fields| (position.clone(), fields)),
+                    self.find(&position)?
+                        .map(move |value| (position.clone(), value)),
                 ))
             }
             Shape::BoundingBox(bl, bh) => {
@@ -173,11 +478,11 @@ impl SpaceIndex {
                             let lower = (&bl).max(vl);
                             let higher = (&bh).min(vh);
                             if higher < lower {
-                                Err(format!(
+                                Err(Error::InvalidShape(format!(
                                     "View port '{:?}' does not intersect '{:?}'",
                                     mbb,
                                     Shape::BoundingBox(bl.clone(), bh.clone())
-                                ))
+                                )))
                             } else {
                                 trace!(
                                     "mbb {:?} shape {:?} lower {:?} higher {:?}",
@@ -186,43 +491,52 @@ impl SpaceIndex {
                                     lower,
                                     higher
                                 );
-                                Ok(self.find_range(lower, higher))
+                                self.find_range(lower, higher)
                             }
                         }
-                        _ => Err(format!("Invalid view port shape '{:?}'", mbb)),
+                        _ => self.find_mbb(&Shape::BoundingBox(bl, bh), view_port),
                     }
                 } else {
-                    Ok(self.find_range(&bl, &bh))
+                    self.find_range(&bl, &bh)
                 }
             }
-            Shape::HyperSphere(center, radius) => {
-                let (bl, bh) = Shape::HyperSphere(center.clone(), radius).get_mbb();
-                let lower;
-                let higher;
-
-                if let Some(mbb) = view_port {
-                    match mbb {
-                        Shape::BoundingBox(vl, vh) => {
-                            // Compute the intersection of the two boxes.
-                            lower = (&bl).max(vl);
-                            higher = (&bh).min(vh);
-                        }
-                        _ => return Err(format!("Invalid view port shape '{:?}'", mbb)),
-                    }
-                } else {
-                    lower = &bl;
-                    higher = &bh;
-                }
-
-                // Filter out results using using a range query over the MBB,
-                // then add the condition of the radius as we are working within
-                // a sphere.
+            Shape::HyperSphere(_, _)
+            | Shape::Mesh(_, _)
+            | Shape::OrientedBox(_, _)
+            | Shape::Ellipsoid(_, _)
+            | Shape::Intersection(_)
+            | Shape::Difference(_, _)
+            | Shape::Mask(_, _, _) => {
+                // Filter out results using a range query over the MBB, then
+                // keep only the positions within the shape.
                 let results = self
-                    .find_range(lower, higher)
-                    .filter(move |(position, _)| (position - &center).norm() <= radius.f64());
+                    .find_mbb(&shape, view_port)?
+                    .filter(move |(position, _)| shape.contains(position));
 
                 Ok(Box::new(results))
             }
+            Shape::Union(shapes) => {
+                // Scan each shape on its own, as the members of the union
+                // might be far apart, and skip positions already returned
+                // for one of the previous shapes.
+                let mut results: Box<dyn Iterator<Item = (Position, usize)> + 's> =
+                    Box::new(std::iter::empty());
+
+                for (i, shape) in shapes.iter().enumerate() {
+                    let previous = shapes[..i].to_vec();
+                    let shape = shape.clone();
+                    let r = self
+                        .find_mbb(&shape, view_port)?
+                        .filter(move |(position, _)| {
+                            shape.contains(position)
+                                && !previous.iter().any(|s| s.contains(position))
+                        });
+
+                    results = Box::new(results.chain(r));
+                }
+
+                Ok(results)
+            }
         }
     }
 }
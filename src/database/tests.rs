@@ -0,0 +1,696 @@
+use super::space::*;
+use super::space_index::SpaceSetObject;
+use super::*;
+use crate::storage::model;
+
+fn get_space(name: &str) -> Space {
+    get_space_at(name, [0.0, 0.0])
+}
+
+fn get_space_at(name: &str, origin: [f64; 2]) -> Space {
+    Space::new(
+        name,
+        CoordinateSystem::new(
+            origin.to_vec(),
+            vec![
+                Axis::new("m", vec![1f64, 0f64], NumberSet::R, 0f64, 100f64, 100).unwrap(),
+                Axis::new("m", vec![0f64, 1f64], NumberSet::R, 0f64, 100f64, 100).unwrap(),
+            ],
+        ),
+    )
+}
+
+fn get_object(id: &str, space: &str, points: &[[f64; 2]]) -> model::SpatialObject {
+    model::SpatialObject {
+        properties: model::Properties {
+            type_name: "Feature".to_string(),
+            id: id.to_string(),
+            attributes: Attributes::new(),
+        },
+        shapes: points
+            .iter()
+            .map(|point| model::v1::Shape {
+                type_name: "Point".to_string(),
+                reference_space: space.to_string(),
+                vertices: vec![point.to_vec()],
+            })
+            .collect(),
+    }
+}
+
+fn get_db(spaces: Vec<Space>, objects: &[model::SpatialObject]) -> DataBase {
+    let core = model::build_index("core", "1", &spaces, objects, None, None).unwrap();
+
+    DataBase::new(spaces, vec![core])
+}
+
+fn get_parameters(db: &DataBase) -> CoreQueryParameters<'_> {
+    CoreQueryParameters {
+        db,
+        output_space: None,
+        threshold_volume: None,
+        view_port: &None,
+        resolution: &None,
+        out_of_bounds: OutOfBounds::Clip,
+        dropped: None,
+    }
+}
+
+// Sorted list of the decoded positions linked to `id`, in any space.
+fn get_positions(db: &DataBase, id: &str) -> Vec<Vec<f64>> {
+    let parameters = get_parameters(db);
+    let mut positions = db
+        .core("core")
+        .unwrap()
+        .get_by_id(&parameters, id)
+        .unwrap()
+        .into_iter()
+        .flat_map(|(_, positions)| positions)
+        .map(|position| position.into())
+        .collect::<Vec<Vec<f64>>>();
+
+    positions.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    positions
+}
+
+fn get_keys(db: &DataBase) -> Vec<String> {
+    db.core("core")
+        .unwrap()
+        .keys()
+        .unwrap()
+        .iter()
+        .map(|properties| properties.id().to_string())
+        .collect()
+}
+
+#[test]
+pub fn check_insert() {
+    let mut db = get_db(
+        vec![get_space("s")],
+        &[
+            get_object("a", "s", &[[1.0, 1.0]]),
+            get_object("c", "s", &[[3.0, 3.0], [4.0, 4.0]]),
+        ],
+    );
+
+    // "b" is inserted between the known identifiers, so the values
+    // stored for "c" are moved.
+    db.insert(
+        "core",
+        &[
+            get_object("b", "s", &[[2.0, 2.0]]),
+            get_object("a", "s", &[[5.0, 5.0], [1.0, 1.0]]),
+        ],
+    )
+    .unwrap();
+
+    assert_eq!(get_keys(&db), vec!["a", "b", "c"]);
+    assert_eq!(
+        get_positions(&db, "a"),
+        vec![vec![1.0, 1.0], vec![5.0, 5.0]]
+    );
+    assert_eq!(get_positions(&db, "b"), vec![vec![2.0, 2.0]]);
+    assert_eq!(
+        get_positions(&db, "c"),
+        vec![vec![3.0, 3.0], vec![4.0, 4.0]]
+    );
+
+    // Values are moved again, along with the positions not yet merged.
+    db.insert("core", &[get_object("0", "s", &[[6.0, 6.0]])])
+        .unwrap();
+
+    assert_eq!(get_keys(&db), vec!["0", "a", "b", "c"]);
+    assert_eq!(get_positions(&db, "0"), vec![vec![6.0, 6.0]]);
+    assert_eq!(get_positions(&db, "b"), vec![vec![2.0, 2.0]]);
+
+    // Positions are found by location as well.
+    let parameters = get_parameters(&db);
+    let found = db
+        .core("core")
+        .unwrap()
+        .get_by_positions(&parameters, vec![vec![2.0, 2.0].into()], "s")
+        .unwrap()
+        .into_iter()
+        .flat_map(|(_, objects)| objects)
+        .map(|(_, properties)| properties.id().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(found, vec!["b"]);
+}
+
+#[test]
+pub fn check_insert_merge() {
+    let mut db = get_db(vec![get_space("s")], &[get_object("a", "s", &[[0.0, 0.0]])]);
+
+    // Enough positions to be merged into the index.
+    let points = (1..5000)
+        .map(|i| [(i % 100) as f64, (i / 100) as f64])
+        .collect::<Vec<_>>();
+    db.insert("core", &[get_object("b", "s", &points)]).unwrap();
+    db.insert("core", &[get_object("0", "s", &[[99.0, 99.0]])])
+        .unwrap();
+
+    assert_eq!(get_positions(&db, "0"), vec![vec![99.0, 99.0]]);
+    assert_eq!(get_positions(&db, "a"), vec![vec![0.0, 0.0]]);
+    assert_eq!(get_positions(&db, "b").len(), points.len());
+
+    // Changes not yet merged are kept when storing the index.
+    let file = std::env::temp_dir().join("mercator_db_check_insert_merge.index");
+    let file = file.to_str().unwrap();
+    let spaces = vec![get_space("s")];
+    storage::bincode::store_index(
+        storage::bincode::Header::new(None, None),
+        &spaces,
+        db.core("core").unwrap(),
+        file,
+        storage::bincode::Compression::None,
+    )
+    .unwrap();
+
+    let db = DataBase::load(&[file]).unwrap();
+    std::fs::remove_file(file).unwrap();
+
+    assert_eq!(get_keys(&db), vec!["0", "a", "b"]);
+    assert_eq!(get_positions(&db, "0"), vec![vec![99.0, 99.0]]);
+    assert_eq!(get_positions(&db, "b").len(), points.len());
+}
+
+#[test]
+pub fn check_insert_unknown_value() {
+    let spaces = vec![get_space("s")];
+    let mut db = get_db(spaces.clone(), &[get_object("a", "s", &[[1.0, 1.0]])]);
+
+    let core = db.core_mut("core").unwrap();
+    let objects = vec![SpaceSetObject::new("s", vec![2.0, 2.0].into(), 1)];
+
+    match core.insert(&spaces, vec![Properties::feature("b")], objects) {
+        Err(Error::InvalidData(_)) => (),
+        r => panic!("Unexpected result: {:?}", r),
+    }
+    assert_eq!(get_keys(&db), vec!["a"]);
+}
+
+#[test]
+pub fn check_remove() {
+    let mut db = get_db(
+        vec![get_space("s")],
+        &[
+            get_object("a", "s", &[[1.0, 1.0]]),
+            get_object("b", "s", &[[2.0, 2.0], [1.0, 1.0]]),
+            get_object("c", "s", &[[3.0, 3.0]]),
+        ],
+    );
+
+    db.remove("core", "b").unwrap();
+
+    // The values stored for "c" are moved down.
+    assert_eq!(get_keys(&db), vec!["a", "c"]);
+    assert_eq!(get_positions(&db, "a"), vec![vec![1.0, 1.0]]);
+    assert_eq!(get_positions(&db, "b"), Vec::<Vec<f64>>::new());
+    assert_eq!(get_positions(&db, "c"), vec![vec![3.0, 3.0]]);
+
+    match db.remove("core", "b") {
+        Err(Error::UnknownId { .. }) => (),
+        r => panic!("Unexpected result: {:?}", r),
+    }
+
+    // Positions inserted afterwards are moved along.
+    db.insert("core", &[get_object("b", "s", &[[4.0, 4.0]])])
+        .unwrap();
+    db.remove("core", "a").unwrap();
+
+    assert_eq!(get_keys(&db), vec!["b", "c"]);
+    assert_eq!(get_positions(&db, "b"), vec![vec![4.0, 4.0]]);
+    assert_eq!(get_positions(&db, "c"), vec![vec![3.0, 3.0]]);
+}
+
+#[test]
+pub fn check_replace() {
+    let mut db = get_db(
+        vec![get_space("s")],
+        &[
+            get_object("a", "s", &[[1.0, 1.0]]),
+            get_object("b", "s", &[[2.0, 2.0], [3.0, 3.0]]),
+            get_object("c", "s", &[[3.0, 3.0]]),
+        ],
+    );
+
+    let mut object = get_object("b", "s", &[[5.0, 5.0]]);
+    object
+        .properties
+        .attributes
+        .insert("colour".to_string(), "red".to_string());
+    db.replace("core", &object).unwrap();
+
+    assert_eq!(get_keys(&db), vec!["a", "b", "c"]);
+    assert_eq!(get_positions(&db, "a"), vec![vec![1.0, 1.0]]);
+    assert_eq!(get_positions(&db, "b"), vec![vec![5.0, 5.0]]);
+    assert_eq!(get_positions(&db, "c"), vec![vec![3.0, 3.0]]);
+
+    let properties = &db.core("core").unwrap().keys().unwrap()[1];
+    assert_eq!(properties.type_name(), "Feature");
+    assert_eq!(
+        properties.attributes().and_then(|a| a.get("colour")),
+        Some(&"red".to_string())
+    );
+
+    match db.replace("core", &get_object("d", "s", &[[1.0, 1.0]])) {
+        Err(Error::UnknownId { .. }) => (),
+        r => panic!("Unexpected result: {:?}", r),
+    }
+
+    // The kind of the identifier can be changed as well.
+    let spaces = vec![get_space("s")];
+    let objects = vec![SpaceSetObject::new("s", vec![6.0, 6.0].into(), 0)];
+    db.core_mut("core")
+        .unwrap()
+        .replace(&spaces, Properties::unknown("c", "Cell"), objects)
+        .unwrap();
+
+    let properties = &db.core("core").unwrap().keys().unwrap()[2];
+    assert_eq!(properties.type_name(), "Cell");
+    assert_eq!(get_positions(&db, "c"), vec![vec![6.0, 6.0]]);
+}
+
+#[test]
+pub fn check_space_conflict() {
+    let dir = std::env::temp_dir();
+    let mut files = vec![];
+
+    // The same name for two different definitions.
+    let shifted = Space::new(
+        "s",
+        CoordinateSystem::new(vec![1f64, 0f64], get_space("s").axes().clone()),
+    );
+
+    for (name, space) in &[("a", get_space("s")), ("b", shifted)] {
+        let spaces = vec![space.clone()];
+        let objects = [get_object(name, "s", &[[1.0, 1.0]])];
+        let core = model::build_index(name, "1", &spaces, &objects, None, None).unwrap();
+
+        let file = dir.join(format!("mercator_db_check_space_conflict_{}.index", name));
+        let file = file.to_str().unwrap().to_string();
+        storage::bincode::store_index(
+            storage::bincode::Header::new(None, None),
+            &spaces,
+            &core,
+            &file,
+            storage::bincode::Compression::None,
+        )
+        .unwrap();
+        files.push(file);
+    }
+
+    let result = DataBase::load(&files.iter().map(|f| f.as_str()).collect::<Vec<_>>());
+    for file in &files {
+        std::fs::remove_file(file).unwrap();
+    }
+
+    match result {
+        Err(Error::SpaceConflict {
+            name,
+            existing,
+            conflicting,
+        }) => {
+            assert_eq!(name, "s");
+            assert_eq!(*existing, get_space("s"));
+            assert_eq!(conflicting.origin(), &vec![1f64, 0f64].into());
+        }
+        Err(e) => panic!("Unexpected error: {:?}", e),
+        Ok(_) => panic!("Conflict not detected"),
+    }
+}
+
+// Ids and distances of the `k` nearest objects to the origin of the
+// Universe.
+fn get_nearest(db: &DataBase, parameters: &CoreQueryParameters, k: usize) -> Vec<(String, f64)> {
+    let origin = db.space("s").unwrap().encode(&[0.0, 0.0]).unwrap();
+
+    db.core("core")
+        .unwrap()
+        .get_nearest(parameters, &origin, k, "s")
+        .unwrap()
+        .into_iter()
+        .map(|(distance, properties)| (properties.id().to_string(), distance))
+        .collect()
+}
+
+#[test]
+pub fn check_nearest() {
+    let objects = [
+        get_object("a", "s", &[[3.0, 4.0]]),
+        get_object("b", "s", &[[50.0, 50.0], [1.0, 0.0], [0.0, 1.0]]),
+        get_object("c", "s", &[[10.0, 0.0]]),
+    ];
+    let db = get_db(vec![get_space("s")], &objects);
+    let parameters = get_parameters(&db);
+
+    // Objects are listed once, even with several positions nearby.
+    assert_eq!(
+        get_nearest(&db, &parameters, 2),
+        vec![("b".to_string(), 1.0), ("a".to_string(), 5.0)]
+    );
+
+    // Asking for more objects than stored returns all of them.
+    assert_eq!(
+        get_nearest(&db, &parameters, 10),
+        vec![
+            ("b".to_string(), 1.0),
+            ("a".to_string(), 5.0),
+            ("c".to_string(), 10.0)
+        ]
+    );
+
+    assert_eq!(get_nearest(&db, &parameters, 0), vec![]);
+}
+
+#[test]
+pub fn check_nearest_spaces() {
+    let objects = [
+        get_object("a", "s", &[[3.0, 4.0]]),
+        get_object("b", "s", &[[50.0, 50.0], [1.0, 0.0]]),
+        get_object("c", "s", &[[10.0, 0.0]]),
+        // At [2, 0] and [6, 0] in the Universe.
+        get_object("d", "t", &[[22.0, 0.0]]),
+        get_object("c", "t", &[[26.0, 0.0]]),
+    ];
+    let db = get_db(
+        vec![get_space("s"), get_space_at("t", [-20.0, 0.0])],
+        &objects,
+    );
+    let parameters = get_parameters(&db);
+
+    // Objects are merged across spaces, using their nearest position.
+    assert_eq!(
+        get_nearest(&db, &parameters, 3),
+        vec![
+            ("b".to_string(), 1.0),
+            ("d".to_string(), 2.0),
+            ("a".to_string(), 5.0)
+        ]
+    );
+    assert_eq!(
+        get_nearest(&db, &parameters, 10),
+        vec![
+            ("b".to_string(), 1.0),
+            ("d".to_string(), 2.0),
+            ("a".to_string(), 5.0),
+            ("c".to_string(), 6.0)
+        ]
+    );
+
+    // Only the positions within the view port are considered.
+    let view_port = Some((vec![0.0, 0.0], vec![3.0, 3.0]));
+    let parameters = CoreQueryParameters {
+        view_port: &view_port,
+        ..get_parameters(&db)
+    };
+
+    assert_eq!(
+        get_nearest(&db, &parameters, 10),
+        vec![("b".to_string(), 1.0), ("d".to_string(), 2.0)]
+    );
+}
+
+fn get_translation(x: f64, y: f64) -> Transform {
+    Transform::new(vec![vec![1.0, 0.0, x], vec![0.0, 1.0, y]]).unwrap()
+}
+
+#[test]
+pub fn check_declare_transform() {
+    let spaces = ["a", "b", "c", "d"].iter().map(|name| get_space(name));
+    let mut db = get_db(spaces.collect(), &[]);
+    let apply = |db: &DataBase, from: &str, to: &str| {
+        db.transform(from, to)
+            .unwrap()
+            .map(|transform| transform.apply(&[1.0, 1.0]).unwrap())
+    };
+
+    db.declare_transform("a", "b", get_translation(10.0, 0.0))
+        .unwrap();
+    db.declare_transform("b", "c", get_translation(0.0, 10.0))
+        .unwrap();
+
+    // Paths are composed along the declared transformations, and
+    // followed backwards through their inverses.
+    assert_eq!(apply(&db, "a", "c"), Some(vec![11.0, 11.0]));
+    assert_eq!(apply(&db, "c", "a"), Some(vec![-9.0, -9.0]));
+    assert_eq!(apply(&db, "a", "d"), None);
+
+    let (a, c) = (db.space("a").unwrap(), db.space("c").unwrap());
+    assert_eq!(
+        db.conversion(a, c)
+            .unwrap()
+            .position(&a.encode(&[1.0, 1.0]).unwrap())
+            .unwrap(),
+        c.encode(&[11.0, 11.0]).unwrap()
+    );
+
+    // Declaring the opposite direction replaces the transformation, and
+    // the paths already resolved through it.
+    db.declare_transform("c", "b", get_translation(0.0, -20.0))
+        .unwrap();
+    assert_eq!(apply(&db, "a", "c"), Some(vec![11.0, 21.0]));
+    assert_eq!(apply(&db, "c", "a"), Some(vec![-9.0, -19.0]));
+
+    // Transformations which cannot be inverted are only followed
+    // forward.
+    let projection = Transform::new(vec![vec![1.0, 0.0, 0.0], vec![0.0, 0.0, 0.0]]).unwrap();
+    db.declare_transform("d", "a", projection).unwrap();
+    assert_eq!(apply(&db, "d", "c"), Some(vec![11.0, 20.0]));
+    assert_eq!(apply(&db, "c", "d"), None);
+
+    match db.declare_transform("a", "a", get_translation(0.0, 0.0)) {
+        Err(Error::InvalidDefinition(_)) => (),
+        r => panic!("Unexpected result: {:?}", r),
+    }
+    match db.declare_transform("a", "b", Transform::identity(3)) {
+        Err(Error::DimensionMismatch {
+            expected: 2,
+            found: 3,
+        }) => (),
+        r => panic!("Unexpected result: {:?}", r),
+    }
+}
+
+#[test]
+pub fn check_nearest_transform() {
+    let objects = [
+        get_object("a", "s", &[[3.0, 4.0]]),
+        // At [2, 0], [6, 0] and [-10, 0] in "s", through the declared
+        // transformation.
+        get_object("d", "t", &[[22.0, 0.0]]),
+        get_object("c", "t", &[[26.0, 0.0]]),
+        get_object("e", "t", &[[10.0, 0.0]]),
+    ];
+    let mut db = get_db(
+        vec![get_space("s"), get_space_at("t", [500.0, 500.0])],
+        &objects,
+    );
+
+    // Without transformation, "t" is far away in the Universe.
+    assert_eq!(
+        get_nearest(&db, &get_parameters(&db), 1),
+        vec![("a".to_string(), 5.0)]
+    );
+
+    db.declare_transform("t", "s", get_translation(-20.0, 0.0))
+        .unwrap();
+
+    // Positions which fall outside of "s" are ignored.
+    assert_eq!(
+        get_nearest(&db, &get_parameters(&db), 10),
+        vec![
+            ("d".to_string(), 2.0),
+            ("a".to_string(), 5.0),
+            ("c".to_string(), 6.0)
+        ]
+    );
+}
+
+// Decoded positions of `id`, converted into the space "n".
+fn get_converted(
+    db: &DataBase,
+    id: &str,
+    out_of_bounds: OutOfBounds,
+    dropped: Option<&std::sync::atomic::AtomicUsize>,
+) -> Result<Vec<Vec<f64>>, Error> {
+    let parameters = CoreQueryParameters {
+        output_space: Some("n"),
+        out_of_bounds,
+        dropped,
+        ..get_parameters(db)
+    };
+
+    let positions = db
+        .core("core")?
+        .get_by_id(&parameters, id)?
+        .into_iter()
+        .flat_map(|(_, positions)| positions)
+        .map(|position| position.into())
+        .collect();
+
+    Ok(positions)
+}
+
+#[test]
+pub fn check_out_of_bounds() {
+    let axis = |v: Vec<f64>| Axis::new("m", v, NumberSet::N, 0.0, 100.0, 100).unwrap();
+    let n = Space::new(
+        "n",
+        CoordinateSystem::new(
+            vec![0.0, 0.0],
+            vec![axis(vec![1.0, 0.0]), axis(vec![0.0, 1.0])],
+        ),
+    );
+    let objects = [get_object("a", "s", &[[5.0, 5.0], [50.0, 50.0]])];
+    let mut db = get_db(vec![get_space("s"), n], &objects);
+    let dropped = std::sync::atomic::AtomicUsize::new(0);
+
+    // [5, 5] falls outside of "n".
+    db.declare_transform("s", "n", get_translation(-10.0, 0.0))
+        .unwrap();
+    assert_eq!(
+        get_converted(&db, "a", OutOfBounds::Drop, Some(&dropped)).unwrap(),
+        vec![vec![40.0, 50.0]]
+    );
+    assert_eq!(dropped.load(std::sync::atomic::Ordering::Relaxed), 1);
+    match get_converted(&db, "a", OutOfBounds::Reject, None) {
+        Err(Error::OutOfRange { .. }) => (),
+        r => panic!("Unexpected result: {:?}", r),
+    }
+
+    // Positions which are not integers are only valid when clipped, and
+    // are not counted as out of range otherwise.
+    db.declare_transform("s", "n", get_translation(0.0, 0.5))
+        .unwrap();
+    assert_eq!(
+        get_converted(&db, "a", OutOfBounds::Clip, None).unwrap(),
+        vec![vec![5.0, 6.0], vec![50.0, 51.0]]
+    );
+    for out_of_bounds in &[OutOfBounds::Drop, OutOfBounds::Reject] {
+        match get_converted(&db, "a", *out_of_bounds, Some(&dropped)) {
+            Err(Error::NotInSet { .. }) => (),
+            r => panic!("Unexpected result: {:?}", r),
+        }
+    }
+    assert_eq!(dropped.load(std::sync::atomic::Ordering::Relaxed), 1);
+}
+
+#[test]
+pub fn check_invalid_shape() {
+    let db = get_db(vec![get_space("s")], &[get_object("a", "s", &[[1.0, 1.0]])]);
+    let parameters = get_parameters(&db);
+    let core = db.core("core").unwrap();
+
+    let mesh = Shape::Mesh(vec![vec![0.0, 0.0, 0.0].into()], vec![[0, 0, 1]]);
+    let result = core.get_by_shape(&parameters, mesh, "s");
+    match result {
+        Err(Error::InvalidShape(_)) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+        Ok(_) => panic!("Malformed mesh accepted"),
+    }
+}
+
+#[test]
+pub fn check_resolution_volume() {
+    let spaces = vec![get_space("s")];
+    let objects = [get_object("a", "s", &[[5.0, 5.0]])];
+    let scales = Some(vec![vec![0, 0], vec![3, 0], vec![1, 1]]);
+    let core = model::build_index("core", "1", &spaces, &objects, scales, None).unwrap();
+    let db = DataBase::new(spaces, vec![core]);
+
+    // Thresholds follow the volume of the cells of each resolution,
+    // relative to the coarsest one: [0, 0] covers 1/8th of the space,
+    // [1, 1] 1/2 and [3, 0] all of it.
+    let positions = |volume: f64| {
+        let parameters = CoreQueryParameters {
+            threshold_volume: Some(volume),
+            ..get_parameters(&db)
+        };
+        let core = db.core("core").unwrap();
+        let positions = core.get_by_id(&parameters, "a").unwrap();
+
+        positions
+            .into_iter()
+            .flat_map(|(_, positions)| positions)
+            .map(|position| position.into())
+            .collect::<Vec<Vec<f64>>>()
+    };
+
+    assert_eq!(positions(1000.0), vec![vec![5.0, 5.0]]);
+    assert_eq!(positions(2000.0), vec![vec![2.0, 2.0]]);
+    assert_eq!(positions(6000.0), vec![vec![0.0, 5.0]]);
+
+    // Scales must provide a value per axis.
+    let spaces = vec![get_space("s")];
+    let scales = Some(vec![vec![0, 0], vec![1]]);
+    match model::build_index("core", "1", &spaces, &objects, scales, None) {
+        Err(Error::DimensionMismatch {
+            expected: 2,
+            found: 1,
+        }) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+        Ok(_) => panic!("Invalid scale accepted"),
+    }
+}
+
+#[test]
+pub fn check_build_external() {
+    let spaces = vec![get_space("s"), get_space_at("t", [-20.0, 0.0])];
+    let objects = (0..30)
+        .map(|i| {
+            let points = (0..10)
+                .map(|j| [((i * 7 + j * 13) % 100) as f64, ((i * 3 + j) % 100) as f64])
+                .collect::<Vec<_>>();
+            let space = if i % 3 == 0 { "t" } else { "s" };
+
+            get_object(&format!("{:02}", i), space, &points)
+        })
+        .collect::<Vec<_>>();
+
+    let core = model::build_index("core", "1", &spaces, &objects, None, Some(10)).unwrap();
+    let memory = DataBase::new(spaces.clone(), vec![core]);
+
+    // Small chunks produce more runs than merged at once.
+    let core = model::build_index_external(
+        "core",
+        "1",
+        &spaces,
+        objects.iter().cloned().map(Ok),
+        None,
+        Some(10),
+        &std::env::temp_dir(),
+        2,
+    )
+    .unwrap();
+    let external = DataBase::new(spaces, vec![core]);
+
+    assert_eq!(get_keys(&memory), get_keys(&external));
+
+    // Compare every resolution, from the finest to the coarsest.
+    let positions = |db: &DataBase, id: &str, volume: f64| {
+        let parameters = CoreQueryParameters {
+            threshold_volume: Some(volume),
+            ..get_parameters(db)
+        };
+        let core = db.core("core").unwrap();
+        let mut positions = core
+            .get_by_id(&parameters, id)
+            .unwrap()
+            .into_iter()
+            .flat_map(|(space, positions)| positions.map(move |p| (space.clone(), p.into())))
+            .collect::<Vec<(String, Vec<f64>)>>();
+
+        positions.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        positions
+    };
+
+    for id in get_keys(&memory) {
+        for volume in &[0.0, 100.0, 1000.0, 10000.0] {
+            assert_eq!(
+                positions(&memory, &id, *volume),
+                positions(&external, &id, *volume)
+            );
+        }
+    }
+}
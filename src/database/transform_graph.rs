@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::sync::PoisonError;
+use std::sync::RwLock;
+
+use super::space::Transform;
+use crate::Error;
+
+// Composed transformation between two spaces, if they are connected.
+type Path = Option<Arc<Transform>>;
+
+// Transformations declared between reference spaces, by name.
+//
+// The transformation between two spaces is found by composing the
+// declared ones along the shortest path between them, in number of
+// transformations. Declared transformations can be followed backwards
+// when they are invertible.
+#[derive(Debug, Default)]
+pub struct TransformGraph {
+    edges: HashMap<String, Vec<(String, Arc<Transform>)>>,
+    // Paths already resolved, including the ones which do not exist.
+    paths: RwLock<HashMap<(String, String), Path>>,
+}
+
+impl TransformGraph {
+    // Declare the transformation from `from` to `to`, replacing any
+    // previous one between these two spaces, in either direction.
+    pub fn declare(&mut self, from: &str, to: &str, transform: Transform) -> Result<(), Error> {
+        if from == to {
+            return Err(Error::InvalidDefinition(format!(
+                "Transformation from space '{}' to itself",
+                from
+            )));
+        }
+
+        self.remove_edge(from, to);
+        self.remove_edge(to, from);
+
+        if let Ok(inverse) = transform.inverse() {
+            self.add_edge(to, from, inverse);
+        }
+        self.add_edge(from, to, transform);
+
+        // Any resolved path might now be shorter, or go through a
+        // replaced transformation.
+        self.paths
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+
+        Ok(())
+    }
+
+    // Transformation from `from` to `to`, if they are connected.
+    pub fn find(&self, from: &str, to: &str) -> Result<Path, Error> {
+        let key = (from.to_string(), to.to_string());
+
+        if let Some(path) = self
+            .paths
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&key)
+        {
+            return Ok(path.clone());
+        }
+
+        let path = self.resolve(from, to)?;
+
+        self.paths
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(key, path.clone());
+
+        Ok(path)
+    }
+
+    fn add_edge(&mut self, from: &str, to: &str, transform: Transform) {
+        self.edges
+            .entry(from.to_string())
+            .or_default()
+            .push((to.to_string(), Arc::new(transform)));
+    }
+
+    fn remove_edge(&mut self, from: &str, to: &str) {
+        if let Some(edges) = self.edges.get_mut(from) {
+            edges.retain(|(target, _)| target != to);
+        }
+    }
+
+    // Breadth-first search of the shortest path, whose transformations
+    // are then composed.
+    fn resolve(&self, from: &str, to: &str) -> Result<Path, Error> {
+        if from == to {
+            return Ok(None);
+        }
+
+        // For each space reached, the space it was reached from, and the
+        // transformation used to do so.
+        let mut previous: HashMap<&str, (&str, &Transform)> = HashMap::new();
+        let mut queue = VecDeque::new();
+        queue.push_back(from);
+
+        while let Some(current) = queue.pop_front() {
+            if current == to {
+                break;
+            }
+
+            if let Some(edges) = self.edges.get(current) {
+                for (target, transform) in edges {
+                    if target != from && !previous.contains_key(target.as_str()) {
+                        previous.insert(target, (current, transform));
+                        queue.push_back(target);
+                    }
+                }
+            }
+        }
+
+        // Walk back the path, from the target space.
+        let mut steps = vec![];
+        let mut current = to;
+        while let Some((source, transform)) = previous.get(current) {
+            steps.push(*transform);
+            current = source;
+        }
+
+        let mut steps = steps.into_iter().rev();
+        let mut path = match steps.next() {
+            None => return Ok(None),
+            Some(transform) => transform.clone(),
+        };
+        for transform in steps {
+            path = path.then(transform)?;
+        }
+
+        Ok(Some(Arc::new(path)))
+    }
+}
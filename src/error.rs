@@ -0,0 +1,200 @@
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::io;
+
+use crate::space::Space;
+
+/// Errors reported by the database and storage functions.
+#[derive(Debug)]
+pub enum Error {
+    /// No reference space is registered under this name.
+    UnknownSpace(String),
+
+    /// No dataset (core) is registered under this name.
+    UnknownCore(String),
+
+    /// The identifier is not registered in the dataset.
+    UnknownId {
+        /// Name of the dataset (core).
+        core: String,
+        /// The identifier looked up.
+        id: String,
+    },
+
+    /// The same name is used for distinct definitions of reference
+    /// spaces.
+    SpaceConflict {
+        /// Name of the reference space.
+        name: String,
+        /// Definition already registered under that name.
+        existing: Box<Space>,
+        /// Definition which differs from it.
+        conflicting: Box<Space>,
+    },
+
+    /// A position does not have the number of dimensions expected.
+    DimensionMismatch {
+        /// Number of dimensions expected.
+        expected: usize,
+        /// Number of dimensions provided.
+        found: usize,
+    },
+
+    /// A value is outside of the valid range of an axis.
+    OutOfRange {
+        /// Name of the reference space, empty when the value is not
+        /// encoded within a reference space.
+        space: String,
+        /// Position of the axis within the reference space.
+        axis: usize,
+        /// The offending value.
+        value: f64,
+        /// Minimum value allowed, included.
+        minimum: f64,
+        /// Maximum value allowed, included.
+        maximum: f64,
+    },
+
+    /// A value is not part of the set of numbers allowed on an axis.
+    NotInSet {
+        /// The offending value.
+        value: f64,
+        /// Name of the set of numbers allowed.
+        set: String,
+    },
+
+    /// A shape cannot be used for the requested operation.
+    InvalidShape(String),
+
+    /// A definition, such as an axis or an index parameter, is not
+    /// valid.
+    InvalidDefinition(String),
+
+    /// Data read from a file or a stream is not valid.
+    InvalidData(String),
+
+    /// Serialization of data failed.
+    Serialization(String),
+
+    /// Deserialization of data failed, as it is corrupted or not in the
+    /// expected format.
+    Deserialization(String),
+
+    /// An index file was written in a format this version of the
+    /// library cannot read.
+    UnsupportedFormat(String),
+
+    /// The content of a file does not match its checksum.
+    Corrupted(String),
+
+    /// Input / Output error.
+    Io(io::Error),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Error::UnknownSpace(name) => write!(f, "No spaces registered under `{}`", name),
+            Error::UnknownCore(name) => write!(f, "No cores registered under `{}`", name),
+            Error::UnknownId { core, id } => write!(f, "Id `{}` not found in core `{}`", id, core),
+            Error::SpaceConflict { name, .. } => write!(
+                f,
+                "Reference Space ID `{}` defined two times, but differently",
+                name
+            ),
+            Error::DimensionMismatch { expected, found } => write!(
+                f,
+                "Invalid number of dimensions: expected {}, found {}",
+                expected, found
+            ),
+            Error::OutOfRange {
+                space,
+                axis,
+                value,
+                minimum,
+                maximum,
+            } => write!(
+                f,
+                "Position out of bounds: {} not in [{}; {}] on axis {} of space `{}`",
+                value, minimum, maximum, axis, space
+            ),
+            Error::NotInSet { value, set } => write!(f, "Value {} is not in {}", value, set),
+            Error::InvalidShape(reason) => write!(f, "Invalid shape: {}", reason),
+            Error::InvalidDefinition(reason) => write!(f, "Invalid definition: {}", reason),
+            Error::InvalidData(reason) => write!(f, "Invalid data: {}", reason),
+            Error::Serialization(reason) => write!(f, "Could not serialize: {}", reason),
+            Error::Deserialization(reason) => write!(f, "Could not deserialize: {}", reason),
+            Error::UnsupportedFormat(reason) => write!(f, "Unsupported format: {}", reason),
+            Error::Corrupted(reason) => write!(f, "Corrupted data: {}", reason),
+            Error::Io(e) => write!(f, "I/O error: {}", e),
+        }
+    }
+}
+
+impl Error {
+    // Record the axis on which a value is out of range.
+    pub(crate) fn on_axis(self, k: usize) -> Self {
+        match self {
+            Error::OutOfRange {
+                space,
+                value,
+                minimum,
+                maximum,
+                ..
+            } => Error::OutOfRange {
+                space,
+                axis: k,
+                value,
+                minimum,
+                maximum,
+            },
+            e => e,
+        }
+    }
+
+    // Record the reference space in which a value is out of range.
+    pub(crate) fn in_space(self, name: &str) -> Self {
+        match self {
+            Error::OutOfRange {
+                axis,
+                value,
+                minimum,
+                maximum,
+                ..
+            } => Error::OutOfRange {
+                space: name.to_string(),
+                axis,
+                value,
+                minimum,
+                maximum,
+            },
+            e => e,
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        if e.is_io() {
+            Error::Io(e.into())
+        } else {
+            Error::Deserialization(e.to_string())
+        }
+    }
+}
@@ -38,6 +38,8 @@ extern crate log;
 extern crate arrayref;
 
 mod database;
+mod error;
 pub mod storage;
 
 pub use database::*;
+pub use error::Error;
@@ -1,6 +1,7 @@
 #[macro_use]
 extern crate measure_time;
 
+use mercator_db::space::OutOfBounds;
 use mercator_db::space::Shape;
 use mercator_db::storage;
 use mercator_db::CoreQueryParameters;
@@ -16,15 +17,29 @@ fn main() {
     // Convert to binary the JSON data:
     if true {
         info_time!("Converting to binary JSON data");
-        storage::json::from::<Vec<mercator_db::storage::model::Space>>("10k.spaces").unwrap();
-        storage::json::from::<Vec<mercator_db::storage::model::v1::SpatialObject>>("10k.objects")
-            .unwrap();
+        storage::json::from::<Vec<mercator_db::storage::model::Space>>(
+            "10k.spaces",
+            storage::bincode::Compression::None,
+        )
+        .unwrap();
+        storage::json::from_array::<mercator_db::storage::model::v1::SpatialObject>(
+            "10k.objects",
+            storage::bincode::Compression::None,
+        )
+        .unwrap();
     }
 
     // Build a Database Index:
     if true {
         info_time!("Building database index");
-        storage::bincode::build("10k", "v0.1", None, None).unwrap();
+        storage::bincode::build::<mercator_db::storage::model::v1::SpatialObject>(
+            "10k",
+            "v0.1",
+            None,
+            None,
+            storage::bincode::Compression::None,
+        )
+        .unwrap();
     }
 
     // Load a Database:
@@ -44,6 +59,8 @@ fn main() {
             threshold_volume: Some(std::f64::MAX),
             view_port: &None,
             resolution: &None,
+            out_of_bounds: OutOfBounds::Clip,
+            dropped: None,
         };
         let r = core.get_by_id(&c, id).unwrap();
         println!("get_by_id {}: {}", id, r.len());
@@ -55,6 +72,8 @@ fn main() {
             threshold_volume: Some(0.0),
             view_port: &None,
             resolution: &None,
+            out_of_bounds: OutOfBounds::Clip,
+            dropped: None,
         };
         let r = core.get_by_id(&c, id).unwrap();
         println!("get_by_id {}: {}", id, r.len());
@@ -66,6 +85,8 @@ fn main() {
             threshold_volume: Some(std::f64::MAX),
             view_port: &None,
             resolution: &None,
+            out_of_bounds: OutOfBounds::Clip,
+            dropped: None,
         };
         let r = core.get_by_label(&c, id).unwrap();
         println!("get_by_label {}: {}", id, r.len());
@@ -84,6 +105,8 @@ fn main() {
             threshold_volume: Some(0.0),
             view_port: &None,
             resolution: &None,
+            out_of_bounds: OutOfBounds::Clip,
+            dropped: None,
         };
         let r = core.get_by_shape(&c, &shape, "std").unwrap();
         println!("get_by_shape {:?}: {}", shape, r.len());
@@ -1,41 +1,414 @@
 //! Bincode support
 
+use std::cell::Cell;
+use std::convert::TryFrom;
+use std::fs;
 use std::fs::File;
+use std::io;
+use std::io::BufRead;
+use std::io::BufReader;
 use std::io::BufWriter;
-use std::io::Error;
-use std::io::ErrorKind;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::io::Write;
+use std::ops::Range;
+use std::path::Path;
+use std::sync::Arc;
 
 use memmap::Mmap;
 use serde::de::DeserializeOwned;
+use serde::Deserialize;
 use serde::Serialize;
 
 use super::model;
+use crate::database::mapped::failure;
+use crate::database::mapped::is_section;
+use crate::database::mapped::sections;
+use crate::database::mapped::with_source;
+use crate::database::mapped::Source;
+use crate::space::Space;
+use crate::Core;
+use crate::CoreV1;
+use crate::Error;
+
+/// Bytes found at the beginning of every index file.
+pub const INDEX_MAGIC: [u8; 8] = *b"MERCATOR";
+
+/// Version of the layout of the index files written by this library.
+///
+/// Files without a header, written before the header was introduced,
+/// are considered to be of version `0`. Starting with version `2`, the
+/// list of *identifiers* and each resolution of the indices are stored
+/// as separate sections, which are decoded on first use. Version `3`
+/// adds attributes to the *identifiers*, version `4` reference spaces
+/// with displacement fields, and version `5` replaces the checksums of
+/// the file and its sections with CRC-32 ones, and are otherwise the
+/// same as version `2`. Starting with version `6`, the checksum of the
+/// header covers the header itself, and the data outside of the
+/// sections instead of the whole data, so that it can be checked when
+/// the file is loaded.
+pub const INDEX_FORMAT: u32 = 6;
+
+// First bytes of a Zstandard frame.
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xb5, 0x2f, 0xfd];
+
+/// Bytes found at the beginning of the files written by
+/// [`store`](fn.store.html).
+pub const DATA_MAGIC: [u8; 8] = *b"MERCDATA";
+
+/// Version of the layout of the files written by
+/// [`store`](fn.store.html).
+///
+/// It is stored right after [`DATA_MAGIC`](constant.DATA_MAGIC.html),
+/// and followed by the data. Files without it, written before it was
+/// introduced, are considered to be of version `0`. Version `1` adds
+/// attributes to the [`Properties`](../model/struct.Properties.html)
+/// of the spatial objects, version `2` displacement fields to the
+/// reference [`Space`](../model/struct.Space.html)s, and version `3`
+/// rejects the axes of integers whose *ticks* are not part of their set,
+/// which are read as axes of real numbers from earlier versions.
+pub const DATA_FORMAT: u32 = 3;
+
+thread_local! {
+    // Format of the file currently being deserialized, see
+    // `with_data_format`.
+    static FORMAT: Cell<u32> = const { Cell::new(DATA_FORMAT) };
+}
+
+// Format of the data file currently being deserialized, or the current
+// format outside of `load` and `load_iter`.
+//
+// This lets the model skip the fields which are missing from older
+// files, as Bincode does not support default values.
+pub(crate) fn data_format() -> u32 {
+    FORMAT.with(|format| format.get())
+}
+
+// Run `f`, which deserializes the content of a data file of format
+// `format`.
+fn with_data_format<F, R>(format: u32, f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    // Make sure the previous format is restored, even on panics.
+    struct Reset(u32);
+
+    impl Drop for Reset {
+        fn drop(&mut self) {
+            FORMAT.with(|format| format.set(self.0));
+        }
+    }
+
+    let _reset = Reset(FORMAT.with(|current| current.replace(format)));
+
+    f()
+}
+
+/// Compression of the files written by this module.
+///
+/// Compressed files are detected automatically when they are read.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Compression {
+    /// The data is written as-is.
+    #[default]
+    None,
+
+    /// The data is compressed with [Zstandard], at the given level,
+    /// from `1` to `22`, `0` selecting the default level.
+    ///
+    /// [Zstandard]: https://facebook.github.io/zstd/
+    Zstd(i32),
+}
+
+/// Description of the content of an index file.
+///
+/// It is stored right after [`INDEX_MAGIC`](constant.INDEX_MAGIC.html),
+/// and followed by the reference spaces and the dataset.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Header {
+    /// Version of the layout of the file.
+    pub format: u32,
+
+    /// Version of the library which wrote the file.
+    pub library: String,
+
+    /// Resolutions requested when building the index, if any.
+    pub scales: Option<Vec<Vec<u32>>>,
+
+    /// Stopping condition used to generate the resolutions of the
+    /// index, if any.
+    pub max_elements: Option<usize>,
+
+    /// Checksum of the data following the header, or starting with
+    /// format `6`, of the header and of the data outside of the sections.
+    pub checksum: u64,
+}
+
+impl Header {
+    /// Describe an index built by this version of the library.
+    ///
+    /// The checksum is computed when the index is stored.
+    ///
+    /// # Parameters
+    ///
+    ///  * `scales`:
+    ///      Resolutions requested when building the index.
+    ///
+    ///  * `max_elements`:
+    ///      Stopping condition used to generate the resolutions.
+    pub fn new(scales: Option<Vec<Vec<u32>>>, max_elements: Option<usize>) -> Self {
+        Header {
+            format: INDEX_FORMAT,
+            library: env!("CARGO_PKG_VERSION").to_string(),
+            scales,
+            max_elements,
+            checksum: 0,
+        }
+    }
+}
+
+// 64-bit FNV-1a hash, used by the files of format 1 to 4.
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+fn fnv(state: u64, bytes: &[u8]) -> u64 {
+    bytes
+        .iter()
+        .fold(state, |h, b| (h ^ u64::from(*b)).wrapping_mul(FNV_PRIME))
+}
+
+// Checksum used for index files of a given format, and their sections.
+//
+// Both algorithms are stable across platforms and releases, unlike the
+// hashers of the standard library.
+#[derive(Clone)]
+pub(crate) enum Checksum {
+    Fnv(u64),
+    Crc32(crc32fast::Hasher),
+}
+
+impl Checksum {
+    pub(crate) fn new(format: u32) -> Self {
+        if format < 5 {
+            Checksum::Fnv(FNV_OFFSET)
+        } else {
+            Checksum::Crc32(crc32fast::Hasher::new())
+        }
+    }
+
+    pub(crate) fn update(&mut self, bytes: &[u8]) {
+        match self {
+            Checksum::Fnv(state) => *state = fnv(*state, bytes),
+            Checksum::Crc32(hasher) => hasher.update(bytes),
+        }
+    }
+
+    pub(crate) fn finish(self) -> u64 {
+        match self {
+            Checksum::Fnv(state) => state,
+            Checksum::Crc32(hasher) => u64::from(hasher.finalize()),
+        }
+    }
+}
+
+// Checksum of `bytes`, as stored in index files of format `format`.
+pub(crate) fn checksum(format: u32, bytes: &[u8]) -> u64 {
+    let mut checksum = Checksum::new(format);
+    checksum.update(bytes);
+    checksum.finish()
+}
+
+// Checksum of an index file, before its data is added. Starting with
+// format 6, it covers the header, the checksum excluded.
+fn header_checksum(header: &Header) -> Result<Checksum, bincode::Error> {
+    let mut state = Checksum::new(header.format);
+
+    if header.format >= 6 {
+        let header = Header {
+            checksum: 0,
+            ..header.clone()
+        };
+        state.update(&bincode::serialize(&header)?);
+    }
+
+    Ok(state)
+}
+
+// Compute the checksum of the data as it is written.
+struct ChecksumWriter<W> {
+    inner: W,
+    state: Checksum,
+    // Whether the content of the sections is left out, as it has its
+    // own checksum.
+    sections: bool,
+}
+
+impl<W: Write> ChecksumWriter<W> {
+    fn new(inner: W, header: &Header) -> Result<Self, bincode::Error> {
+        Ok(ChecksumWriter {
+            inner,
+            state: header_checksum(header)?,
+            sections: header.format >= 6,
+        })
+    }
+}
+
+impl<W: Write> Write for ChecksumWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+
+        if !(self.sections && is_section(&buf[..written])) {
+            self.state.update(&buf[..written]);
+        }
+
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+// Map a file in memory, or decompress it if needed.
+fn open(from: &str) -> Result<Source, Error> {
+    let file_in = File::open(from)?;
+
+    let mmap = unsafe { Mmap::map(&file_in)? };
+
+    if mmap.starts_with(&ZSTD_MAGIC) {
+        Ok(Source::Decompressed(zstd::stream::decode_all(&mmap[..])?))
+    } else {
+        Ok(Source::Mapped(mmap))
+    }
+}
+
+// Compress the data written to it, if requested.
+enum Compressor<W: Write> {
+    Raw(W),
+    Zstd(zstd::stream::Encoder<'static, W>),
+}
+
+impl<W: Write> Compressor<W> {
+    fn new(writer: W, compression: Compression) -> Result<Self, Error> {
+        match compression {
+            Compression::None => Ok(Compressor::Raw(writer)),
+            Compression::Zstd(level) => {
+                Ok(Compressor::Zstd(zstd::stream::Encoder::new(writer, level)?))
+            }
+        }
+    }
+
+    // Write the end of the compressed data, and flush everything.
+    fn finish(self) -> Result<(), Error> {
+        let mut writer = match self {
+            Compressor::Raw(writer) => writer,
+            Compressor::Zstd(encoder) => encoder.finish()?,
+        };
+
+        Ok(writer.flush()?)
+    }
+}
+
+impl<W: Write> Write for Compressor<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            Compressor::Raw(writer) => writer.write(buf),
+            Compressor::Zstd(encoder) => encoder.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Compressor::Raw(writer) => writer.flush(),
+            Compressor::Zstd(encoder) => encoder.flush(),
+        }
+    }
+}
 
 /// Deserialize a data structure.
 ///
+/// Files written with an older format are converted on the fly.
+///
 /// # Parameters
 ///
 ///  * `from`:
-///      File to read, which contains Bincode data.
+///      File to read, which contains Bincode data, compressed or not.
 pub fn load<T>(from: &str) -> Result<T, Error>
 where
     T: DeserializeOwned,
 {
-    let file_in = File::open(from)?;
+    let data = open(from)?;
 
-    let mmap = unsafe { Mmap::map(&file_in)? };
+    let (format, payload) = split_data(&data[..], from)?;
 
-    match bincode::deserialize(&mmap[..]) {
+    match with_data_format(format, || bincode::deserialize(payload)) {
         Ok(data) => Ok(data),
-        Err(e) => Err(Error::new(
-            ErrorKind::InvalidData,
-            format!("Bincode could not deserialize: {:?}", e),
-        )),
+        Err(e) => Err(Error::Deserialization(format!("{}: {}", from, e))),
     }
 }
 
+/// Deserialize a list of elements, one at a time.
+///
+/// This reads files written by [`store`](fn.store.html) from a `Vec<T>`,
+/// without loading the whole list in memory.
+///
+/// # Parameters
+///
+///  * `from`:
+///      File to read, which contains Bincode data, compressed or not.
+pub fn load_iter<T>(from: &str) -> Result<impl Iterator<Item = Result<T, Error>>, Error>
+where
+    T: DeserializeOwned,
+{
+    let mut file_in = BufReader::new(File::open(from)?);
+
+    let compressed = file_in.fill_buf()?.starts_with(&ZSTD_MAGIC);
+    let mut reader: Box<dyn Read> = if compressed {
+        Box::new(zstd::stream::Decoder::with_buffer(file_in)?)
+    } else {
+        Box::new(file_in)
+    };
+
+    let deserialization = |e: bincode::Error| Error::Deserialization(format!("{}: {}", from, e));
+
+    // Sequences are prefixed by their length, which takes the place of
+    // the magic bytes in files of format 0.
+    let mut magic = [0; DATA_MAGIC.len()];
+    reader.read_exact(&mut magic)?;
+    let (format, len) = if magic == DATA_MAGIC {
+        let format = bincode::deserialize_from(&mut reader).map_err(deserialization)?;
+        check_data_format(format, from)?;
+
+        let len: u64 = bincode::deserialize_from(&mut reader).map_err(deserialization)?;
+        (format, len)
+    } else {
+        (0, u64::from_le_bytes(magic))
+    };
+
+    let from = from.to_string();
+    let mut failed = false;
+
+    Ok((0..len).map_while(move |_| {
+        if failed {
+            return None;
+        }
+
+        match with_data_format(format, || bincode::deserialize_from(&mut reader)) {
+            Ok(data) => Some(Ok(data)),
+            Err(e) => {
+                failed = true;
+                Some(Err(Error::Deserialization(format!("{}: {}", from, e))))
+            }
+        }
+    }))
+}
+
 /// Serialize a data structure.
 ///
+/// The data is preceded by [`DATA_MAGIC`](constant.DATA_MAGIC.html) and
+/// the [`DATA_FORMAT`](constant.DATA_FORMAT.html).
+///
 /// # Parameters
 ///
 ///  * `data`:
@@ -43,26 +416,390 @@ where
 ///
 ///  * `to`:
 ///      File to use to store the serialized data.
-pub fn store<T>(data: T, to: &str) -> Result<(), Error>
+///
+///  * `compression`:
+///      Compression to apply to the file.
+pub fn store<T>(data: T, to: &str, compression: Compression) -> Result<(), Error>
 where
     T: Serialize,
 {
     let file_out = File::create(to)?;
 
     // We create a buffered writer from the file we get
-    let writer = BufWriter::new(&file_out);
+    let mut writer = Compressor::new(BufWriter::new(&file_out), compression)?;
+    write_data_header(&mut writer, to)?;
 
-    match bincode::serialize_into(writer, &data) {
+    match bincode::serialize_into(&mut writer, &data) {
+        Ok(()) => writer.finish(),
+        Err(e) => Err(Error::Serialization(format!("{}: {}", to, e))),
+    }
+}
+
+// Write the magic bytes and the format of a data file.
+pub(crate) fn write_data_header<W>(writer: &mut W, to: &str) -> Result<(), Error>
+where
+    W: Write,
+{
+    writer.write_all(&DATA_MAGIC)?;
+
+    match bincode::serialize_into(writer, &DATA_FORMAT) {
         Ok(()) => Ok(()),
-        Err(e) => Err(Error::new(
-            ErrorKind::InvalidData,
-            format!("Bincode could not serialize: {:?}", e),
-        )),
+        Err(e) => Err(Error::Serialization(format!("{}: {}", to, e))),
     }
 }
 
+// Make sure we know how to read a data file.
+fn check_data_format(format: u32, from: &str) -> Result<(), Error> {
+    if format > DATA_FORMAT {
+        return Err(Error::UnsupportedFormat(format!(
+            "{}: format {}, this version supports up to format {}",
+            from, format, DATA_FORMAT
+        )));
+    }
+
+    Ok(())
+}
+
+// Separate the format of a data file from its data.
+fn split_data<'a>(data: &'a [u8], from: &str) -> Result<(u32, &'a [u8]), Error> {
+    if !data.starts_with(&DATA_MAGIC) {
+        return Ok((0, data));
+    }
+
+    let mut payload = &data[DATA_MAGIC.len()..];
+    let format = match bincode::deserialize_from(&mut payload) {
+        Ok(format) => format,
+        Err(e) => return Err(Error::Deserialization(format!("{}: {}", from, e))),
+    };
+    check_data_format(format, from)?;
+
+    Ok((format, payload))
+}
+
+/// Compress, or decompress a file written by this module.
+///
+/// Index files can be compressed as well, and are still loaded
+/// transparently, but are then fully decompressed in memory. The
+/// converted file is written to a temporary file first, which then
+/// replaces `to`.
+///
+/// # Parameters
+///
+///  * `from`:
+///      File to read, compressed or not.
+///
+///  * `to`:
+///      File to use to store the converted data, can be the same as
+///      `from`.
+///
+///  * `compression`:
+///      Compression to apply to `to`.
+pub fn compress(from: &str, to: &str, compression: Compression) -> Result<(), Error> {
+    let data = open(from)?;
+
+    let tmp = format!("{}.tmp", to);
+    {
+        let file_out = File::create(&tmp)?;
+        let mut writer = Compressor::new(BufWriter::new(&file_out), compression)?;
+        writer.write_all(&data)?;
+        writer.finish()?;
+    }
+
+    Ok(fs::rename(&tmp, to)?)
+}
+
+/// Serialize an index, preceded by its header.
+///
+/// # Parameters
+///
+///  * `header`:
+///      Description of the index, its checksum is computed while the
+///      index is written.
+///
+///  * `spaces`:
+///      Reference spaces used by the dataset.
+///
+///  * `core`:
+///      The dataset index.
+///
+///  * `to`:
+///      File to use to store the index.
+///
+///  * `compression`:
+///      Compression to apply to the index. Compressed indices are fully
+///      decompressed in memory when they are loaded.
+pub fn store_index(
+    mut header: Header,
+    spaces: &[Space],
+    core: &Core,
+    to: &str,
+    compression: Compression,
+) -> Result<(), Error> {
+    // Sections copied from a damaged file are reported as such.
+    let serialization = |e: bincode::Error| {
+        failure().unwrap_or_else(|| Error::Serialization(format!("{}: {}", to, e)))
+    };
+
+    if compression != Compression::None {
+        // The header can not be rewritten within the compressed data, so
+        // the checksum is computed beforehand.
+        let mut writer = ChecksumWriter::new(io::sink(), &header).map_err(serialization)?;
+        bincode::serialize_into(&mut writer, &(spaces, core)).map_err(serialization)?;
+        header.checksum = writer.state.finish();
+
+        let file_out = File::create(to)?;
+        let mut writer = Compressor::new(BufWriter::new(&file_out), compression)?;
+        writer.write_all(&INDEX_MAGIC)?;
+        bincode::serialize_into(&mut writer, &header).map_err(serialization)?;
+        bincode::serialize_into(&mut writer, &(spaces, core)).map_err(serialization)?;
+
+        return writer.finish();
+    }
+
+    let mut file_out = BufWriter::new(File::create(to)?);
+    file_out.write_all(&INDEX_MAGIC)?;
+
+    // Reserve the space of the header, the checksum has a fixed size, so
+    // the header can be rewritten in place once it is known.
+    let offset = file_out.stream_position()?;
+    bincode::serialize_into(&mut file_out, &header).map_err(serialization)?;
+
+    let mut writer = ChecksumWriter::new(file_out, &header).map_err(serialization)?;
+    bincode::serialize_into(&mut writer, &(spaces, core)).map_err(serialization)?;
+
+    header.checksum = writer.state.finish();
+
+    let mut file_out = writer.inner;
+    file_out.seek(SeekFrom::Start(offset))?;
+    bincode::serialize_into(&mut file_out, &header).map_err(serialization)?;
+    file_out.flush()?;
+
+    Ok(())
+}
+
+/// Read the header of an index file.
+///
+/// Files written before headers were introduced are reported as format
+/// `0`, without any build parameters.
+///
+/// # Parameters
+///
+///  * `from`:
+///      Index file to read.
+pub fn load_header(from: &str) -> Result<Header, Error> {
+    let data = open(from)?;
+
+    let (header, _) = split_index(&data[..], from)?;
+
+    Ok(header)
+}
+
+/// Load an index, checking it is compatible.
+///
+/// The file stays memory-mapped, or decompressed in memory when it is
+/// compressed, and the list of *identifiers* as well as each resolution
+/// of the indices are only decoded when they are first used. Queries
+/// do not run on the mapped file itself: the decoded values are kept in
+/// memory, until the index is dropped.
+///
+/// Each of them is checked against its own checksum when it is decoded,
+/// so errors are reported by the queries, instead of by this function,
+/// which checks the rest of the file. Files of format `2` to `5` do not
+/// have a checksum for the rest of the file alone, and are checked as a
+/// whole, which reads the whole file.
+///
+/// Files written with an older format are fully decoded and converted
+/// on the fly, see [`migrate`](fn.migrate.html) to convert them
+/// permanently.
+///
+/// # Parameters
+///
+///  * `from`:
+///      Index file to read.
+pub fn load_index(from: &str) -> Result<(Vec<Space>, Core), Error> {
+    let source = Arc::new(open(from)?);
+
+    let (header, _) = split_index(&source[..], from)?;
+
+    with_source(source, header.format, |data| {
+        let (header, payload) = split_index(data, from)?;
+        let deserialization = |e| Error::Deserialization(format!("{}: {}", from, e));
+
+        match header.format {
+            0 | 1 => {
+                verify_payload(&header, payload, from)?;
+
+                bincode::deserialize::<(Vec<Space>, CoreV1)>(payload)
+                    .map(|(spaces, core)| (spaces, Core::from(core)))
+                    .map_err(deserialization)
+            }
+            2..=5 => {
+                verify_payload(&header, payload, from)?;
+
+                bincode::deserialize(payload).map_err(deserialization)
+            }
+            6 => {
+                let index = bincode::deserialize(payload).map_err(deserialization)?;
+                verify_outside_sections(&header, data, payload, &sections(), from)?;
+
+                Ok(index)
+            }
+            _ => unreachable!(),
+        }
+    })
+}
+
+// Check the data following the header of files of format 5 and
+// earlier against their checksum.
+fn verify_payload(header: &Header, payload: &[u8], from: &str) -> Result<(), Error> {
+    // Format 0 files do not have a checksum.
+    if header.format > 0 && header.checksum != checksum(header.format, payload) {
+        return Err(Error::Corrupted(format!("{}: checksum mismatch", from)));
+    }
+
+    Ok(())
+}
+
+// Check the header, and the `payload` outside of `sections`, of index
+// files of format 6 and later, against their checksum. The sections are
+// located within `data`, the whole file, which ends with `payload`.
+fn verify_outside_sections(
+    header: &Header,
+    data: &[u8],
+    payload: &[u8],
+    sections: &[(Range<usize>, u64)],
+    from: &str,
+) -> Result<(), Error> {
+    let serialization = |e| Error::Serialization(format!("{}: {}", from, e));
+    let mut state = header_checksum(header).map_err(serialization)?;
+
+    let mut start = data.len() - payload.len();
+    for (section, _) in sections {
+        state.update(&data[start..section.start]);
+        start = section.end;
+    }
+    state.update(&data[start..]);
+
+    if header.checksum != state.finish() {
+        return Err(Error::Corrupted(format!("{}: checksum mismatch", from)));
+    }
+
+    Ok(())
+}
+
+/// Check the whole content of an index file against its checksums.
+///
+/// # Parameters
+///
+///  * `from`:
+///      Index file to read.
+pub fn verify_index(from: &str) -> Result<(), Error> {
+    let source = Arc::new(open(from)?);
+
+    let (header, payload) = split_index(&source[..], from)?;
+    if header.format < 6 {
+        return verify_payload(&header, payload, from);
+    }
+
+    // The checksum of the header does not cover the content of the
+    // sections, which are checked against their own.
+    with_source(source.clone(), header.format, |data| {
+        let (header, payload) = split_index(data, from)?;
+
+        if let Err(e) = bincode::deserialize::<(Vec<Space>, Core)>(payload) {
+            return Err(Error::Deserialization(format!("{}: {}", from, e)));
+        }
+
+        let sections = sections();
+        verify_outside_sections(&header, data, payload, &sections, from)?;
+
+        for (section, expected) in sections {
+            if checksum(header.format, &data[section.clone()]) != expected {
+                return Err(Error::Corrupted(format!(
+                    "{}: checksum mismatch for section at offset {}",
+                    from, section.start
+                )));
+            }
+        }
+
+        Ok(())
+    })
+}
+
+/// Rewrite an index file using the current format.
+///
+/// The build parameters are kept when available. The converted index is
+/// written uncompressed to a temporary file first, which then replaces
+/// `to`, see [`compress`](fn.compress.html) to compress it.
+///
+/// # Parameters
+///
+///  * `from`:
+///      Index file to read.
+///
+///  * `to`:
+///      File to use to store the converted index, can be the same as
+///      `from`.
+pub fn migrate(from: &str, to: &str) -> Result<(), Error> {
+    let header = load_header(from)?;
+    let (spaces, core) = load_index(from)?;
+
+    // The sections which are not yet decoded are copied from `from`, so
+    // it must not be overwritten while writing.
+    let tmp = format!("{}.tmp", to);
+    let stored = store_index(
+        Header::new(header.scales, header.max_elements),
+        &spaces,
+        &core,
+        &tmp,
+        Compression::None,
+    );
+
+    if let Err(e) = stored {
+        let _ = fs::remove_file(&tmp);
+        return Err(e);
+    }
+
+    Ok(fs::rename(&tmp, to)?)
+}
+
+// Separate the header from the data of an index file, and make sure we
+// know how to read that data.
+fn split_index<'a>(data: &'a [u8], from: &str) -> Result<(Header, &'a [u8]), Error> {
+    if !data.starts_with(&INDEX_MAGIC) {
+        let header = Header {
+            format: 0,
+            library: String::new(),
+            scales: None,
+            max_elements: None,
+            checksum: 0,
+        };
+
+        return Ok((header, data));
+    }
+
+    let mut payload = &data[INDEX_MAGIC.len()..];
+    let header: Header = match bincode::deserialize_from(&mut payload) {
+        Ok(header) => header,
+        Err(e) => return Err(Error::Deserialization(format!("{}: {}", from, e))),
+    };
+
+    if header.format > INDEX_FORMAT {
+        return Err(Error::UnsupportedFormat(format!(
+            "{}: format {} written by version {}, this version supports up to format {}",
+            from, header.format, header.library, INDEX_FORMAT
+        )));
+    }
+
+    Ok((header, payload))
+}
+
 /// Build an index from the input files.
 ///
+/// The objects file contains a list of `O`, either
+/// [`v1`](../model/v1/struct.SpatialObject.html) or
+/// [`v2`](../model/v2/struct.SpatialObject.html) spatial objects.
+///
 /// # Parameters
 ///
 ///  * `name`:
@@ -86,33 +823,115 @@ where
 ///     equal to the number of distinct Ids, or smaller or equal to this
 ///     value.
 ///
+/// * `compression`:
+///     Compression to apply to the index file.
+///
 /// **Note**: `max_elements` is ignored when `scales` is not `None`.
-pub fn build(
+pub fn build<O>(
     name: &str,
     version: &str,
     scales: Option<Vec<Vec<u32>>>,
     max_elements: Option<usize>,
-) -> Result<(), Error> {
+    compression: Compression,
+) -> Result<(), Error>
+where
+    O: model::Indexable + DeserializeOwned,
+{
     let fn_spaces = format!("{}.spaces.bin", name);
     let fn_objects = format!("{}.objects.bin", name);
     let fn_index = format!("{}.index", name);
 
     let spaces = load::<Vec<model::Space>>(&fn_spaces)?
         .iter()
-        .map(|s| s.into())
-        .collect::<Vec<_>>();
-
-    let objects = load::<Vec<model::SpatialObject>>(&fn_objects)?;
-
-    let core = match model::build_index(name, version, &spaces, &objects, scales, max_elements) {
-        Ok(core) => core,
-        Err(e) => {
-            return Err(Error::new(
-                ErrorKind::InvalidData,
-                format!("Failure to build index: {:?}", e),
-            ))
-        }
-    };
+        .map(Space::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let objects = load::<Vec<O>>(&fn_objects)?;
+
+    let header = Header::new(scales.clone(), max_elements);
+    let core = model::build_index(name, version, &spaces, &objects, scales, max_elements)?;
+
+    store_index(header, &spaces, &core, &fn_index, compression)
+}
+
+/// Build an index from the input files, using a bounded amount of
+/// memory.
+///
+/// The objects are read one at a time, and their positions are sorted
+/// on disk, in chunks. This produces the same index file as
+/// [`build`](fn.build.html), but only the distinct Ids and the final
+/// index are held in memory.
+///
+/// The objects file contains a list of `O`, see [`build`](fn.build.html).
+///
+/// # Parameters
+///
+///  * `name`:
+///      Index name, this value will also be used to generate file names
+///      as such:
+///       * `.spaces.bin` and `.objects.bin` will be appended for the
+///          input files.
+///       * `.index` will be appended for the index file.
+///
+/// * `version`:
+///     Parameter to distinguish revisions of an index.
+///
+/// * `scales`:
+///     An optional list of specific index resolutions to generates on
+///     top of the full resolution one.
+///
+/// * `max_elements`:
+///     If this is specified, automatically generates scaled indices, by
+///     halving the number elements between resolutions, and stop
+///     generating indices either when the number of points remaining is
+///     equal to the number of distinct Ids, or smaller or equal to this
+///     value.
+///
+/// * `directory`:
+///     Location of the temporary files, which are removed once the
+///     index is built.
+///
+/// * `chunk_size`:
+///     Maximum number of positions held in memory while sorting.
+///
+/// * `compression`:
+///     Compression to apply to the index file.
+///
+/// **Note**: `max_elements` is ignored when `scales` is not `None`.
+pub fn build_external<O>(
+    name: &str,
+    version: &str,
+    scales: Option<Vec<Vec<u32>>>,
+    max_elements: Option<usize>,
+    directory: &Path,
+    chunk_size: usize,
+    compression: Compression,
+) -> Result<(), Error>
+where
+    O: model::Indexable + DeserializeOwned,
+{
+    let fn_spaces = format!("{}.spaces.bin", name);
+    let fn_objects = format!("{}.objects.bin", name);
+    let fn_index = format!("{}.index", name);
+
+    let spaces = load::<Vec<model::Space>>(&fn_spaces)?
+        .iter()
+        .map(Space::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let objects = load_iter::<O>(&fn_objects)?;
+
+    let header = Header::new(scales.clone(), max_elements);
+    let core = model::build_index_external(
+        name,
+        version,
+        &spaces,
+        objects,
+        scales,
+        max_elements,
+        directory,
+        chunk_size,
+    )?;
 
-    store((spaces, core), &fn_index)
+    store_index(header, &spaces, &core, &fn_index, compression)
 }
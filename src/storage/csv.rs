@@ -0,0 +1,173 @@
+//! CSV export
+//!
+//! Query results are written one position per line, with the following
+//! columns:
+//!
+//! ```txt
+//! space,x0,x1,x2,type,id
+//! std,0.2,0.5,0.1,Feature,oid0.5793259558369925
+//! ```
+//!
+//!  * `space`: Id of the reference space of the position.
+//!  * `x0` to `xn`: the coordinates of the position, one column per
+//!    axis. There are as many columns as the largest number of
+//!    dimensions of the reference spaces found in the results, the
+//!    columns of the missing axes are left empty.
+//!  * `type` and `id`: the *identifier* linked to the position.
+//!
+//! Fields are quoted as needed, following [RFC 4180].
+//!
+//! [RFC 4180]: https://tools.ietf.org/html/rfc4180
+
+use std::borrow::Cow;
+use std::io::Write;
+use std::iter::Peekable;
+
+use crate::database;
+use crate::Error;
+use database::space::Position;
+
+// Quote a field if it contains a separator, a quote or a line break.
+fn field(value: &str) -> Cow<'_, str> {
+    if value.contains(&[',', '"', '\n', '\r'][..]) {
+        Cow::Owned(format!("\"{}\"", value.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(value)
+    }
+}
+
+fn write_header<W>(writer: &mut W, dimensions: usize) -> Result<(), Error>
+where
+    W: Write,
+{
+    write!(writer, "space")?;
+    for k in 0..dimensions {
+        write!(writer, ",x{}", k)?;
+    }
+    writeln!(writer, ",type,id")?;
+
+    Ok(())
+}
+
+fn write_row<W>(
+    writer: &mut W,
+    dimensions: usize,
+    space: &str,
+    position: &Position,
+    properties: &database::Properties,
+) -> Result<(), Error>
+where
+    W: Write,
+{
+    let coordinates: Vec<f64> = position.into();
+
+    write!(writer, "{}", field(space))?;
+    for k in 0..dimensions {
+        match coordinates.get(k) {
+            Some(c) => write!(writer, ",{}", c)?,
+            None => write!(writer, ",")?,
+        }
+    }
+    writeln!(
+        writer,
+        ",{},{}",
+        field(properties.type_name()),
+        field(properties.id())
+    )?;
+
+    Ok(())
+}
+
+// Find the largest number of dimensions of the positions, looking only
+// at the first one of each list, as they all share the same reference
+// space.
+fn dimensions<I, T>(lists: &mut [(&String, Peekable<I>)], position: fn(&T) -> &Position) -> usize
+where
+    I: Iterator<Item = T>,
+{
+    lists
+        .iter_mut()
+        .filter_map(|(_, list)| list.peek().map(|v| position(v).dimensions()))
+        .max()
+        .unwrap_or(0)
+}
+
+/// Write the results of a query as CSV.
+///
+/// The results are written as they are produced by the iterators, once
+/// the first position of each reference space is known, in order to
+/// size the coordinate columns.
+///
+/// # Parameters
+///
+///  * `writer`:
+///      Where to write the CSV data, it is recommended to use a
+///      buffered writer.
+///
+///  * `results`:
+///      The results of a query, such as
+///      [`Core::get_by_shape`](../../struct.Core.html#method.get_by_shape).
+pub fn write_objects<W>(
+    mut writer: W,
+    results: database::IterObjectsBySpaces<'_>,
+) -> Result<(), Error>
+where
+    W: Write,
+{
+    let mut results = results
+        .into_iter()
+        .map(|(space, objects)| (space, objects.peekable()))
+        .collect::<Vec<_>>();
+    let dimensions = dimensions(&mut results, |(position, _)| position);
+
+    write_header(&mut writer, dimensions)?;
+    for (space, objects) in results {
+        for (position, properties) in objects {
+            write_row(&mut writer, dimensions, space, &position, properties)?;
+        }
+    }
+
+    Ok(writer.flush()?)
+}
+
+/// Write the positions of an *identifier* as CSV.
+///
+/// The positions are written as they are produced by the iterators, once
+/// the first position of each reference space is known, in order to
+/// size the coordinate columns.
+///
+/// # Parameters
+///
+///  * `writer`:
+///      Where to write the CSV data, it is recommended to use a
+///      buffered writer.
+///
+///  * `properties`:
+///      The *identifier* the positions are linked to.
+///
+///  * `results`:
+///      The positions, as returned by
+///      [`Core::get_by_id`](../../struct.Core.html#method.get_by_id).
+pub fn write_positions<W>(
+    mut writer: W,
+    properties: &database::Properties,
+    results: Vec<(&String, database::IterPositions<'_>)>,
+) -> Result<(), Error>
+where
+    W: Write,
+{
+    let mut results = results
+        .into_iter()
+        .map(|(space, positions)| (space, positions.peekable()))
+        .collect::<Vec<_>>();
+    let dimensions = dimensions(&mut results, |position| position);
+
+    write_header(&mut writer, dimensions)?;
+    for (space, positions) in results {
+        for position in positions {
+            write_row(&mut writer, dimensions, space, &position, properties)?;
+        }
+    }
+
+    Ok(writer.flush()?)
+}
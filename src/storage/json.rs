@@ -1,34 +1,193 @@
 //! JSON support
+//!
+//! Files can be converted at once with [`from`](fn.from.html), or one
+//! element at a time, without loading the whole list in memory, from a
+//! JSON array with [`from_array`](fn.from_array.html) or from
+//! newline-delimited JSON with [`from_ndjson`](fn.from_ndjson.html).
 
+use std::fmt;
+use std::fs;
 use std::fs::File;
+use std::io::BufReader;
 use std::io::BufWriter;
-use std::io::Error;
-use std::io::ErrorKind;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::io::Write;
+use std::marker::PhantomData;
 
 use memmap::Mmap;
+use serde::de;
 use serde::de::DeserializeOwned;
+use serde::Deserializer;
 use serde::Serialize;
 
-fn convert<T>(from: &str, to: &str) -> Result<(), Error>
+use super::bincode::compress;
+use super::bincode::store;
+use super::bincode::write_data_header;
+use super::bincode::Compression;
+use crate::Error;
+
+fn convert<T>(from: &str, to: &str, compression: Compression) -> Result<(), Error>
 where
     T: Serialize + DeserializeOwned,
 {
     let file_in = File::open(from)?;
-    let file_out = File::create(to)?;
-
-    // We create a buffered writer from the file we get
-    let writer = BufWriter::new(&file_out);
 
     let mmap = unsafe { Mmap::map(&file_in)? };
     let v: T = serde_json::from_slice(&mmap[..])?;
 
-    match bincode::serialize_into(writer, &v) {
-        Ok(()) => Ok(()),
-        Err(e) => Err(Error::new(
-            ErrorKind::InvalidData,
-            format!("Bincode could not serialize: {:?}", e),
-        )),
+    store(v, to, compression)
+}
+
+// Write the elements of a list one at a time, with the same layout as a
+// `Vec` written by `store`.
+//
+// The list is written to a temporary file, which replaces `to` once
+// complete, so that `to` is left untouched on errors.
+struct ListWriter<'a> {
+    writer: BufWriter<File>,
+    to: &'a str,
+    tmp: String,
+    // Location of the length of the list.
+    offset: u64,
+    len: u64,
+}
+
+impl<'a> ListWriter<'a> {
+    fn new(to: &'a str) -> Result<Self, Error> {
+        let tmp = format!("{}.tmp", to);
+        let mut writer = BufWriter::new(File::create(&tmp)?);
+        write_data_header(&mut writer, to)?;
+
+        let mut list = ListWriter {
+            offset: writer.stream_position()?,
+            writer,
+            to,
+            tmp,
+            len: 0,
+        };
+
+        // Sequences are prefixed by their length, which is rewritten once
+        // known.
+        list.write(&0u64)?;
+
+        Ok(list)
+    }
+
+    fn write<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize,
+    {
+        match bincode::serialize_into(&mut self.writer, value) {
+            Ok(()) => Ok(()),
+            Err(e) => Err(Error::Serialization(format!("{}: {}", self.to, e))),
+        }
+    }
+
+    fn push<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize,
+    {
+        self.write(value)?;
+        self.len += 1;
+
+        Ok(())
+    }
+
+    fn finish(mut self, compression: Compression) -> Result<(), Error> {
+        let len = self.len;
+        self.writer.seek(SeekFrom::Start(self.offset))?;
+        self.write(&len)?;
+        self.writer.flush()?;
+
+        let ListWriter {
+            writer, to, tmp, ..
+        } = self;
+        drop(writer);
+
+        // The length is only known at the end, so the file is compressed
+        // once complete.
+        if compression != Compression::None {
+            compress(&tmp, &tmp, compression)?;
+        }
+
+        Ok(fs::rename(&tmp, to)?)
+    }
+}
+
+// Write the elements of a JSON array as they are parsed.
+struct ArrayVisitor<'a, 'w, T> {
+    list: &'a mut ListWriter<'w>,
+    // Error raised while writing, if any, as the parser only reports
+    // its own errors.
+    error: &'a mut Option<Error>,
+    element: PhantomData<T>,
+}
+
+impl<'de, T> de::Visitor<'de> for ArrayVisitor<'_, '_, T>
+where
+    T: Serialize + DeserializeOwned,
+{
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an array")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::SeqAccess<'de>,
+    {
+        while let Some(value) = seq.next_element::<T>()? {
+            if let Err(e) = self.list.push(&value) {
+                let message = e.to_string();
+                *self.error = Some(e);
+
+                return Err(de::Error::custom(message));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn convert_array<T>(from: &str, to: &str, compression: Compression) -> Result<(), Error>
+where
+    T: Serialize + DeserializeOwned,
+{
+    let reader = BufReader::new(File::open(from)?);
+    let mut deserializer = serde_json::Deserializer::from_reader(reader);
+
+    let mut list = ListWriter::new(to)?;
+    let mut error = None;
+
+    let visitor = ArrayVisitor::<T> {
+        list: &mut list,
+        error: &mut error,
+        element: PhantomData,
+    };
+
+    if let Err(e) = deserializer.deserialize_seq(visitor) {
+        return Err(error.unwrap_or_else(|| e.into()));
+    }
+    deserializer.end()?;
+
+    list.finish(compression)
+}
+
+fn convert_ndjson<T>(from: &str, to: &str, compression: Compression) -> Result<(), Error>
+where
+    T: Serialize + DeserializeOwned,
+{
+    let reader = BufReader::new(File::open(from)?);
+
+    let mut list = ListWriter::new(to)?;
+
+    for value in serde_json::Deserializer::from_reader(reader).into_iter::<T>() {
+        list.push(&value?)?;
     }
+
+    list.finish(compression)
 }
 
 /// Deserialise a JSON file.
@@ -37,9 +196,12 @@ where
 ///
 ///  * `name`:
 ///      Base name of the file,
-///       * `.xyz` will be automatically appended for the source file, while
+///       * `.json` will be automatically appended for the source file, while
 ///       * `.bin` will be appended for the output file.
-pub fn from<T>(name: &str) -> Result<(), Error>
+///
+///  * `compression`:
+///      Compression to apply to the output file.
+pub fn from<T>(name: &str, compression: Compression) -> Result<(), Error>
 where
     T: Serialize + DeserializeOwned,
 {
@@ -47,5 +209,60 @@ where
     let fn_in = format!("{}.json", name);
     let fn_out = format!("{}.bin", name);
 
-    convert::<T>(&fn_in, &fn_out)
+    convert::<T>(&fn_in, &fn_out, compression)
+}
+
+/// Deserialise a JSON file containing an array, one element at a time.
+///
+/// The output is the same as the one of [`from`](fn.from.html) for a
+/// `Vec<T>`, so it can be used to build indices, see
+/// [`build_external`](../bincode/fn.build_external.html) to do so
+/// without loading all the objects in memory either.
+///
+/// The output is written to a temporary file first, which then replaces
+/// the output file, so that it is left untouched on errors.
+///
+/// # Parameters
+///
+///  * `name`:
+///      Base name of the file,
+///       * `.json` will be automatically appended for the source file, while
+///       * `.bin` will be appended for the output file.
+///
+///  * `compression`:
+///      Compression to apply to the output file.
+pub fn from_array<T>(name: &str, compression: Compression) -> Result<(), Error>
+where
+    T: Serialize + DeserializeOwned,
+{
+    let fn_in = format!("{}.json", name);
+    let fn_out = format!("{}.bin", name);
+
+    convert_array::<T>(&fn_in, &fn_out, compression)
+}
+
+/// Deserialise a newline-delimited JSON file, one element at a time.
+///
+/// Each line contains one element, such as the objects written by the
+/// [`ndjson`](../ndjson/index.html) export. The output is the same as
+/// the one of [`from_array`](fn.from_array.html).
+///
+/// # Parameters
+///
+///  * `name`:
+///      Base name of the file,
+///       * `.ndjson` will be automatically appended for the source file,
+///         while
+///       * `.bin` will be appended for the output file.
+///
+///  * `compression`:
+///      Compression to apply to the output file.
+pub fn from_ndjson<T>(name: &str, compression: Compression) -> Result<(), Error>
+where
+    T: Serialize + DeserializeOwned,
+{
+    let fn_in = format!("{}.ndjson", name);
+    let fn_out = format!("{}.bin", name);
+
+    convert_ndjson::<T>(&fn_in, &fn_out, compression)
 }
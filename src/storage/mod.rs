@@ -4,6 +4,12 @@
 //! store and manipulate indices and data.
 
 pub mod bincode;
+pub mod csv;
 pub mod json;
 pub mod model;
+pub mod ndjson;
+pub mod nifti;
 pub mod xyz;
+
+#[cfg(test)]
+mod tests;
@@ -4,11 +4,16 @@
 //! process to exchange objects either through network or to storage.
 
 use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::path::Path;
 
 use serde::Deserialize;
+use serde::Deserializer;
 use serde::Serialize;
 
+use super::bincode::data_format;
 use crate::database;
+use crate::Error;
 use database::space;
 use database::space_index::SpaceSetObject;
 use database::Core;
@@ -24,6 +29,54 @@ pub struct Space {
 
     /// List of axes of the space.
     pub axes: Vec<Axis>,
+
+    /// Non-linear part of the transformation into the Universe, if any.
+    #[serde(default, deserialize_with = "deformation")]
+    pub deformation: Option<Deformation>,
+}
+
+// Data files of format 1 and earlier do not store deformations.
+fn deformation<'de, D>(deserializer: D) -> Result<Option<Deformation>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    if data_format() < 2 {
+        Ok(None)
+    } else {
+        Option::<Deformation>::deserialize(deserializer)
+    }
+}
+
+/// Non-linear transformation of a reference space into the Universe.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Deformation {
+    /// Displacements applied to the positions of the space, once mapped
+    /// into the Universe through its origin and axes.
+    pub forward: DisplacementField,
+
+    /// Displacements applied to Universe positions, before they are
+    /// mapped into the space through its origin and axes. It is computed
+    /// from `forward` when missing.
+    #[serde(default)]
+    pub inverse: Option<DisplacementField>,
+}
+
+/// Displacements sampled on a regular grid of the Universe.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DisplacementField {
+    /// Position of the first sample, expressed in Universe coordinates.
+    pub origin: Vec<f64>,
+
+    /// Distance between two consecutive samples, along each axis of the
+    /// Universe.
+    pub spacing: Vec<f64>,
+
+    /// Number of samples along each axis of the Universe.
+    pub dimensions: Vec<usize>,
+
+    /// The displacement vectors of all the samples, one after the other,
+    /// with the first axis varying fastest.
+    pub displacements: Vec<f64>,
 }
 
 /// Reference space axis definition.
@@ -33,25 +86,48 @@ pub struct Axis {
     pub measurement_unit: String,
 
     /// Define the valid range of number on this axis.
+    #[serde(deserialize_with = "graduation")]
     pub graduation: Graduation,
 
     /// Vector which defines the direction of the axis in the Universe
     pub unit_vector: Vec<f64>,
 }
 
+// Data files of format 2 and earlier predate the enforcement of the
+// number sets, and read axes of integers which do not fit their set as
+// axes of real numbers.
+fn graduation<'de, D>(deserializer: D) -> Result<Graduation, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let mut graduation = Graduation::deserialize(deserializer)?;
+
+    if data_format() < 3 && (graduation.set == "N" || graduation.set == "Z") {
+        let g = &graduation;
+        let set = g.set.as_str().into();
+
+        if let Ok(legacy) = space::Graduation::legacy(set, g.minimum, g.maximum, g.steps) {
+            graduation.set = (&legacy.set).into();
+        }
+    }
+
+    Ok(graduation)
+}
+
 /// Valid range of numbers on the axis.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Graduation {
-    /// Mathematical Number Set of numbers allowed.
+    /// Mathematical Number Set of numbers allowed, one of `N`, `Z`, `Q`
+    /// or `R`.
     pub set: String,
 
     /// Minimum value allowed, included.
     pub minimum: f64,
 
-    /// Maximum value allowed, excluded.
+    /// Maximum value allowed, included.
     pub maximum: f64,
 
-    /// Number of distinct positions between `[min; max[`
+    /// Number of *ticks* between `[min; max]`.
     pub steps: u64,
 }
 
@@ -70,8 +146,10 @@ pub mod v1 {
 
     use super::database;
     use super::space;
+    use super::Indexable;
     use super::Point;
     use super::Properties;
+    use crate::Error;
 
     /// Links Properties to a list of spatial volumes.
     #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -83,6 +161,38 @@ pub mod v1 {
         pub shapes: Vec<Shape>,
     }
 
+    impl Indexable for SpatialObject {
+        fn id(&self) -> &str {
+            &self.properties.id
+        }
+
+        fn positions(
+            &self,
+            _spaces: &[space::Space],
+        ) -> Result<Vec<(String, space::Position)>, Error> {
+            self.shapes
+                .iter()
+                .map(|shape| {
+                    if shape.type_name != "Point" {
+                        return Err(Error::InvalidShape(format!(
+                            "Unsupported shape type '{}'",
+                            shape.type_name
+                        )));
+                    }
+
+                    match shape.vertices.first() {
+                        Some(point) => Ok((shape.reference_space.clone(), point.into())),
+                        None => Err(Error::InvalidShape("Point without vertices".to_string())),
+                    }
+                })
+                .collect()
+        }
+
+        fn attributes(&self) -> Option<&database::Attributes> {
+            Some(&self.properties.attributes)
+        }
+    }
+
     /// Define a Shape, within a specific reference space.
     #[derive(Clone, Debug, Deserialize, Serialize)]
     pub struct Shape {
@@ -151,10 +261,15 @@ pub mod v2 {
     use serde::Deserialize;
     use serde::Serialize;
 
+    use std::collections::HashSet;
+
     use super::database;
+    use super::rasterise;
     use super::space;
+    use super::Indexable;
     use super::Point;
     use super::Properties;
+    use crate::Error;
 
     /// Links Properties to a list of spatial volumes.
     #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -166,6 +281,73 @@ pub mod v2 {
         pub volumes: Vec<Volume>,
     }
 
+    impl Indexable for SpatialObject {
+        fn id(&self) -> &str {
+            &self.properties.id
+        }
+
+        fn positions(
+            &self,
+            spaces: &[space::Space],
+        ) -> Result<Vec<(String, space::Position)>, Error> {
+            let mut unique = HashSet::new();
+            let mut positions = vec![];
+
+            for volume in &self.volumes {
+                let space = spaces.iter().find(|space| space.name() == &volume.space);
+
+                let mut cells: Vec<space::Position> = vec![];
+                for shape in &volume.shapes {
+                    match (shape, space) {
+                        (Shape::Points(points), _) => {
+                            cells.extend(points.iter().map(|point| point.into()))
+                        }
+                        (Shape::BoundingBoxes(boxes), Some(space)) => {
+                            for (lower, higher) in boxes {
+                                cells.extend(rasterise(space, lower, higher, |_, _| true)?);
+                            }
+                        }
+                        (Shape::HyperSpheres(spheres), Some(space)) => {
+                            for (center, radius) in spheres {
+                                let lower = center.iter().map(|c| c - radius).collect::<Vec<_>>();
+                                let higher = center.iter().map(|c| c + radius).collect::<Vec<_>>();
+
+                                // Keep the cells which intersect the sphere,
+                                // using the point of the cell closest to
+                                // the center.
+                                cells.extend(rasterise(space, &lower, &higher, |low, high| {
+                                    let distance = center
+                                        .iter()
+                                        .enumerate()
+                                        .map(|(k, c)| (c.max(low[k]).min(high[k]) - c).powi(2))
+                                        .sum::<f64>();
+
+                                    distance <= radius * radius
+                                })?);
+                            }
+                        }
+                        // Volumes cannot be rasterised without their
+                        // reference space, they would not be indexed
+                        // anyway.
+                        (_, None) => (),
+                    }
+                }
+
+                for position in cells {
+                    if unique.insert((&volume.space, position.clone())) {
+                        positions.push((volume.space.clone(), position));
+                    }
+                }
+            }
+
+            Ok(positions)
+        }
+
+        fn attributes(&self) -> Option<&database::Attributes> {
+            Some(&self.properties.attributes)
+        }
+    }
+
     /// Defines a volume as the union of geometric shapes.
     #[derive(Clone, Debug, Deserialize, Serialize)]
     pub struct Volume {
@@ -303,6 +485,23 @@ pub struct Properties {
 
     /// An arbitrary string.
     pub id: String,
+
+    /// Arbitrary metadata, such as colours or annotations, which are
+    /// kept in the index.
+    #[serde(default, deserialize_with = "attributes")]
+    pub attributes: database::Attributes,
+}
+
+// Data files of format 0 do not store attributes.
+fn attributes<'de, D>(deserializer: D) -> Result<database::Attributes, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    if data_format() < 1 {
+        Ok(database::Attributes::new())
+    } else {
+        database::Attributes::deserialize(deserializer)
+    }
 }
 
 impl From<&space::Graduation> for Graduation {
@@ -316,19 +515,29 @@ impl From<&space::Graduation> for Graduation {
     }
 }
 
-impl From<Axis> for space::Axis {
-    fn from(axis: Axis) -> Self {
-        let g = axis.graduation;
+impl TryFrom<&Axis> for space::Axis {
+    type Error = Error;
+
+    fn try_from(axis: &Axis) -> Result<Self, Self::Error> {
+        let g = &axis.graduation;
+        let set = match g.set.as_str() {
+            set @ "N" | set @ "Z" | set @ "Q" | set @ "R" => set.into(),
+            set => {
+                return Err(Error::InvalidDefinition(format!(
+                    "Invalid set number: '{}', expected: N, Z, Q, R",
+                    set
+                )))
+            }
+        };
 
         space::Axis::new(
             &axis.measurement_unit,
-            axis.unit_vector,
-            g.set.as_str().into(),
+            axis.unit_vector.clone(),
+            set,
             g.minimum,
             g.maximum,
             g.steps,
         )
-        .unwrap_or_else(|e| panic!("Unable to create Axis as defined: {}", e))
     }
 }
 
@@ -342,17 +551,29 @@ impl From<&space::Axis> for Axis {
     }
 }
 
-impl From<&Space> for space::Space {
-    fn from(space: &Space) -> Self {
+impl TryFrom<&Space> for space::Space {
+    type Error = Error;
+
+    fn try_from(space: &Space) -> Result<Self, Self::Error> {
         let axes = space
             .axes
             .iter()
-            .map(|a| a.clone().into())
-            .collect::<Vec<_>>();
+            .map(space::Axis::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut system = space::CoordinateSystem::new(space.origin.clone(), axes);
 
-        let system = space::CoordinateSystem::new(space.origin.clone(), axes);
+        if let Some(deformation) = &space.deformation {
+            let forward = space::DisplacementField::try_from(&deformation.forward)?;
+            let inverse = match &deformation.inverse {
+                Some(inverse) => space::DisplacementField::try_from(inverse)?,
+                None => forward.inverse(),
+            };
+
+            system = space::CoordinateSystem::deformed(system, forward, inverse)?;
+        }
 
-        space::Space::new(&space.name, system)
+        Ok(space::Space::new(&space.name, system))
     }
 }
 
@@ -360,10 +581,40 @@ impl From<&space::Space> for Space {
     fn from(space: &space::Space) -> Self {
         let axes = space.axes().iter().map(|a| a.into()).collect::<Vec<_>>();
 
+        let deformation = space.deformation().map(|(forward, inverse)| Deformation {
+            forward: forward.into(),
+            inverse: Some(inverse.into()),
+        });
+
         Space {
             name: space.name().clone(),
             origin: space.origin().into(),
             axes,
+            deformation,
+        }
+    }
+}
+
+impl TryFrom<&DisplacementField> for space::DisplacementField {
+    type Error = Error;
+
+    fn try_from(field: &DisplacementField) -> Result<Self, Self::Error> {
+        space::DisplacementField::new(
+            field.origin.clone(),
+            field.spacing.clone(),
+            field.dimensions.clone(),
+            field.displacements.clone(),
+        )
+    }
+}
+
+impl From<&space::DisplacementField> for DisplacementField {
+    fn from(field: &space::DisplacementField) -> Self {
+        DisplacementField {
+            origin: field.origin().to_vec(),
+            spacing: field.spacing().to_vec(),
+            dimensions: field.dimensions().to_vec(),
+            displacements: field.displacements().to_vec(),
         }
     }
 }
@@ -373,12 +624,149 @@ impl From<&&database::Properties> for Properties {
         Properties {
             type_name: p.type_name().to_string(),
             id: p.id().into(),
+            attributes: p.attributes().cloned().unwrap_or_default(),
         }
     }
 }
 
 pub use v1::SpatialObject;
 
+/// Spatial objects which can be indexed.
+pub trait Indexable {
+    /// The *identifier* of the object.
+    fn id(&self) -> &str;
+
+    /// List the positions covered by the object, as
+    /// (**Space Id**, *Spatial position*) tuples.
+    ///
+    /// # Parameters
+    ///
+    ///  * `spaces`:
+    ///      The reference spaces, used to rasterise volumes on their
+    ///      grid. Volumes defined in other spaces may be ignored.
+    fn positions(&self, spaces: &[space::Space]) -> Result<Vec<(String, space::Position)>, Error>;
+
+    /// Attributes of the object, which are attached to its
+    /// *identifier* in the index.
+    fn attributes(&self) -> Option<&database::Attributes> {
+        None
+    }
+}
+
+// Build the *identifier* of an object, as stored in the index.
+fn to_properties<O>(object: &O) -> database::Properties
+where
+    O: Indexable,
+{
+    let properties = database::Properties::feature(object.id());
+
+    match object.attributes() {
+        Some(attributes) => properties.with_attributes(attributes.clone()),
+        None => properties,
+    }
+}
+
+// Rasterise the box `[lower; higher]` on the grid of `space`.
+//
+// Each cell of the grid which overlaps the box, and for which `keep`
+// returns true is selected. `keep` receives the lower and higher corners
+// of the cell. The cells are returned as the position of their center,
+// so that encoding them yields back the same cell, except on axes of
+// integers, where their lower corner is exact and part of the set.
+fn rasterise<F>(
+    space: &space::Space,
+    lower: &[f64],
+    higher: &[f64],
+    keep: F,
+) -> Result<Vec<space::Position>, Error>
+where
+    F: Fn(&[f64], &[f64]) -> bool,
+{
+    let axes = space.axes();
+
+    for corner in &[lower, higher] {
+        if corner.len() != axes.len() {
+            return Err(Error::DimensionMismatch {
+                expected: axes.len(),
+                found: corner.len(),
+            });
+        }
+    }
+
+    let mut clamped = (vec![], vec![]);
+    for (k, axis) in axes.iter().enumerate() {
+        let graduation = axis.graduation();
+
+        if lower[k] > higher[k] {
+            return Err(Error::InvalidShape(format!(
+                "Lower corner {:?} is above higher corner {:?}",
+                lower, higher
+            )));
+        }
+
+        // Only the part of the box within the space can be indexed.
+        if higher[k] < graduation.minimum || lower[k] > graduation.maximum {
+            return Ok(vec![]);
+        }
+
+        clamped.0.push(lower[k].max(graduation.minimum));
+        clamped.1.push(higher[k].min(graduation.maximum));
+    }
+
+    // Cells containing the corners, which are not rounded to the set of
+    // numbers of the axes, as the corners are not positions themselves.
+    // The `steps` cells of an axis cover its whole range, so the
+    // maximum belongs to the last one.
+    let cell = |k: usize, value: f64| {
+        let graduation = axes[k].graduation();
+        let cell = ((value - graduation.minimum) / graduation.epsilon) as u64;
+
+        cell.min(graduation.steps.saturating_sub(1))
+    };
+
+    // The higher bound of the shape is excluded, so move it to the next
+    // cell, in order to keep the cells which contain the higher corner.
+    let lower = (0..axes.len())
+        .map(|k| cell(k, clamped.0[k]))
+        .collect::<Vec<_>>();
+    let higher = (0..axes.len())
+        .map(|k| cell(k, clamped.1[k]) + 1)
+        .collect::<Vec<_>>();
+
+    let cells = space::Shape::BoundingBox(lower.into(), higher.into()).rasterise()?;
+
+    let mut positions = vec![];
+    for cell in cells {
+        let mut low = vec![];
+        let mut high = vec![];
+        for (k, axis) in axes.iter().enumerate() {
+            let graduation = axis.graduation();
+            let l = graduation.minimum + cell[k].f64() * graduation.epsilon;
+
+            low.push(l);
+            high.push((l + graduation.epsilon).min(graduation.maximum));
+        }
+
+        if keep(&low, &high) {
+            let position = axes
+                .iter()
+                .enumerate()
+                .map(|(k, axis)| {
+                    if axis.graduation().set.is_integer() {
+                        low[k]
+                    } else {
+                        (low[k] + high[k]) / 2.0
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            positions.push(position.into());
+        }
+    }
+
+    Ok(positions)
+}
+
 /// Generate an index.
 ///
 /// # Parameters
@@ -394,7 +782,68 @@ pub use v1::SpatialObject;
 ///     space is known will be indexed.
 ///
 /// * `objects`:
-///     The data points to index.
+///     The objects to index, either [`v1`](v1/index.html) or
+///     [`v2`](v2/index.html) spatial objects.
+///
+/// * `scales`:
+///     An optional list of specific index resolutions to generates on
+///     top of the full resolution one.
+///
+/// * `max_elements`:
+///     If this is specified, automatically generates scaled indices, by
+///     halving the number elements between resolutions, and stop
+///     generating indices either when the number of points remaining is
+///     equal to the number of distinct Ids, or smaller or equal to this
+///     value.
+///
+/// **Note**: `max_elements` is ignored when `scales` is not `None`.
+pub fn build_index<O>(
+    name: &str,
+    version: &str,
+    spaces: &[space::Space],
+    objects: &[O],
+    scales: Option<Vec<Vec<u32>>>,
+    max_elements: Option<usize>,
+) -> Result<Core, Error>
+where
+    O: Indexable,
+{
+    let (properties, space_set_objects) = build_space_set_objects(objects, spaces)?;
+
+    Core::new(
+        name,
+        version,
+        spaces,
+        properties,
+        space_set_objects,
+        scales,
+        max_elements,
+    )
+}
+
+/// Generate an index, using a bounded amount of memory.
+///
+/// The objects are consumed one at a time, and the positions are
+/// sorted on disk to compute the resolutions of the index. The
+/// resulting index is the same as the one built by
+/// [`build_index`](fn.build_index.html).
+///
+/// # Parameters
+///
+/// * `name`:
+///     Name to give to the index.
+///
+/// * `version`:
+///     Parameter to distinguish revisions of an index.
+///
+/// * `spaces`:
+///     A list of the reference spaces. Only objects whose reference
+///     space is known will be indexed.
+///
+/// * `objects`:
+///     The objects to index, either [`v1`](v1/index.html) or
+///     [`v2`](v2/index.html) spatial objects. The first error aborts
+///     the build.
 ///
 /// * `scales`:
 ///     An optional list of specific index resolutions to generates on
@@ -407,15 +856,109 @@ pub use v1::SpatialObject;
 ///     equal to the number of distinct Ids, or smaller or equal to this
 ///     value.
 ///
+/// * `directory`:
+///     Location of the temporary files.
+///
+/// * `chunk_size`:
+///     Maximum number of positions held in memory while sorting.
+///
 /// **Note**: `max_elements` is ignored when `scales` is not `None`.
-pub fn build_index(
+#[allow(clippy::too_many_arguments)]
+pub fn build_index_external<O, I>(
     name: &str,
     version: &str,
     spaces: &[space::Space],
-    objects: &[SpatialObject],
+    objects: I,
     scales: Option<Vec<Vec<u32>>>,
     max_elements: Option<usize>,
-) -> Result<Core, String> {
+    directory: &Path,
+    chunk_size: usize,
+) -> Result<Core, Error>
+where
+    O: Indexable,
+    I: IntoIterator<Item = Result<O, Error>>,
+{
+    let objects = objects.into_iter().map(|object| {
+        let object = object?;
+        let positions = object
+            .positions(spaces)?
+            .into_iter()
+            // The value is set once all the properties are known.
+            .map(|(space_id, position)| SpaceSetObject::new(&space_id, position, 0))
+            .collect::<Vec<_>>();
+
+        Ok((to_properties(&object), positions))
+    });
+
+    Core::new_external(
+        name,
+        version,
+        spaces,
+        objects,
+        scales,
+        max_elements,
+        directory,
+        chunk_size,
+    )
+}
+
+/// Add objects to an existing index.
+///
+/// # Parameters
+///
+/// * `core`:
+///     The index to update.
+///
+/// * `spaces`:
+///     A list of the reference spaces. Only objects whose reference
+///     space is known will be indexed. All the reference spaces already
+///     used by `core` must be provided.
+///
+/// * `objects`:
+///     The objects to add to the index.
+pub fn insert_into<O>(core: &mut Core, spaces: &[space::Space], objects: &[O]) -> Result<(), Error>
+where
+    O: Indexable,
+{
+    let (properties, space_set_objects) = build_space_set_objects(objects, spaces)?;
+
+    core.insert(spaces, properties, space_set_objects)
+}
+
+/// Replace the positions of an object in an existing index.
+///
+/// # Parameters
+///
+/// * `core`:
+///     The index to update.
+///
+/// * `spaces`:
+///     A list of the reference spaces. Only positions whose reference
+///     space is known will be indexed. All the reference spaces already
+///     used by `core` must be provided.
+///
+/// * `object`:
+///     The object, whose `properties` must be already registered in
+///     `core`, with its new attributes and the new shapes associated
+///     to it.
+pub fn replace_in<O>(core: &mut Core, spaces: &[space::Space], object: &O) -> Result<(), Error>
+where
+    O: Indexable,
+{
+    let (_, space_set_objects) = build_space_set_objects(std::slice::from_ref(object), spaces)?;
+
+    core.replace(spaces, to_properties(object), space_set_objects)
+}
+
+// Convert the objects into a sorted list of properties, and a list of
+// positions linked to those properties by offset in the former list.
+fn build_space_set_objects<O>(
+    objects: &[O],
+    spaces: &[space::Space],
+) -> Result<(Vec<database::Properties>, Vec<SpaceSetObject>), Error>
+where
+    O: Indexable,
+{
     let mut properties = vec![];
     let mut space_set_objects = vec![];
     {
@@ -423,31 +966,21 @@ pub fn build_index(
         let mut properties_hm = HashMap::new();
 
         for object in objects {
-            let value = match properties_hm.get(object.properties.id.as_str()) {
+            let value = match properties_hm.get(object.id()) {
                 Some(_) => {
-                    properties_ref.push(object.properties.id.as_str());
+                    properties_ref.push(object.id());
                     properties_ref.len() - 1
                 }
                 None => {
-                    properties_hm.insert(
-                        object.properties.id.as_str(),
-                        database::Properties::Feature(object.properties.id.clone()),
-                    );
+                    properties_hm.insert(object.id(), to_properties(object));
 
-                    properties_ref.push(object.properties.id.as_str());
+                    properties_ref.push(object.id());
                     properties_ref.len() - 1
                 }
             };
 
-            for point in &object.shapes {
-                assert_eq!(point.type_name, "Point");
-
-                space_set_objects.push(SpaceSetObject::new(
-                    &point.reference_space,
-                    // Use a reference to prevent an allocation
-                    (&point.vertices[0]).into(),
-                    value,
-                ))
+            for (space_id, position) in object.positions(spaces)? {
+                space_set_objects.push(SpaceSetObject::new(&space_id, position, value))
             }
         }
 
@@ -464,13 +997,5 @@ pub fn build_index(
         });
     }
 
-    Core::new(
-        name,
-        version,
-        spaces,
-        properties,
-        space_set_objects,
-        scales,
-        max_elements,
-    )
+    Ok((properties, space_set_objects))
 }
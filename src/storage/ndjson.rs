@@ -0,0 +1,157 @@
+//! Newline-delimited JSON export
+//!
+//! Query results are written as one [`v2`] spatial object per line.
+//!
+//! Consecutive positions linked to the same *identifier* within the
+//! same reference space are grouped in a single object, so an
+//! *identifier* may appear on several lines. Each object is kept in
+//! memory until it is complete.
+//!
+//! ```txt
+//! {"properties":{"type":"Feature","id":"oid1","attributes":{}},"volumes":[{"space":"std","shapes":[{"points":[[0.2,0.5,0.1],[0.3,0.5,0.1]]}]}]}
+//! {"properties":{"type":"Feature","id":"oid2","attributes":{}},"volumes":[{"space":"std","shapes":[{"points":[[0.7,0.1,0.4]]}]}]}
+//! ```
+//!
+//! [`v2`]: ../model/v2/struct.SpatialObject.html
+
+use std::io::Write;
+
+use super::model;
+use crate::database;
+use crate::Error;
+use database::space::Position;
+
+// Write spatial objects, one point at a time.
+struct ObjectWriter<'w, W> {
+    writer: &'w mut W,
+    // Object currently being written, which holds a single volume.
+    current: Option<model::v2::SpatialObject>,
+}
+
+impl<'w, W> ObjectWriter<'w, W>
+where
+    W: Write,
+{
+    fn new(writer: &'w mut W) -> Self {
+        ObjectWriter {
+            writer,
+            current: None,
+        }
+    }
+
+    fn push(
+        &mut self,
+        space: &str,
+        position: &Position,
+        properties: &database::Properties,
+    ) -> Result<(), Error> {
+        let same = match &self.current {
+            Some(object) => {
+                let p = &object.properties;
+                object.volumes[0].space == space
+                    && p.id == properties.id()
+                    && p.type_name == properties.type_name()
+            }
+            None => false,
+        };
+
+        if !same {
+            self.close()?;
+
+            self.current = Some(model::v2::SpatialObject {
+                properties: model::Properties::from(&properties),
+                volumes: vec![model::v2::Volume {
+                    space: space.to_string(),
+                    shapes: vec![model::v2::Shape::Points(vec![])],
+                }],
+            });
+        }
+
+        if let Some(object) = &mut self.current {
+            if let model::v2::Shape::Points(points) = &mut object.volumes[0].shapes[0] {
+                points.push(position.into());
+            }
+        }
+
+        Ok(())
+    }
+
+    fn close(&mut self) -> Result<(), Error> {
+        if let Some(object) = self.current.take() {
+            if let Err(e) = serde_json::to_writer(&mut *self.writer, &object) {
+                return Err(Error::Serialization(e.to_string()));
+            }
+            self.writer.write_all(b"\n")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Write the results of a query as newline-delimited JSON.
+///
+/// The results are written as they are produced by the iterators.
+///
+/// # Parameters
+///
+///  * `writer`:
+///      Where to write the JSON data, it is recommended to use a
+///      buffered writer.
+///
+///  * `results`:
+///      The results of a query, such as
+///      [`Core::get_by_shape`](../../struct.Core.html#method.get_by_shape).
+pub fn write_objects<W>(
+    mut writer: W,
+    results: database::IterObjectsBySpaces<'_>,
+) -> Result<(), Error>
+where
+    W: Write,
+{
+    let mut objects_writer = ObjectWriter::new(&mut writer);
+
+    for (space, objects) in results {
+        for (position, properties) in objects {
+            objects_writer.push(space, &position, properties)?;
+        }
+    }
+    objects_writer.close()?;
+
+    Ok(writer.flush()?)
+}
+
+/// Write the positions of an *identifier* as newline-delimited JSON.
+///
+/// One object is written per reference space.
+///
+/// # Parameters
+///
+///  * `writer`:
+///      Where to write the JSON data, it is recommended to use a
+///      buffered writer.
+///
+///  * `properties`:
+///      The *identifier* the positions are linked to.
+///
+///  * `results`:
+///      The positions, as returned by
+///      [`Core::get_by_id`](../../struct.Core.html#method.get_by_id).
+pub fn write_positions<W>(
+    mut writer: W,
+    properties: &database::Properties,
+    results: Vec<(&String, database::IterPositions<'_>)>,
+) -> Result<(), Error>
+where
+    W: Write,
+{
+    let mut objects_writer = ObjectWriter::new(&mut writer);
+
+    for (space, positions) in results {
+        for position in positions {
+            objects_writer.push(space, &position, properties)?;
+        }
+    }
+    objects_writer.close()?;
+
+    Ok(writer.flush()?)
+}
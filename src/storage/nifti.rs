@@ -0,0 +1,596 @@
+//! # NIfTI file format
+//!
+//! This module reads label volumes stored in the [NIfTI-1] format,
+//! either as `.nii` files, or compressed as `.nii.gz` files.
+//!
+//! # Reference space
+//!
+//! The header of the file provides the affine transformation from the
+//! voxel offsets to the coordinates of the scanner, or template. The
+//! `sform` is used when it is set, otherwise the `qform`, and as a last
+//! resort, the voxel sizes.
+//!
+//! The reference space derived from it has one axis per dimension of
+//! the volume, following the columns of the transformation, with one
+//! *tick* per voxel. The encoded coordinates of a position are
+//! therefore the offsets of the voxel containing it, and the origin of
+//! the space is the outer corner of the first voxel.
+//!
+//! Rotations, as well as shears, are supported.
+//!
+//! # Labels
+//!
+//! Each distinct, non-zero value of the volume is a label. The voxels of
+//! a label are converted either to a [`v2`] spatial object, with one
+//! point at the center of each voxel, or to a [`Mask`] shape.
+//!
+//! [NIfTI-1]: https://nifti.nimh.nih.gov/nifti-1
+//! [`v2`]: ../model/v2/struct.SpatialObject.html
+//! [`Mask`]: ../../space/enum.Shape.html#variant.Mask
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::fs::File;
+use std::io::BufReader;
+use std::io::Read;
+use std::path::Path;
+
+use flate2::read::GzDecoder;
+
+use super::bincode::store;
+use super::bincode::Compression;
+use super::model;
+use super::model::v2;
+use crate::database::space;
+use crate::Error;
+
+const HEADER_SIZE: usize = 348;
+const HEADER_SIZE_V2: usize = 540;
+
+// First bytes of a gzip stream.
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+#[derive(Clone, Copy, Debug)]
+enum DataType {
+    U8,
+    I8,
+    U16,
+    I16,
+    U32,
+    I32,
+    U64,
+    I64,
+    F32,
+    F64,
+}
+
+impl DataType {
+    fn from_code(code: i16) -> Result<Self, Error> {
+        let t = match code {
+            2 => DataType::U8,
+            4 => DataType::I16,
+            8 => DataType::I32,
+            16 => DataType::F32,
+            64 => DataType::F64,
+            256 => DataType::I8,
+            512 => DataType::U16,
+            768 => DataType::U32,
+            1024 => DataType::I64,
+            1280 => DataType::U64,
+            _ => {
+                return Err(Error::UnsupportedFormat(format!(
+                    "NIfTI data type {} is not supported for label volumes",
+                    code
+                )))
+            }
+        };
+
+        Ok(t)
+    }
+
+    fn size(self) -> usize {
+        match self {
+            DataType::U8 | DataType::I8 => 1,
+            DataType::U16 | DataType::I16 => 2,
+            DataType::U32 | DataType::I32 | DataType::F32 => 4,
+            DataType::U64 | DataType::I64 | DataType::F64 => 8,
+        }
+    }
+}
+
+// Read numbers from the content of the file, with the byte order of the
+// file.
+#[derive(Clone, Copy)]
+struct Bytes<'a> {
+    bytes: &'a [u8],
+    big_endian: bool,
+}
+
+macro_rules! read {
+    ($name:ident, $t:ty) => {
+        fn $name(self, offset: usize) -> $t {
+            const SIZE: usize = std::mem::size_of::<$t>();
+            let mut b = [0; SIZE];
+            b.copy_from_slice(&self.bytes[offset..offset + SIZE]);
+
+            if self.big_endian {
+                <$t>::from_be_bytes(b)
+            } else {
+                <$t>::from_le_bytes(b)
+            }
+        }
+    };
+}
+
+impl Bytes<'_> {
+    read!(u8, u8);
+    read!(i8, i8);
+    read!(u16, u16);
+    read!(i16, i16);
+    read!(u32, u32);
+    read!(i32, i32);
+    read!(u64, u64);
+    read!(i64, i64);
+    read!(f32, f32);
+    read!(f64, f64);
+
+    fn value(self, offset: usize, data_type: DataType) -> f64 {
+        match data_type {
+            DataType::U8 => self.u8(offset) as f64,
+            DataType::I8 => self.i8(offset) as f64,
+            DataType::U16 => self.u16(offset) as f64,
+            DataType::I16 => self.i16(offset) as f64,
+            DataType::U32 => self.u32(offset) as f64,
+            DataType::I32 => self.i32(offset) as f64,
+            DataType::U64 => self.u64(offset) as f64,
+            DataType::I64 => self.i64(offset) as f64,
+            DataType::F32 => self.f32(offset) as f64,
+            DataType::F64 => self.f64(offset),
+        }
+    }
+}
+
+/// A label volume read from a NIfTI file.
+pub struct Volume {
+    space: space::Space,
+    dimensions: [usize; 3],
+    // Size of the voxels along each axis, in the unit of the axis.
+    voxel_size: [f64; 3],
+    data_type: DataType,
+    big_endian: bool,
+    // Scaling of the stored values, only applied when the slope is set.
+    slope: f64,
+    intercept: f64,
+    // Content of the file, with the voxels values starting at `offset`.
+    bytes: Vec<u8>,
+    offset: usize,
+}
+
+impl Volume {
+    /// Read a NIfTI file.
+    ///
+    /// # Parameters
+    ///
+    ///  * `path`:
+    ///      Location of the `.nii` or `.nii.gz` file.
+    ///
+    ///  * `space`:
+    ///      Id to give to the reference space of the volume.
+    pub fn open<P>(path: P, space: &str) -> Result<Self, Error>
+    where
+        P: AsRef<Path>,
+    {
+        let mut file = BufReader::new(File::open(path)?);
+        let mut bytes = vec![];
+        file.read_to_end(&mut bytes)?;
+
+        if bytes.starts_with(&GZIP_MAGIC) {
+            let mut decompressed = vec![];
+            GzDecoder::new(&bytes[..]).read_to_end(&mut decompressed)?;
+            bytes = decompressed;
+        }
+
+        Volume::from_bytes(bytes, space)
+    }
+
+    fn from_bytes(bytes: Vec<u8>, space: &str) -> Result<Self, Error> {
+        if bytes.len() < HEADER_SIZE {
+            return Err(Error::InvalidData(format!(
+                "NIfTI file too short: {} bytes",
+                bytes.len()
+            )));
+        }
+
+        // The size of the header tells us the byte order of the file.
+        let mut header = Bytes {
+            bytes: &bytes,
+            big_endian: false,
+        };
+        match header.i32(0) as usize {
+            HEADER_SIZE => (),
+            _ if (header.i32(0).swap_bytes() as usize) == HEADER_SIZE => header.big_endian = true,
+            HEADER_SIZE_V2 => {
+                return Err(Error::UnsupportedFormat("NIfTI-2 files".to_string()));
+            }
+            _ if (header.i32(0).swap_bytes() as usize) == HEADER_SIZE_V2 => {
+                return Err(Error::UnsupportedFormat("NIfTI-2 files".to_string()));
+            }
+            size => {
+                return Err(Error::InvalidData(format!(
+                    "Invalid NIfTI header size: {}",
+                    size
+                )));
+            }
+        }
+
+        match &bytes[344..348] {
+            b"n+1\0" => (),
+            b"ni1\0" => {
+                return Err(Error::UnsupportedFormat(
+                    "NIfTI header and image in separate files".to_string(),
+                ))
+            }
+            magic => {
+                return Err(Error::InvalidData(format!(
+                    "Invalid NIfTI magic: {:?}",
+                    magic
+                )))
+            }
+        }
+
+        // Label volumes have 3 dimensions at most, any other dimension
+        // must be of size 1.
+        let rank = header.i16(40);
+        if !(1..=7).contains(&rank) {
+            return Err(Error::InvalidData(format!(
+                "Invalid NIfTI number of dimensions: {}",
+                rank
+            )));
+        }
+
+        let mut dimensions = [1; 3];
+        for k in 1..=rank as usize {
+            let d = header.i16(40 + 2 * k);
+            if d < 1 {
+                return Err(Error::InvalidData(format!(
+                    "Invalid NIfTI size {} for dimension {}",
+                    d, k
+                )));
+            }
+
+            match k {
+                1..=3 => dimensions[k - 1] = d as usize,
+                _ if d > 1 => {
+                    return Err(Error::UnsupportedFormat(format!(
+                        "NIfTI volumes with {} dimensions",
+                        rank
+                    )))
+                }
+                _ => (),
+            }
+        }
+
+        let data_type = DataType::from_code(header.i16(70))?;
+
+        let offset = header.f32(108);
+        if !offset.is_finite() || offset < HEADER_SIZE as f32 {
+            return Err(Error::InvalidData(format!(
+                "Invalid NIfTI voxel offset: {}",
+                offset
+            )));
+        }
+        let offset = offset as usize;
+
+        let end = dimensions
+            .iter()
+            .try_fold(data_type.size(), |length, d| length.checked_mul(*d))
+            .and_then(|length| length.checked_add(offset));
+        match end {
+            Some(end) if end <= bytes.len() => (),
+            _ => {
+                return Err(Error::InvalidData(format!(
+                    "NIfTI file too short: {} bytes, for {:?} voxels from offset {}",
+                    bytes.len(),
+                    dimensions,
+                    offset
+                )))
+            }
+        }
+
+        let (slope, intercept) = match (header.f32(112), header.f32(116)) {
+            (s, i) if s != 0.0 && s.is_finite() && i.is_finite() => (s as f64, i as f64),
+            _ => (1.0, 0.0),
+        };
+
+        let (space, voxel_size) = Volume::space_from(header, space, &dimensions)?;
+        let big_endian = header.big_endian;
+
+        Ok(Volume {
+            space,
+            dimensions,
+            voxel_size,
+            data_type,
+            big_endian,
+            slope,
+            intercept,
+            bytes,
+            offset,
+        })
+    }
+
+    // Compute the affine transformation from voxel offsets to
+    // coordinates, as a list of columns, followed by the translation.
+    fn affine(header: Bytes) -> [[f64; 3]; 4] {
+        let pixdim = |k: usize| header.f32(76 + 4 * k) as f64;
+
+        if header.i16(254) > 0 {
+            // sform
+            let row = |r: usize| [0, 1, 2, 3].map(|c| header.f32(280 + 16 * r + 4 * c) as f64);
+            let rows = [row(0), row(1), row(2)];
+
+            [0, 1, 2, 3].map(|c| [rows[0][c], rows[1][c], rows[2][c]])
+        } else if header.i16(252) > 0 {
+            // qform, from the quaternion (a, b, c, d)
+            let (b, c, d) = (
+                header.f32(256) as f64,
+                header.f32(260) as f64,
+                header.f32(264) as f64,
+            );
+            let a = (1.0 - (b * b + c * c + d * d)).max(0.0).sqrt();
+            let qfac = if pixdim(0) < 0.0 { -1.0 } else { 1.0 };
+
+            // Columns of the rotation matrix.
+            let rotation = [
+                [
+                    a * a + b * b - c * c - d * d,
+                    2.0 * (b * c + a * d),
+                    2.0 * (b * d - a * c),
+                ],
+                [
+                    2.0 * (b * c - a * d),
+                    a * a + c * c - b * b - d * d,
+                    2.0 * (c * d + a * b),
+                ],
+                [
+                    2.0 * (b * d + a * c),
+                    2.0 * (c * d - a * b),
+                    a * a + d * d - c * c - b * b,
+                ],
+            ];
+            let scale = [pixdim(1), pixdim(2), pixdim(3) * qfac];
+
+            [
+                rotation[0].map(|v| v * scale[0]),
+                rotation[1].map(|v| v * scale[1]),
+                rotation[2].map(|v| v * scale[2]),
+                [
+                    header.f32(268) as f64,
+                    header.f32(272) as f64,
+                    header.f32(276) as f64,
+                ],
+            ]
+        } else {
+            [
+                [pixdim(1), 0.0, 0.0],
+                [0.0, pixdim(2), 0.0],
+                [0.0, 0.0, pixdim(3)],
+                [0.0, 0.0, 0.0],
+            ]
+        }
+    }
+
+    fn space_from(
+        header: Bytes,
+        name: &str,
+        dimensions: &[usize; 3],
+    ) -> Result<(space::Space, [f64; 3]), Error> {
+        let unit = match header.u8(123) & 0x07 {
+            // Millimeters are assumed when the unit is not specified.
+            0 | 2 => "mm",
+            1 => "m",
+            3 => "um",
+            u => {
+                return Err(Error::InvalidData(format!(
+                    "Invalid NIfTI spatial unit: {}",
+                    u
+                )))
+            }
+        };
+
+        let affine = Volume::affine(header);
+        let columns = &affine[..3];
+        let norm = |v: &[f64; 3]| v.iter().map(|x| x * x).sum::<f64>().sqrt();
+
+        let mut voxel_size = [0.0; 3];
+        for (k, column) in columns.iter().enumerate() {
+            voxel_size[k] = norm(column);
+
+            if voxel_size[k] <= 0.0 || !voxel_size[k].is_finite() {
+                return Err(Error::InvalidData(format!(
+                    "Invalid NIfTI transformation, axis {} has no length",
+                    k
+                )));
+            }
+        }
+
+        // The transformation gives the center of the first voxel, while
+        // the origin of the space is its outer corner.
+        let matrix = (0..3)
+            .map(|r| {
+                let mut row = columns.iter().map(|c| c[r]).collect::<Vec<_>>();
+                row.push(affine[3][r] - row.iter().sum::<f64>() / 2.0);
+                row
+            })
+            .collect::<Vec<_>>();
+
+        let graduations = dimensions
+            .iter()
+            .map(|d| space::Graduation::new(space::NumberSet::R, 0.0, *d as f64, *d as u64))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let system = space::CoordinateSystem::from_matrix(&matrix, unit, graduations)?;
+
+        Ok((space::Space::new(name, system), voxel_size))
+    }
+
+    /// The reference space of the volume.
+    pub fn space(&self) -> &space::Space {
+        &self.space
+    }
+
+    /// Number of voxels of the volume along each axis.
+    pub fn dimensions(&self) -> &[usize] {
+        &self.dimensions
+    }
+
+    // Call `f` with the offsets and the label of each voxel.
+    fn for_each<F>(&self, mut f: F) -> Result<(), Error>
+    where
+        F: FnMut([usize; 3], i64),
+    {
+        let data = Bytes {
+            bytes: &self.bytes,
+            big_endian: self.big_endian,
+        };
+        let size = self.data_type.size();
+        let mut offset = self.offset;
+
+        for k in 0..self.dimensions[2] {
+            for j in 0..self.dimensions[1] {
+                for i in 0..self.dimensions[0] {
+                    let value = data.value(offset, self.data_type) * self.slope + self.intercept;
+                    if value.fract() != 0.0 {
+                        return Err(Error::InvalidData(format!(
+                            "Invalid NIfTI label {} for voxel {:?}",
+                            value,
+                            [i, j, k]
+                        )));
+                    }
+
+                    f([i, j, k], value as i64);
+                    offset += size;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// List the distinct labels found in the volume, ignoring `0`.
+    pub fn labels(&self) -> Result<Vec<i64>, Error> {
+        let mut labels = BTreeSet::new();
+        self.for_each(|_, label| {
+            if label != 0 {
+                labels.insert(label);
+            }
+        })?;
+
+        Ok(labels.into_iter().collect())
+    }
+
+    /// Convert the volume to spatial objects.
+    ///
+    /// One object is generated per label, using the label as `id`, with
+    /// a point at the center of each of its voxels.
+    pub fn objects(&self) -> Result<Vec<v2::SpatialObject>, Error> {
+        let mut labels = BTreeMap::new();
+        self.for_each(|voxel, label| {
+            if label != 0 {
+                let point = (0..3)
+                    .map(|k| (voxel[k] as f64 + 0.5) * self.voxel_size[k])
+                    .collect::<model::Point>();
+                labels.entry(label).or_insert_with(Vec::new).push(point);
+            }
+        })?;
+
+        let objects = labels
+            .into_iter()
+            .map(|(label, points)| v2::SpatialObject {
+                properties: model::Properties {
+                    type_name: "Feature".to_string(),
+                    id: label.to_string(),
+                    attributes: Default::default(),
+                },
+                volumes: vec![v2::Volume {
+                    space: self.space.name().clone(),
+                    shapes: vec![v2::Shape::Points(points)],
+                }],
+            })
+            .collect();
+
+        Ok(objects)
+    }
+
+    /// Build a mask of the voxels of a label.
+    ///
+    /// The mask is expressed in encoded coordinates of the
+    /// [reference space](#method.space) of the volume, which has to be
+    /// known by the database to query it.
+    ///
+    /// # Parameters
+    ///
+    ///  * `label`:
+    ///      Value of the voxels to select.
+    pub fn mask(&self, label: i64) -> Result<space::Shape, Error> {
+        let mut voxels = space::Voxels::new(self.dimensions.to_vec());
+        let mut inserted = Ok(());
+        self.for_each(|voxel, l| {
+            if l == label && inserted.is_ok() {
+                inserted = voxels.insert(&voxel);
+            }
+        })?;
+        inserted?;
+
+        let vectors = (0..3)
+            .map(|i| {
+                (0..3)
+                    .map(|k| if i == k { 1.0 } else { 0.0 })
+                    .collect::<Vec<_>>()
+                    .into()
+            })
+            .collect();
+
+        Ok(space::Shape::Mask(vec![0u64; 3].into(), vectors, voxels))
+    }
+}
+
+/// Read a NIfTI file and convert it to the internal format for indexing.
+///
+/// The reference space of the volume, and one [`v2`] spatial object per
+/// label are stored, so that an index can be built with
+/// [`build`](../bincode/fn.build.html).
+///
+///  # Parameters
+///
+///  * `name`:
+///      Base name of the files,
+///       * `.nii` or `.nii.gz` will be automatically appended for the
+///         source file, while
+///       * `.spaces.bin` and `.objects.bin` will be appended for the
+///         output files.
+///
+///  * `space`:
+///      Id to give to the reference space of the volume.
+///
+/// [`v2`]: ../model/v2/struct.SpatialObject.html
+pub fn from(name: &str, space: &str) -> Result<(), Error> {
+    let fn_in = format!("{}.nii", name);
+    let fn_in = if Path::new(&fn_in).exists() {
+        fn_in
+    } else {
+        format!("{}.nii.gz", name)
+    };
+
+    let volume = Volume::open(&fn_in, space)?;
+
+    store(
+        vec![model::Space::from(volume.space())],
+        &format!("{}.spaces.bin", name),
+        Compression::None,
+    )?;
+    store(
+        volume.objects()?,
+        &format!("{}.objects.bin", name),
+        Compression::None,
+    )
+}
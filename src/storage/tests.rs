@@ -0,0 +1,1155 @@
+use std::convert::TryFrom;
+use std::fs;
+use std::path::Path;
+
+use serde::Serialize;
+
+use super::bincode::*;
+use super::model;
+use super::nifti;
+use super::xyz;
+use super::xyz::Calibration;
+use super::xyz::Options;
+use crate::database::space_index::SpaceSetIndex;
+use crate::database::space_index::SpaceSetObject;
+use crate::database::space_index::CELL_BITS;
+use crate::space::*;
+use crate::*;
+
+fn get_space() -> Space {
+    Space::new(
+        "s",
+        CoordinateSystem::new(
+            vec![0f64, 0f64],
+            vec![
+                Axis::new("m", vec![1f64, 0f64], NumberSet::R, 0f64, 100f64, 100).unwrap(),
+                Axis::new("m", vec![0f64, 1f64], NumberSet::R, 0f64, 100f64, 100).unwrap(),
+            ],
+        ),
+    )
+}
+
+fn get_object(id: &str, points: &[[f64; 2]]) -> model::SpatialObject {
+    model::SpatialObject {
+        properties: model::Properties {
+            type_name: "Feature".to_string(),
+            id: id.to_string(),
+            attributes: Attributes::new(),
+        },
+        shapes: points
+            .iter()
+            .map(|point| model::v1::Shape {
+                type_name: "Point".to_string(),
+                reference_space: "s".to_string(),
+                vertices: vec![point.to_vec()],
+            })
+            .collect(),
+    }
+}
+
+fn get_objects() -> Vec<model::SpatialObject> {
+    vec![
+        get_object("first", &[[1.0, 2.0], [3.0, 4.0]]),
+        get_object("second", &[[5.0, 6.0]]),
+    ]
+}
+
+// Path of a temporary file, unique to each test.
+fn get_file(name: &str) -> String {
+    let file = std::env::temp_dir().join(format!("mercator_db_{}", name));
+
+    file.to_str().unwrap().to_string()
+}
+
+fn store(file: &str) {
+    let spaces = vec![get_space()];
+    let core = model::build_index("core", "1", &spaces, &get_objects(), None, None).unwrap();
+
+    store_index(
+        Header::new(Some(vec![vec![0, 0]]), Some(10)),
+        &spaces,
+        &core,
+        file,
+        Compression::None,
+    )
+    .unwrap();
+}
+
+// Ids and sorted decoded positions of every object of an index file.
+fn get_content(file: &str) -> Vec<(String, Vec<Vec<f64>>)> {
+    get_db_content(&DataBase::load(&[file]).unwrap())
+}
+
+// Ids and sorted decoded positions of every object of the first dataset.
+fn get_db_content(db: &DataBase) -> Vec<(String, Vec<Vec<f64>>)> {
+    let parameters = CoreQueryParameters {
+        db,
+        output_space: None,
+        threshold_volume: None,
+        view_port: &None,
+        resolution: &None,
+        out_of_bounds: OutOfBounds::Clip,
+        dropped: None,
+    };
+    let core = db.core(&db.core_keys()[0]).unwrap();
+
+    core.keys()
+        .unwrap()
+        .iter()
+        .map(|properties| {
+            let mut positions = core
+                .get_by_id(&parameters, properties.id())
+                .unwrap()
+                .into_iter()
+                .flat_map(|(_, positions)| positions)
+                .map(|position| position.into())
+                .collect::<Vec<Vec<f64>>>();
+            positions.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+            (properties.id().to_string(), positions)
+        })
+        .collect()
+}
+
+fn get_expected() -> Vec<(String, Vec<Vec<f64>>)> {
+    vec![
+        ("first".to_string(), vec![vec![1.0, 2.0], vec![3.0, 4.0]]),
+        ("second".to_string(), vec![vec![5.0, 6.0]]),
+    ]
+}
+
+#[test]
+pub fn check_header() {
+    let file = get_file("check_header.index");
+    store(&file);
+
+    let header = load_header(&file).unwrap();
+    assert_eq!(header.format, INDEX_FORMAT);
+    assert_eq!(header.scales, Some(vec![vec![0, 0]]));
+    assert_eq!(header.max_elements, Some(10));
+    assert!(verify_index(&file).is_ok());
+    assert_eq!(get_content(&file), get_expected());
+
+    // Files written by newer versions are rejected.
+    let mut header = Header::new(None, None);
+    header.format = INDEX_FORMAT + 1;
+    let mut data = INDEX_MAGIC.to_vec();
+    data.extend(::bincode::serialize(&header).unwrap());
+    fs::write(&file, data).unwrap();
+
+    let result = load_header(&file);
+    fs::remove_file(&file).unwrap();
+
+    match result {
+        Err(Error::UnsupportedFormat(_)) => (),
+        r => panic!("Unexpected result: {:?}", r),
+    }
+}
+
+#[test]
+pub fn check_corrupted() {
+    let file = get_file("check_corrupted.index");
+    store(&file);
+
+    // Alter an identifier, which is stored in a section of the file.
+    let mut data = fs::read(&file).unwrap();
+    let offset = data.windows(6).position(|w| w == b"second").unwrap();
+    data[offset] = b'S';
+    fs::write(&file, data).unwrap();
+
+    let verified = verify_index(&file);
+    let loaded = DataBase::load(&[&file]);
+    let migrated = format!("{}.migrated", file);
+    let converted = migrate(&file, &migrated);
+    fs::remove_file(&file).unwrap();
+
+    match verified {
+        Err(Error::Corrupted(_)) => (),
+        r => panic!("Unexpected result: {:?}", r),
+    }
+
+    // Sections are checked before they are copied as-is.
+    assert!(!Path::new(&migrated).exists());
+    assert!(!Path::new(&format!("{}.tmp", migrated)).exists());
+    match converted {
+        Err(Error::Corrupted(_)) => (),
+        r => panic!("Unexpected result: {:?}", r),
+    }
+
+    // Sections are checked when they are decoded.
+    let db = loaded.unwrap();
+    match db.core("core").unwrap().keys() {
+        Err(Error::Corrupted(_)) => (),
+        r => panic!("Unexpected result: {:?}", r),
+    }
+}
+
+#[test]
+pub fn check_corrupted_spaces() {
+    let file = get_file("check_corrupted_spaces.index");
+    store(&file);
+    let data = fs::read(&file).unwrap();
+
+    // Alter the name of the reference space, which is stored outside of
+    // the sections of the file.
+    let mut spaces = data.clone();
+    let offset = spaces
+        .windows(9)
+        .position(|w| w == b"\x01\0\0\0\0\0\0\0s")
+        .unwrap();
+    spaces[offset + 8] = b'S';
+
+    // Alter the stopping condition recorded in the header.
+    let mut header = data;
+    let header_size = ::bincode::serialized_size(&load_header(&file).unwrap()).unwrap();
+    header[INDEX_MAGIC.len() + header_size as usize - 16] ^= 0x01;
+
+    for data in &[spaces, header] {
+        fs::write(&file, data).unwrap();
+
+        match DataBase::load(&[&file]).err() {
+            Some(Error::Corrupted(_)) => (),
+            r => panic!("Unexpected result: {:?}", r),
+        }
+
+        match verify_index(&file) {
+            Err(Error::Corrupted(_)) => (),
+            r => panic!("Unexpected result: {:?}", r),
+        }
+    }
+
+    fs::remove_file(&file).unwrap();
+}
+
+// Layout of the index files of format 0 and 1.
+#[derive(Serialize)]
+struct CoreV1 {
+    title: String,
+    version: String,
+    properties: Vec<Properties>,
+    space_db: Vec<(String, Vec<SpaceIndexV1>)>,
+}
+
+// Threshold volume, scale and index of a resolution.
+type SpaceIndexV1 = (f64, Vec<u32>, SpaceSetIndex);
+
+fn get_core_v1() -> CoreV1 {
+    let encode = |point: &[f64]| get_space().encode(point).unwrap();
+    let objects = [
+        SpaceSetObject::new("s", encode(&[1.0, 2.0]), 0),
+        SpaceSetObject::new("s", encode(&[3.0, 4.0]), 0),
+        SpaceSetObject::new("s", encode(&[5.0, 6.0]), 1),
+    ];
+    let index = SpaceSetIndex::new(objects.iter(), 2, CELL_BITS);
+
+    CoreV1 {
+        title: "core".to_string(),
+        version: "1".to_string(),
+        properties: vec![
+            Properties::Feature("first".to_string()),
+            Properties::Feature("second".to_string()),
+        ],
+        space_db: vec![("s".to_string(), vec![(10000.0, vec![0, 0], index)])],
+    }
+}
+
+fn check_migrate_from(file: &str) {
+    let migrated = format!("{}.migrated", file);
+    migrate(file, &migrated).unwrap();
+
+    let header = load_header(&migrated);
+    let verified = verify_index(&migrated);
+    let content = get_content(&migrated);
+    fs::remove_file(file).unwrap();
+    fs::remove_file(&migrated).unwrap();
+
+    assert_eq!(header.unwrap().format, INDEX_FORMAT);
+    assert!(verified.is_ok());
+    assert_eq!(content, get_expected());
+}
+
+#[test]
+pub fn check_migrate() {
+    let payload = ::bincode::serialize(&(vec![get_space()], get_core_v1())).unwrap();
+
+    // Format 0 files have no header.
+    let file = get_file("check_migrate_0.index");
+    fs::write(&file, &payload).unwrap();
+    assert_eq!(load_header(&file).unwrap().format, 0);
+    check_migrate_from(&file);
+
+    // Format 1 files have a header, with a FNV-1a checksum.
+    let file = get_file("check_migrate_1.index");
+    let mut header = Header::new(None, None);
+    header.format = 1;
+    header.checksum = 0xcbf2_9ce4_8422_2325;
+    for b in &payload {
+        header.checksum = (header.checksum ^ u64::from(*b)).wrapping_mul(0x0000_0100_0000_01b3);
+    }
+
+    let mut data = INDEX_MAGIC.to_vec();
+    data.extend(::bincode::serialize(&header).unwrap());
+    data.extend(&payload);
+    fs::write(&file, &data).unwrap();
+    assert!(verify_index(&file).is_ok());
+    check_migrate_from(&file);
+
+    // Format 1 files are checked before being converted.
+    let file = get_file("check_migrate_1_corrupted.index");
+    let last = data.len() - 1;
+    data[last] ^= 0xff;
+    fs::write(&file, &data).unwrap();
+    let result = migrate(&file, &format!("{}.migrated", file));
+    fs::remove_file(&file).unwrap();
+    assert!(!Path::new(&format!("{}.migrated", file)).exists());
+
+    match result {
+        Err(Error::Corrupted(_)) => (),
+        r => panic!("Unexpected result: {:?}", r),
+    }
+}
+
+fn get_volume(id: &str, shapes: Vec<model::v2::Shape>) -> model::v2::SpatialObject {
+    model::v2::SpatialObject {
+        properties: model::Properties {
+            type_name: "Feature".to_string(),
+            id: id.to_string(),
+            attributes: Attributes::new(),
+        },
+        volumes: vec![model::v2::Volume {
+            space: "s".to_string(),
+            shapes,
+        }],
+    }
+}
+
+#[test]
+pub fn check_rasterise() {
+    let spaces = vec![get_space()];
+    let objects = vec![
+        get_volume(
+            "box",
+            vec![model::v2::Shape::BoundingBoxes(vec![(
+                vec![1.0, 1.0],
+                vec![2.5, 1.5],
+            )])],
+        ),
+        // Only the cells closer than the radius to the center are kept.
+        get_volume(
+            "sphere",
+            vec![model::v2::Shape::HyperSpheres(vec![(
+                vec![10.0, 10.0],
+                1.2,
+            )])],
+        ),
+        // The part of the box outside of the space is ignored.
+        get_volume(
+            "outside",
+            vec![model::v2::Shape::BoundingBoxes(vec![(
+                vec![-10.0, 98.5],
+                vec![0.5, 120.0],
+            )])],
+        ),
+    ];
+    let core = model::build_index("core", "1", &spaces, &objects, None, None).unwrap();
+    let db = DataBase::new(spaces, vec![core]);
+
+    let mut sphere = vec![];
+    for x in 8..12 {
+        for y in 8..12 {
+            if (x == 8 || x == 11) && (y == 8 || y == 11) {
+                continue;
+            }
+            sphere.push(vec![x as f64, y as f64]);
+        }
+    }
+
+    assert_eq!(
+        get_db_content(&db),
+        vec![
+            ("box".to_string(), vec![vec![1.0, 1.0], vec![2.0, 1.0]]),
+            (
+                "outside".to_string(),
+                vec![vec![0.0, 98.0], vec![0.0, 99.0]]
+            ),
+            ("sphere".to_string(), sphere),
+        ]
+    );
+}
+
+#[test]
+pub fn check_rasterise_integers() {
+    // Two units between the ticks of the axes.
+    let axis = |v: Vec<f64>| Axis::new("m", v, NumberSet::N, 0.0, 100.0, 50).unwrap();
+    let spaces = vec![Space::new(
+        "s",
+        CoordinateSystem::new(
+            vec![0.0, 0.0],
+            vec![axis(vec![1.0, 0.0]), axis(vec![0.0, 1.0])],
+        ),
+    )];
+    let objects = vec![
+        get_volume(
+            "box",
+            vec![model::v2::Shape::BoundingBoxes(vec![(
+                vec![1.0, 1.0],
+                vec![2.5, 1.5],
+            )])],
+        ),
+        get_volume(
+            "sphere",
+            vec![model::v2::Shape::HyperSpheres(vec![(
+                vec![10.0, 10.0],
+                1.2,
+            )])],
+        ),
+        // The maximum belongs to the last cell of the axes.
+        get_volume(
+            "corner",
+            vec![model::v2::Shape::BoundingBoxes(vec![(
+                vec![97.0, 100.0],
+                vec![100.0, 100.0],
+            )])],
+        ),
+    ];
+    let core = model::build_index("core", "1", &spaces, &objects, None, None).unwrap();
+    let db = DataBase::new(spaces, vec![core]);
+
+    // Cells are indexed by their lower corner, which is an integer.
+    assert_eq!(
+        get_db_content(&db),
+        vec![
+            ("box".to_string(), vec![vec![0.0, 0.0], vec![2.0, 0.0]]),
+            (
+                "corner".to_string(),
+                vec![vec![96.0, 98.0], vec![98.0, 98.0]]
+            ),
+            (
+                "sphere".to_string(),
+                vec![
+                    vec![8.0, 8.0],
+                    vec![8.0, 10.0],
+                    vec![10.0, 8.0],
+                    vec![10.0, 10.0]
+                ]
+            ),
+        ]
+    );
+}
+
+#[test]
+pub fn check_unknown_shape() {
+    let spaces = vec![get_space()];
+    let mut object = get_object("first", &[[1.0, 2.0]]);
+    object.shapes[0].type_name = "Sphere".to_string();
+
+    match model::build_index("core", "1", &spaces, &[object], None, None) {
+        Err(Error::InvalidShape(_)) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+        Ok(_) => panic!("Unknown shape type accepted"),
+    }
+}
+
+// Results of a query over a 2-D and a 1-D reference spaces.
+fn get_results<'r>(
+    spaces: &'r [String; 2],
+    properties: &'r [Properties; 2],
+) -> IterObjectsBySpaces<'r> {
+    let first: IterObjects<'r> = Box::new(
+        vec![
+            (vec![1.0, 2.0].into(), &properties[0]),
+            (vec![3.5, 4.0].into(), &properties[0]),
+            (vec![5.0, 6.0].into(), &properties[1]),
+        ]
+        .into_iter(),
+    );
+    let second: IterObjects<'r> = Box::new(vec![(vec![7.0].into(), &properties[1])].into_iter());
+
+    vec![(&spaces[0], first), (&spaces[1], second)]
+}
+
+#[test]
+pub fn check_csv() {
+    let spaces = ["s".to_string(), "t,1".to_string()];
+    let properties = [
+        Properties::feature("a"),
+        Properties::unknown("b\"c", "Cell"),
+    ];
+
+    let mut output = vec![];
+    super::csv::write_objects(&mut output, get_results(&spaces, &properties)).unwrap();
+
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "space,x0,x1,type,id\n\
+         s,1,2,Feature,a\n\
+         s,3.5,4,Feature,a\n\
+         s,5,6,Cell,\"b\"\"c\"\n\
+         \"t,1\",7,,Cell,\"b\"\"c\"\n"
+    );
+}
+
+#[test]
+pub fn check_ndjson() {
+    let spaces = ["s".to_string(), "t".to_string()];
+    let properties = [Properties::feature("a"), Properties::unknown("b\n", "Cell")];
+
+    let mut output = vec![];
+    super::ndjson::write_objects(&mut output, get_results(&spaces, &properties)).unwrap();
+
+    let output = String::from_utf8(output).unwrap();
+    let lines = output.lines().collect::<Vec<_>>();
+
+    assert_eq!(
+        lines,
+        vec![
+            r#"{"properties":{"type":"Feature","id":"a","attributes":{}},"volumes":[{"space":"s","shapes":[{"points":[[1.0,2.0],[3.5,4.0]]}]}]}"#,
+            r#"{"properties":{"type":"Cell","id":"b\n","attributes":{}},"volumes":[{"space":"s","shapes":[{"points":[[5.0,6.0]]}]}]}"#,
+            r#"{"properties":{"type":"Cell","id":"b\n","attributes":{}},"volumes":[{"space":"t","shapes":[{"points":[[7.0]]}]}]}"#,
+        ]
+    );
+
+    // Each line is a valid v2 spatial object.
+    for line in lines {
+        let object: model::v2::SpatialObject = serde_json::from_str(line).unwrap();
+        assert_eq!(object.volumes.len(), 1);
+    }
+}
+
+// Affine transformation of a NIfTI header, as set in the header.
+enum Form {
+    None,
+    // Quaternion (b, c, d) and offset.
+    Qform([f32; 3], [f32; 3]),
+    // Rows of the transformation.
+    Sform([[f32; 4]; 3]),
+}
+
+// A 3 by 2 by 1 NIfTI-1 volume of 16 bits labels, with voxels of 2 by 1
+// by 1 millimeters.
+fn get_nifti(big_endian: bool, forms: &[Form]) -> Vec<u8> {
+    let mut bytes = vec![0u8; 352];
+    let mut put = |offset: usize, value: &[u8]| {
+        let mut value = value.to_vec();
+        if big_endian {
+            value.reverse();
+        }
+        bytes[offset..offset + value.len()].copy_from_slice(&value);
+    };
+
+    put(0, &348i32.to_le_bytes());
+    for (k, d) in [3i16, 3, 2, 1].iter().enumerate() {
+        put(40 + 2 * k, &d.to_le_bytes());
+    }
+    put(70, &4i16.to_le_bytes());
+    for (k, d) in [1f32, 2.0, 1.0, 1.0].iter().enumerate() {
+        put(76 + 4 * k, &d.to_le_bytes());
+    }
+    put(108, &352f32.to_le_bytes());
+
+    for form in forms {
+        match form {
+            Form::None => (),
+            Form::Qform(quaternion, offset) => {
+                put(252, &1i16.to_le_bytes());
+                for (k, v) in quaternion.iter().chain(offset).enumerate() {
+                    put(256 + 4 * k, &v.to_le_bytes());
+                }
+            }
+            Form::Sform(rows) => {
+                put(254, &1i16.to_le_bytes());
+                for (k, v) in rows.iter().flatten().enumerate() {
+                    put(280 + 4 * k, &v.to_le_bytes());
+                }
+            }
+        }
+    }
+
+    for label in &[0i16, 1, 1, 2, 0, 1] {
+        let mut label = label.to_le_bytes().to_vec();
+        if big_endian {
+            label.reverse();
+        }
+        bytes.extend(label);
+    }
+    bytes[344..348].copy_from_slice(b"n+1\0");
+
+    bytes
+}
+
+fn open_nifti(name: &str, bytes: &[u8]) -> nifti::Volume {
+    let file = get_file(name);
+    fs::write(&file, bytes).unwrap();
+    let volume = nifti::Volume::open(&file, "v").unwrap();
+    fs::remove_file(&file).unwrap();
+
+    volume
+}
+
+// Coordinates, in the universe, of the outer corners of the voxels.
+fn get_corners(volume: &nifti::Volume) -> Vec<Vec<f64>> {
+    [[0u64, 0, 0], [1, 0, 0], [0, 1, 0], [0, 0, 1]]
+        .iter()
+        .map(|corner| {
+            let p = Space::change_base(&corner.to_vec().into(), volume.space(), Space::universe());
+            let p: Vec<f64> = (&p.unwrap()).into();
+
+            // Ignore rounding errors of the single precision header.
+            p.iter().map(|v| (v * 1e4).round() / 1e4).collect()
+        })
+        .collect()
+}
+
+#[test]
+pub fn check_nifti_endianness() {
+    for big_endian in &[false, true] {
+        let volume = open_nifti("nifti_endianness", &get_nifti(*big_endian, &[Form::None]));
+
+        assert_eq!(volume.dimensions(), &[3, 2, 1]);
+        assert_eq!(volume.labels().unwrap(), vec![1, 2]);
+
+        let points = volume
+            .objects()
+            .unwrap()
+            .into_iter()
+            .map(|object| match &object.volumes[..] {
+                [model::v2::Volume { space, shapes }] => match &shapes[..] {
+                    [model::v2::Shape::Points(points)] => {
+                        assert_eq!(space, "v");
+                        (object.properties.id, points.clone())
+                    }
+                    s => panic!("Unexpected shapes: {:?}", s),
+                },
+                v => panic!("Unexpected volumes: {:?}", v),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            points,
+            vec![
+                (
+                    "1".to_string(),
+                    vec![
+                        vec![3.0, 0.5, 0.5],
+                        vec![5.0, 0.5, 0.5],
+                        vec![5.0, 1.5, 0.5]
+                    ]
+                ),
+                ("2".to_string(), vec![vec![1.0, 1.5, 0.5]]),
+            ]
+        );
+
+        match volume.mask(1).unwrap() {
+            Shape::Mask(_, _, voxels) => {
+                assert_eq!(voxels.len(), 3);
+                assert!(voxels.contains(&[1, 0, 0]));
+                assert!(voxels.contains(&[2, 0, 0]));
+                assert!(voxels.contains(&[2, 1, 0]));
+                assert!(!voxels.contains(&[0, 1, 0]));
+            }
+            s => panic!("Unexpected shape: {:?}", s),
+        }
+    }
+}
+
+#[test]
+pub fn check_nifti_transformation() {
+    let half = 0.5f32.sqrt();
+    // Rotation of a quarter turn around z, the centre of the first voxel
+    // being at [10, 20, 30] mm.
+    let qform = Form::Qform([0.0, 0.0, half], [10.0, 20.0, 30.0]);
+    let sform = Form::Sform([
+        [0.0, -1.0, 0.0, 10.0],
+        [2.0, 0.0, 0.0, 20.0],
+        [0.0, 0.0, 1.0, 30.0],
+    ]);
+    let identity = Form::Sform([
+        [2.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+    ]);
+
+    let corners = |forms: &[Form]| {
+        let little = get_corners(&open_nifti("nifti_form", &get_nifti(false, forms)));
+        let big = get_corners(&open_nifti("nifti_form", &get_nifti(true, forms)));
+        assert_eq!(little, big);
+
+        little
+    };
+
+    // Without transformation, the voxel sizes are used.
+    let expected = corners(&[Form::None]);
+    assert_eq!(corners(&[identity]), expected);
+
+    // The quaternion and the matrix describe the same transformation.
+    let expected = corners(&[sform]);
+    assert_ne!(expected, corners(&[Form::None]));
+    assert_eq!(corners(&[qform]), expected);
+
+    // The sform takes precedence over the qform.
+    let qform = Form::Qform([0.0, 0.0, 0.0], [0.0, 0.0, 0.0]);
+    let sform = Form::Sform([
+        [0.0, -1.0, 0.0, 10.0],
+        [2.0, 0.0, 0.0, 20.0],
+        [0.0, 0.0, 1.0, 30.0],
+    ]);
+    assert_eq!(corners(&[qform, sform]), expected);
+}
+
+#[test]
+pub fn check_nifti_scaling() {
+    let mut bytes = get_nifti(false, &[Form::None]);
+    bytes[112..116].copy_from_slice(&2f32.to_le_bytes());
+    bytes[116..120].copy_from_slice(&1f32.to_le_bytes());
+    let volume = open_nifti("nifti_scaling", &bytes);
+    assert_eq!(volume.labels().unwrap(), vec![1, 3, 5]);
+
+    // Labels have to be integers.
+    bytes[112..116].copy_from_slice(&0.5f32.to_le_bytes());
+    let volume = open_nifti("nifti_scaling", &bytes);
+    match volume.labels() {
+        Err(Error::InvalidData(_)) => (),
+        r => panic!("Unexpected result: {:?}", r),
+    }
+}
+
+#[test]
+pub fn check_nifti_gzip() {
+    use std::io::Write;
+
+    let bytes = get_nifti(false, &[Form::None]);
+    let mut encoder = flate2::write::GzEncoder::new(vec![], flate2::Compression::default());
+    encoder.write_all(&bytes).unwrap();
+    let volume = open_nifti("nifti.nii.gz", &encoder.finish().unwrap());
+
+    assert_eq!(volume.labels().unwrap(), vec![1, 2]);
+    assert_eq!(
+        get_corners(&volume),
+        get_corners(&open_nifti("nifti.nii", &bytes))
+    );
+}
+
+#[test]
+pub fn check_nifti_offset() {
+    let file = get_file("offset.nii");
+
+    for offset in &[f32::NAN, f32::INFINITY, 100.0, 353.0, 1e30] {
+        let mut bytes = get_nifti(false, &[Form::None]);
+        bytes[108..112].copy_from_slice(&offset.to_le_bytes());
+        fs::write(&file, &bytes).unwrap();
+
+        match nifti::Volume::open(&file, "v") {
+            Err(Error::InvalidData(_)) => (),
+            r => panic!("Unexpected result: {:?}", r.map(|_| ())),
+        }
+    }
+
+    fs::remove_file(&file).unwrap();
+}
+
+#[test]
+pub fn check_xyz_round_trip() {
+    let voxel = Calibration::default().voxel_size;
+    let spaces = ["WHS-Rat-um".to_string()];
+    let mut attributes = Attributes::new();
+    attributes.insert(xyz::COMMENT.to_string(), "26 0".to_string());
+    attributes.insert(xyz::COLOUR.to_string(), "0.5 0.25 1".to_string());
+    let properties = [
+        Properties::feature("Aar27s49").with_attributes(attributes.clone()),
+        Properties::feature("a b\nc%d"),
+    ];
+    let points = [
+        vec![voxel, 2.0 * voxel, -3.0 * voxel],
+        vec![0.0, 10.0 * voxel, 5.0 * voxel],
+    ];
+
+    let objects: IterObjects = Box::new(
+        points
+            .iter()
+            .zip(&properties)
+            .map(|(point, properties)| (point.clone().into(), properties)),
+    );
+    let mut output = vec![];
+    xyz::write_objects(
+        &mut output,
+        vec![(&spaces[0], objects)],
+        &Calibration::default(),
+    )
+    .unwrap();
+
+    let name = get_file("xyz_round_trip");
+    fs::write(format!("{}.xyz", name), output).unwrap();
+    xyz::from(&name, &Options::default()).unwrap();
+    let mut objects = load::<Vec<model::v1::SpatialObject>>(&format!("{}.bin", name)).unwrap();
+    fs::remove_file(format!("{}.xyz", name)).unwrap();
+    fs::remove_file(format!("{}.bin", name)).unwrap();
+
+    objects.sort_by(|a, b| a.properties.id.cmp(&b.properties.id));
+    assert_eq!(objects.len(), 2);
+    for (object, (properties, point)) in objects.iter().zip(properties.iter().zip(&points)) {
+        assert_eq!(object.properties.id, properties.id());
+        assert_eq!(object.shapes.len(), 1);
+        assert_eq!(object.shapes[0].reference_space, spaces[0]);
+        assert_eq!(object.shapes[0].vertices, vec![point.clone()]);
+    }
+    assert_eq!(objects[0].properties.attributes, attributes);
+    assert!(objects[1].properties.attributes.contains_key(xyz::COLOUR));
+    assert!(!objects[1].properties.attributes.contains_key(xyz::COMMENT));
+}
+
+// Layout of the spatial objects in data files of format 0.
+#[derive(Serialize)]
+struct PropertiesV0 {
+    type_name: String,
+    id: String,
+}
+
+#[derive(Serialize)]
+struct SpatialObjectV0 {
+    properties: PropertiesV0,
+    shapes: Vec<model::v1::Shape>,
+}
+
+#[test]
+pub fn check_legacy_objects() {
+    let objects = get_objects()
+        .into_iter()
+        .map(|object| SpatialObjectV0 {
+            properties: PropertiesV0 {
+                type_name: object.properties.type_name,
+                id: object.properties.id,
+            },
+            shapes: object.shapes,
+        })
+        .collect::<Vec<_>>();
+
+    let file = get_file("legacy.objects.bin");
+    fs::write(&file, bincode::serialize(&objects).unwrap()).unwrap();
+
+    let check = |loaded: Vec<model::SpatialObject>| {
+        let expected = get_objects();
+        assert_eq!(loaded.len(), expected.len());
+        for (l, e) in loaded.iter().zip(&expected) {
+            assert_eq!(l.properties.id, e.properties.id);
+            assert!(l.properties.attributes.is_empty());
+            assert_eq!(l.shapes.len(), e.shapes.len());
+        }
+    };
+    check(load(&file).unwrap());
+    check(load_iter(&file).unwrap().collect::<Result<_, _>>().unwrap());
+
+    // Files written by later versions are rejected.
+    let mut content = DATA_MAGIC.to_vec();
+    content.extend(bincode::serialize(&(DATA_FORMAT + 1, objects)).unwrap());
+    fs::write(&file, content).unwrap();
+    match load::<Vec<model::SpatialObject>>(&file) {
+        Err(Error::UnsupportedFormat(_)) => (),
+        r => panic!("Unexpected result: {:?}", r),
+    }
+    assert!(matches!(
+        load_iter::<model::SpatialObject>(&file),
+        Err(Error::UnsupportedFormat(_))
+    ));
+
+    fs::remove_file(&file).unwrap();
+}
+
+// Import a XYZ file, and sort the objects by id.
+fn get_xyz(name: &str, content: &[u8]) -> Vec<model::SpatialObject> {
+    let name = get_file(name);
+    fs::write(format!("{}.xyz", name), content).unwrap();
+    xyz::from(&name, &Options::default()).unwrap();
+    let mut objects = load::<Vec<model::SpatialObject>>(&format!("{}.bin", name)).unwrap();
+    fs::remove_file(format!("{}.xyz", name)).unwrap();
+    fs::remove_file(format!("{}.bin", name)).unwrap();
+
+    objects.sort_by(|a, b| a.properties.id.cmp(&b.properties.id));
+    for object in &mut objects {
+        object
+            .shapes
+            .sort_by(|a, b| a.vertices.partial_cmp(&b.vertices).unwrap());
+    }
+
+    objects
+}
+
+#[test]
+pub fn check_xyz_import_export() {
+    let content = "RGBA 1 0 0 1 # RGBA\n\
+                   244,623,248 # WHS Origin\n\
+                   246,653,440 # Bregma\n\
+                   \n\
+                   #Aar27s49 26 0\n\
+                   RGB 0.5 0.25 1\n\
+                   245 624 250\n\
+                   246 623 248\n\
+                   \n\
+                   #Aar27s48\n\
+                   250 620 240\n";
+    let imported = get_xyz("xyz_import", content.as_bytes());
+
+    // Colours and comments do not leak to the following identifiers.
+    let ids = imported
+        .iter()
+        .map(|o| (o.properties.id.as_str(), &o.properties.attributes))
+        .collect::<Vec<_>>();
+    let mut attributes = Attributes::new();
+    attributes.insert(xyz::COLOUR.to_string(), "0.5 0.25 1".to_string());
+    attributes.insert(xyz::COMMENT.to_string(), "26 0".to_string());
+    assert_eq!(
+        ids,
+        vec![("Aar27s48", &Attributes::new()), ("Aar27s49", &attributes)]
+    );
+
+    // One tick per voxel, around the WHS origin.
+    let axis = |v: Vec<f64>| Axis::new("mm", v, NumberSet::R, -10.0, 30.0, 1024).unwrap();
+    let spaces = vec![Space::new(
+        "WHS-Rat-um",
+        CoordinateSystem::new(
+            vec![0.0, 0.0, 0.0],
+            vec![
+                axis(vec![1.0, 0.0, 0.0]),
+                axis(vec![0.0, 1.0, 0.0]),
+                axis(vec![0.0, 0.0, 1.0]),
+            ],
+        ),
+    )];
+    let core = model::build_index("core", "1", &spaces, &imported, None, None).unwrap();
+    let db = DataBase::new(spaces, vec![core]);
+    let parameters = CoreQueryParameters {
+        db: &db,
+        output_space: None,
+        threshold_volume: None,
+        view_port: &None,
+        resolution: &None,
+        out_of_bounds: OutOfBounds::Reject,
+        dropped: None,
+    };
+
+    let mut output = vec![];
+    xyz::write_core(
+        &mut output,
+        db.core("core").unwrap(),
+        &parameters,
+        &Calibration::default(),
+    )
+    .unwrap();
+    let exported = get_xyz("xyz_export", &output);
+
+    assert_eq!(exported.len(), imported.len());
+    for (e, i) in exported.iter().zip(&imported) {
+        assert_eq!(e.properties.id, i.properties.id);
+        assert_eq!(
+            e.properties.attributes.get(xyz::COMMENT),
+            i.properties.attributes.get(xyz::COMMENT)
+        );
+        let vertices = |o: &model::SpatialObject| {
+            o.shapes
+                .iter()
+                .map(|s| s.vertices.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(vertices(e), vertices(i));
+    }
+    assert_eq!(exported[1].properties.attributes, attributes);
+}
+
+#[test]
+pub fn check_compressed() {
+    // Zstandard frames start with these bytes.
+    let compressed = |file: &str| {
+        fs::read(file)
+            .unwrap()
+            .starts_with(&[0x28, 0xb5, 0x2f, 0xfd])
+    };
+
+    let file = get_file("check_compressed.index");
+    let spaces = vec![get_space()];
+    let core = model::build_index("core", "1", &spaces, &get_objects(), None, None).unwrap();
+    store_index(
+        Header::new(None, Some(10)),
+        &spaces,
+        &core,
+        &file,
+        Compression::Zstd(0),
+    )
+    .unwrap();
+
+    assert!(compressed(&file));
+    assert_eq!(load_header(&file).unwrap().max_elements, Some(10));
+    verify_index(&file).unwrap();
+    assert_eq!(get_content(&file), get_expected());
+    fs::remove_file(&file).unwrap();
+
+    // Data files are read one element at a time as well.
+    let name = get_file("check_compressed");
+    super::bincode::store(
+        vec![model::Space::from(&spaces[0])],
+        &format!("{}.spaces.bin", name),
+        Compression::Zstd(3),
+    )
+    .unwrap();
+    super::bincode::store(
+        get_objects(),
+        &format!("{}.objects.bin", name),
+        Compression::Zstd(3),
+    )
+    .unwrap();
+    assert!(compressed(&format!("{}.objects.bin", name)));
+
+    let ids = load_iter::<model::SpatialObject>(&format!("{}.objects.bin", name))
+        .unwrap()
+        .map(|object| object.unwrap().properties.id)
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec!["first", "second"]);
+
+    let file = format!("{}.index", name);
+    build::<model::SpatialObject>(&name, "1", None, None, Compression::Zstd(3)).unwrap();
+    assert!(compressed(&file));
+    assert_eq!(get_content(&file), get_expected());
+
+    build_external::<model::SpatialObject>(
+        &name,
+        "1",
+        None,
+        None,
+        &std::env::temp_dir(),
+        1,
+        Compression::Zstd(3),
+    )
+    .unwrap();
+    assert!(compressed(&file));
+    assert_eq!(get_content(&file), get_expected());
+
+    for extension in &["spaces.bin", "objects.bin", "index"] {
+        fs::remove_file(format!("{}.{}", name, extension)).unwrap();
+    }
+}
+
+#[test]
+pub fn check_json_streaming() {
+    let name = get_file("check_json_streaming");
+    let (json, ndjson, bin) = (
+        format!("{}.json", name),
+        format!("{}.ndjson", name),
+        format!("{}.bin", name),
+    );
+
+    let objects = get_objects();
+    fs::write(&json, serde_json::to_vec(&objects).unwrap()).unwrap();
+    let lines = objects
+        .iter()
+        .map(|object| serde_json::to_string(object).unwrap() + "\n")
+        .collect::<String>();
+    fs::write(&ndjson, lines).unwrap();
+
+    // Streamed lists are written exactly as whole ones.
+    super::json::from::<Vec<model::SpatialObject>>(&name, Compression::None).unwrap();
+    let expected = fs::read(&bin).unwrap();
+    super::json::from_array::<model::SpatialObject>(&name, Compression::None).unwrap();
+    assert_eq!(fs::read(&bin).unwrap(), expected);
+    super::json::from_ndjson::<model::SpatialObject>(&name, Compression::None).unwrap();
+    assert_eq!(fs::read(&bin).unwrap(), expected);
+
+    super::json::from_array::<model::SpatialObject>(&name, Compression::Zstd(0)).unwrap();
+    let ids = load::<Vec<model::SpatialObject>>(&bin)
+        .unwrap()
+        .into_iter()
+        .map(|object| object.properties.id)
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec!["first", "second"]);
+    fs::remove_file(&bin).unwrap();
+
+    // Failed conversions only leave the temporary file.
+    fs::write(
+        &json,
+        r#"[{"properties":{"type":"Feature","id":"a"},"shapes":[]},{}]"#,
+    )
+    .unwrap();
+    assert!(super::json::from_array::<model::SpatialObject>(&name, Compression::None).is_err());
+    assert!(!Path::new(&bin).exists());
+
+    for file in &[json, ndjson, format!("{}.tmp", bin)] {
+        fs::remove_file(file).unwrap();
+    }
+}
+
+// Layout of the reference spaces in data files of format 0 and 1.
+#[derive(Serialize)]
+struct SpaceV1 {
+    name: String,
+    origin: Vec<f64>,
+    axes: Vec<model::Axis>,
+}
+
+#[test]
+pub fn check_legacy_spaces() {
+    let space = model::Space::from(&get_space());
+    let spaces = vec![SpaceV1 {
+        name: space.name.clone(),
+        origin: space.origin.clone(),
+        axes: space.axes.clone(),
+    }];
+
+    let file = get_file("legacy.spaces.bin");
+    let mut format_1 = DATA_MAGIC.to_vec();
+    format_1.extend(bincode::serialize(&(1u32, &spaces)).unwrap());
+
+    for content in &[bincode::serialize(&spaces).unwrap(), format_1] {
+        fs::write(&file, content).unwrap();
+
+        let loaded = load::<Vec<model::Space>>(&file).unwrap();
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].name, "s");
+        assert!(loaded[0].deformation.is_none());
+        assert_eq!(Space::try_from(&loaded[0]).unwrap(), get_space());
+    }
+
+    fs::remove_file(&file).unwrap();
+}
+
+#[test]
+pub fn check_legacy_number_set() {
+    let mut spaces = vec![model::Space::from(&get_space())];
+    spaces[0].axes[0].graduation.set = "N".to_string();
+    spaces[0].axes[0].graduation.minimum = -100.0;
+
+    // Axes of integers with negative bounds are rejected...
+    match Space::try_from(&spaces[0]) {
+        Err(Error::InvalidDefinition(_)) => (),
+        r => panic!("Unexpected result: {:?}", r),
+    }
+
+    // ...unless they are read from files written before the sets were
+    // enforced.
+    let file = get_file("legacy_set.spaces.bin");
+    let mut content = DATA_MAGIC.to_vec();
+    content.extend(bincode::serialize(&(2u32, &spaces)).unwrap());
+    fs::write(&file, content).unwrap();
+
+    let loaded = load::<Vec<model::Space>>(&file).unwrap();
+    assert_eq!(loaded[0].axes[0].graduation.set, "R");
+    assert_eq!(loaded[0].axes[1].graduation.set, "R");
+    assert!(Space::try_from(&loaded[0]).is_ok());
+
+    fs::remove_file(&file).unwrap();
+}
+
+#[test]
+pub fn check_invalid_deformation() {
+    let mut space = model::Space::from(&get_space());
+    space.deformation = Some(model::Deformation {
+        forward: model::DisplacementField {
+            origin: vec![0.0, 0.0],
+            spacing: vec![1.0, 1.0],
+            dimensions: vec![2, 2],
+            // One vector is missing.
+            displacements: vec![0.0; 6],
+        },
+        inverse: None,
+    });
+
+    match Space::try_from(&space) {
+        Err(Error::InvalidDefinition(_)) => (),
+        r => panic!("Unexpected result: {:?}", r),
+    }
+}
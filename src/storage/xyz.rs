@@ -21,7 +21,7 @@
 //!    in Voxel coordinates.
 //!  * `[X],[Y],[Z] # Bregma`: same as above, for another reference
 //!    space.
-//!  * `SCALE [F]`: **TBC** Size of the voxels.
+//!  * `SCALE [F]`: Size of the voxels, in millimeters.
 //!
 //! The rest of the file contains (one per line):
 //!  * coordinate triplets (x, y and z), each  representing one point
@@ -102,34 +102,218 @@
 //!  * `[x​q​, y​q, z​q 1]`​ are MeshView coordinates for the **WHS Rat 39 μm**
 //!    package (RAS directions, expressed in 39.0625 μm voxels).
 //!
+//! Other atlases and resolutions are supported by the [`Options`] of
+//! the conversion, which select the reference space, the voxel size,
+//! and the origin, either the `WHS Origin` or `Bregma`.
 //!
+//! # Export
 //!
+//! Query results can be written back to XYZ files, using the inverse of
+//! the conversion above, as described by a [`Calibration`]. The
+//! positions must therefore be expressed in millimeters, in a reference
+//! space with the same orientation as the WHS, which can be selected
+//! with the `output_space` of the query parameters.
+//!
+//! The points of each *identifier* are written after a comment line
+//! with the *identifier*, and a `RGB` line with its colour, or a
+//! distinct one when it has none.
+//!
+//! The comment line starts with the `id_prefix` of the calibration, the
+//! whitespace and `%` characters of the *identifier* being
+//! percent-encoded, so that the file can be imported again with the
+//! same `id_prefix`.
+//!
+//! # Attributes
+//!
+//! The colour of the points, given by the `RGB` line following the
+//! comment line of the *identifier*, and the values following the
+//! *identifier* on that line, such as `26 0` in `#Aar27s49 26 0`, are
+//! kept as the [`COLOUR`] and [`COMMENT`] attributes of the
+//! *identifier*, and written back on export.
+//!
+//! [`Calibration`]: struct.Calibration.html
+//! [`COLOUR`]: constant.COLOUR.html
+//! [`COMMENT`]: constant.COMMENT.html
+//! [`Options`]: struct.Options.html
 //! [MeshView]: http://www.nesys.uio.no/MeshView/meshview.html?atlas=WHS_SD_rat_atlas_v2
 //! [University of Oslo]: https://www.med.uio.no/imb/english/research/groups/neural-systems/index.html
 //! [Waxholm Space Atlas]: https://www.nitrc.org/projects/whs-sd-atlas
 
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::BufReader;
-use std::io::Error;
-use std::io::ErrorKind;
 use std::io::Read;
+use std::io::Write;
 
 use super::bincode::store;
+use super::bincode::Compression;
 use super::model::v1::Shape;
 use super::model::v1::SpatialObject;
 use super::model::Properties;
+use crate::database;
+use crate::Error;
+use database::space::Position;
+use database::Attributes;
+
+/// Name of the attribute storing the colour of the points, as the
+/// space-separated values of the `RGB` or `RGBA` directive.
+pub const COLOUR: &str = "colour";
+
+/// Name of the attribute storing the values following the *identifier*
+/// on its comment line.
+pub const COMMENT: &str = "comment";
+
+/// Calibration of the voxel coordinates of a XYZ file.
+#[derive(Clone, Debug)]
+pub struct Calibration {
+    /// Position of the WHS origin, in voxels.
+    pub origin: [f64; 3],
+
+    /// Position of Bregma, in voxels.
+    pub bregma: [f64; 3],
+
+    /// Size of the voxels, in millimeters.
+    pub voxel_size: f64,
+
+    /// Prefix of the comment lines which provide the *identifier* of
+    /// the following points, see [`Options`](struct.Options.html).
+    pub id_prefix: String,
+}
+
+impl Default for Calibration {
+    /// Calibration of the **WHS Rat 39 μm** package.
+    fn default() -> Self {
+        Calibration {
+            origin: [244.0, 623.0, 248.0],
+            bregma: [246.0, 653.0, 440.0],
+            voxel_size: 0.039_062_5,
+            id_prefix: "#".to_string(),
+        }
+    }
+}
+
+/// Origin of the coordinates of the imported points.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Origin {
+    /// Waxholm Space origin, as given by the `WHS Origin` directive.
+    Whs,
+
+    /// Bregma, as given by the `Bregma` directive.
+    Bregma,
+}
+
+/// Options of the conversion of XYZ files.
+///
+/// The default values convert files of the **WHS Rat 39 μm** package.
+#[derive(Clone, Debug)]
+pub struct Options {
+    /// Id of the reference space of the points.
+    pub space: String,
+
+    /// Size of the voxels, in millimeters. When `None`, the value of
+    /// the `SCALE` directive of the file is used.
+    pub voxel_size: Option<f64>,
+
+    /// Origin to use for the coordinates.
+    pub origin: Origin,
+
+    /// Prefix of the comment lines which provide the *identifier* of
+    /// the following points, for example `#`.
+    ///
+    /// The *identifier* is the rest of the first word of the line, so
+    /// this has to match the `id_prefix` of the
+    /// [`Calibration`](struct.Calibration.html) used to write the file.
+    pub id_prefix: String,
+
+    /// **Type** of the *identifiers*.
+    pub type_name: String,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            space: "WHS-Rat-um".to_string(),
+            voxel_size: Some(Calibration::default().voxel_size),
+            origin: Origin::Whs,
+            id_prefix: Calibration::default().id_prefix,
+            type_name: "Feature".to_string(),
+        }
+    }
+}
 
-fn convert(string: &str) -> Result<Vec<SpatialObject>, Error> {
+// Parse a `[X],[Y],[Z]` voxel position.
+fn parse_voxel(value: &str) -> Result<Vec<f64>, Error> {
+    let t = value
+        .split(',')
+        .map(|s| s.parse::<f64>())
+        .collect::<Result<Vec<_>, _>>();
+
+    match t {
+        Ok(t) if t.len() == 3 => Ok(t),
+        _ => Err(Error::InvalidData(format!(
+            "Invalid voxel position {:?}",
+            value
+        ))),
+    }
+}
+
+// Identifiers are written as the first word of a line, so whitespace is
+// percent-encoded, as well as `%` itself.
+fn escape(id: &str) -> Cow<'_, str> {
+    if !id.chars().any(|c| c == '%' || c.is_whitespace()) {
+        return Cow::Borrowed(id);
+    }
+
+    let mut escaped = String::with_capacity(id.len());
+    for c in id.chars() {
+        if c == '%' || c.is_whitespace() {
+            let mut bytes = [0; 4];
+            for b in c.encode_utf8(&mut bytes).bytes() {
+                escaped.push_str(&format!("%{:02X}", b));
+            }
+        } else {
+            escaped.push(c);
+        }
+    }
+
+    Cow::Owned(escaped)
+}
+
+fn unescape(id: &str) -> Result<String, Error> {
+    let invalid = || Error::InvalidData(format!("Invalid identifier {:?}", id));
+
+    let mut bytes = Vec::with_capacity(id.len());
+    let mut rest = id.as_bytes();
+    while let Some((b, tail)) = rest.split_first() {
+        rest = if *b == b'%' {
+            let hex = tail.get(..2).ok_or_else(invalid)?;
+            let hex = std::str::from_utf8(hex).map_err(|_| invalid())?;
+            bytes.push(u8::from_str_radix(hex, 16).map_err(|_| invalid())?);
+            &tail[2..]
+        } else {
+            bytes.push(*b);
+            tail
+        };
+    }
+
+    String::from_utf8(bytes).map_err(|_| invalid())
+}
+
+fn convert(string: &str, options: &Options) -> Result<Vec<SpatialObject>, Error> {
     // Read manually the XYZ file, as this is a simple format.
     // Read line by line, skip all line we don't know how to parse, for the
     // remaining ones do:
-    //  * lines starting with '#A' we update the current point ID
+    //  * lines starting with the id prefix update the current point ID
     //  * lines we can parse as triplet fo f64, add a position to the list,
     //     under the oid key.
     let mut oids = HashMap::new();
     let mut oid = None;
-    let mut origin = vec![];
+    let mut colour = None;
+    let mut comment = None;
+    let mut whs = None;
+    let mut bregma = None;
+    let mut scale = options.voxel_size;
     for line in string.lines() {
         let values = line.split_whitespace().collect::<Vec<_>>();
 
@@ -139,33 +323,62 @@ fn convert(string: &str) -> Result<Vec<SpatialObject>, Error> {
         }
 
         match values[0] {
-            "RGBA" => (),
-            "RGB" => (),
-            "SCALE" => (),
-            _ if values[0].starts_with("#A") => {
+            "RGBA" | "RGB" => {
+                // Keep the colour values, without the trailing comment.
+                let c = values[1..]
+                    .iter()
+                    .take_while(|v| !v.starts_with('#'))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                colour = Some(c.join(" "));
+            }
+            "SCALE" => {
+                if options.voxel_size.is_none() {
+                    scale = match values.get(1).map(|v| v.parse::<f64>()) {
+                        Some(Ok(v)) if v > 0.0 => Some(v),
+                        _ => {
+                            return Err(Error::InvalidData(format!(
+                                "Invalid SCALE directive {:?}",
+                                line
+                            )))
+                        }
+                    };
+                    trace!("SCALE FOUND: {:?}", scale);
+                }
+            }
+            _ if values[0].starts_with(&options.id_prefix) => {
                 // Update the oid value.
-                oid = Some(values[0].trim_start_matches('#').to_string());
-                trace!("FOUND OID {:?}", oid);
+                let id = &values[0][options.id_prefix.len()..];
+                oid = if id.is_empty() {
+                    None
+                } else {
+                    Some(unescape(id)?)
+                };
+                // The colour applies from the following RGB line only.
+                colour = None;
+                comment = match values[1..].join(" ") {
+                    c if c.is_empty() => None,
+                    c => Some(c),
+                };
+                trace!("FOUND OID {:?}, comment {:?}", oid, comment);
             }
             _ if line.contains("WHS") => {
                 // Store the voxel offset value
-                let t: Vec<_> = values[0]
-                    .split(',')
-                    .filter_map(|s| match s.parse::<f64>() {
-                        Err(_) => None,
-                        Ok(v) => Some(v),
-                    })
-                    .collect();
-
-                if t.len() == 3 && origin.is_empty() {
-                    origin = t;
-                } else {
-                    return Err(Error::new(
-                        ErrorKind::InvalidData,
-                        format!("Invalid WHS origin new {:?}, current {:?}", t, origin),
-                    ));
+                if whs.is_some() {
+                    return Err(Error::InvalidData(format!(
+                        "Duplicate WHS origin {:?}",
+                        line
+                    )));
                 }
-                trace!("ORIGIN FOUND: {:?}", origin);
+                whs = Some(parse_voxel(values[0])?);
+                trace!("ORIGIN FOUND: {:?}", whs);
+            }
+            _ if line.contains("Bregma") => {
+                if bregma.is_some() {
+                    return Err(Error::InvalidData(format!("Duplicate Bregma {:?}", line)));
+                }
+                bregma = Some(parse_voxel(values[0])?);
+                trace!("BREGMA FOUND: {:?}", bregma);
             }
             _ if values.len() == 3 => {
                 // Check we have an oid to register the position under first.
@@ -176,12 +389,44 @@ fn convert(string: &str) -> Result<Vec<SpatialObject>, Error> {
 
                 if let (Some(oid), Ok(x), Ok(y), Ok(z)) = (oid.clone(), x, y, z) {
                     trace!("after (oid, x, y, z) = {:?}", (&oid, &x, &y, &z));
+                    let origin = match (&options.origin, &whs, &bregma) {
+                        (Origin::Whs, Some(origin), _) => origin,
+                        (Origin::Bregma, _, Some(origin)) => origin,
+                        (origin, _, _) => {
+                            return Err(Error::InvalidData(format!(
+                                "Missing {:?} origin before the first point",
+                                origin
+                            )))
+                        }
+                    };
+                    let scale = match scale {
+                        Some(scale) => scale,
+                        None => {
+                            return Err(Error::InvalidData(
+                                "Missing SCALE before the first point".to_string(),
+                            ))
+                        }
+                    };
+
                     // We need to convert these voxel values into mm-s
                     let (x, y, z) = (x - origin[0], y - origin[1], z - origin[2]);
-                    let (x, y, z) = (x * 0.039_062_5, y * 0.039_062_5, z * 0.039_062_5);
+                    let (x, y, z) = (x * scale, y * scale, z * scale);
 
+                    // The attributes of an identifier are the ones found
+                    // with its first point.
                     oids.entry(oid)
-                        .or_insert_with(Vec::new)
+                        .or_insert_with(|| {
+                            let mut attributes = Attributes::new();
+                            if let Some(colour) = &colour {
+                                attributes.insert(COLOUR.to_string(), colour.clone());
+                            }
+                            if let Some(comment) = &comment {
+                                attributes.insert(COMMENT.to_string(), comment.clone());
+                            }
+
+                            (attributes, vec![])
+                        })
+                        .1
                         .push(vec![x, y, z]);
                 }
             }
@@ -192,17 +437,18 @@ fn convert(string: &str) -> Result<Vec<SpatialObject>, Error> {
     // Transform the points into SpatialObjects
     Ok(oids
         .drain()
-        .map(|(k, v)| {
+        .map(|(k, (attributes, v))| {
             let properties = Properties {
-                type_name: "Feature".to_string(),
+                type_name: options.type_name.clone(),
                 id: k,
+                attributes,
             };
 
             let shapes = v
                 .into_iter()
                 .map(|position| Shape {
                     type_name: "Point".to_string(),
-                    reference_space: "WHS-Rat-um".to_string(),
+                    reference_space: options.space.clone(),
                     vertices: vec![position],
                 })
                 .collect();
@@ -223,7 +469,10 @@ fn convert(string: &str) -> Result<Vec<SpatialObject>, Error> {
 ///      Base name of the file,
 ///       * `.xyz` will be automatically appended for the source file, while
 ///       * `.bin` will be appended for the output file.
-pub fn from(name: &str) -> Result<(), Error> {
+///
+///  * `options`:
+///      How to convert the points, see [`Options`](struct.Options.html).
+pub fn from(name: &str, options: &Options) -> Result<(), Error> {
     let fn_in = format!("{}.xyz", name);
     let fn_out = format!("{}.bin", name);
 
@@ -231,7 +480,245 @@ pub fn from(name: &str) -> Result<(), Error> {
     let mut string = String::new();
     file_in.read_to_string(&mut string)?;
 
-    let v = convert(&string)?;
+    let v = convert(&string, options)?;
+
+    store(v, &fn_out, Compression::None)
+}
+
+// Pick the colour of the n-th identifier, by rotating the hue by the
+// golden angle, so that consecutive identifiers are easy to distinguish.
+fn colour(n: usize) -> [f64; 3] {
+    let hue = (n as f64 * 0.618_033_988_749_895).fract() * 6.0;
+    let (saturation, value) = (0.75, 0.9);
+
+    let chroma = value * saturation;
+    let x = chroma * (1.0 - (hue % 2.0 - 1.0).abs());
+    let (r, g, b) = match hue as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = value - chroma;
+
+    [r + m, g + m, b + m]
+}
+
+// Write the points of each identifier, converted to voxel coordinates.
+struct XyzWriter<'w, W> {
+    writer: &'w mut W,
+    calibration: &'w Calibration,
+    // Number of identifiers written so far.
+    count: usize,
+}
+
+impl<'w, W> XyzWriter<'w, W>
+where
+    W: Write,
+{
+    fn new(writer: &'w mut W, calibration: &'w Calibration) -> Result<Self, Error> {
+        let [x, y, z] = calibration.origin;
+        let [bx, by, bz] = calibration.bregma;
+
+        writeln!(writer, "RGBA 1 0 0 1 # RGBA")?;
+        writeln!(writer, "{},{},{} # WHS Origin", x, y, z)?;
+        writeln!(writer, "{},{},{} # Bregma", bx, by, bz)?;
+
+        Ok(XyzWriter {
+            writer,
+            calibration,
+            count: 0,
+        })
+    }
+
+    fn id(&mut self, properties: &database::Properties) -> Result<(), Error> {
+        let attributes = properties.attributes();
+        let attribute = |name: &str| attributes.and_then(|a| a.get(name));
+
+        // The comment is read back as space-separated values.
+        writeln!(self.writer)?;
+        write!(
+            self.writer,
+            "{}{}",
+            self.calibration.id_prefix,
+            escape(properties.id())
+        )?;
+        if let Some(comment) = attribute(COMMENT) {
+            for value in comment.split_whitespace() {
+                write!(self.writer, " {}", value)?;
+            }
+        }
+        writeln!(self.writer)?;
+
+        // Keep the colour of the identifier, without its alpha value.
+        let rgb = attribute(COLOUR)
+            .map(|c| c.split_whitespace().take(3).collect::<Vec<_>>())
+            .filter(|c| c.len() == 3);
+        match rgb {
+            Some(rgb) => writeln!(self.writer, "RGB {}", rgb.join(" "))?,
+            None => {
+                let [r, g, b] = colour(self.count);
+                writeln!(self.writer, "RGB {} {} {}", r, g, b)?;
+            }
+        }
+        self.count += 1;
+
+        Ok(())
+    }
+
+    fn point(&mut self, position: &Position) -> Result<(), Error> {
+        let p: Vec<f64> = position.into();
+        if p.len() != 3 {
+            return Err(Error::DimensionMismatch {
+                expected: 3,
+                found: p.len(),
+            });
+        }
+
+        let Calibration {
+            origin, voxel_size, ..
+        } = self.calibration;
+        writeln!(
+            self.writer,
+            "{} {} {}",
+            p[0] / voxel_size + origin[0],
+            p[1] / voxel_size + origin[1],
+            p[2] / voxel_size + origin[2]
+        )?;
+
+        Ok(())
+    }
+}
+
+/// Write the results of a query as a XYZ file.
+///
+/// The positions are grouped by *identifier*, so they are all held in
+/// memory before being written.
+///
+/// # Parameters
+///
+///  * `writer`:
+///      Where to write the XYZ data, it is recommended to use a
+///      buffered writer.
+///
+///  * `results`:
+///      The results of a query, such as
+///      [`Core::get_by_shape`](../../struct.Core.html#method.get_by_shape).
+///
+///  * `calibration`:
+///      Conversion of the positions to voxel coordinates.
+pub fn write_objects<W>(
+    mut writer: W,
+    results: database::IterObjectsBySpaces<'_>,
+    calibration: &Calibration,
+) -> Result<(), Error>
+where
+    W: Write,
+{
+    // Keep the identifiers in the order they are found.
+    let mut offsets = HashMap::new();
+    let mut objects = vec![];
+    for (_, positions) in results {
+        for (position, properties) in positions {
+            let offset = *offsets
+                .entry((properties.type_name(), properties.id()))
+                .or_insert_with(|| {
+                    objects.push((properties, vec![]));
+                    objects.len() - 1
+                });
+            objects[offset].1.push(position);
+        }
+    }
+
+    let mut xyz_writer = XyzWriter::new(&mut writer, calibration)?;
+    for (properties, positions) in objects {
+        xyz_writer.id(properties)?;
+        for position in positions {
+            xyz_writer.point(&position)?;
+        }
+    }
+
+    Ok(writer.flush()?)
+}
+
+/// Write the positions of an *identifier* as a XYZ file.
+///
+/// # Parameters
+///
+///  * `writer`:
+///      Where to write the XYZ data, it is recommended to use a
+///      buffered writer.
+///
+///  * `properties`:
+///      The *identifier* the positions are linked to.
+///
+///  * `results`:
+///      The positions, as returned by
+///      [`Core::get_by_id`](../../struct.Core.html#method.get_by_id).
+///
+///  * `calibration`:
+///      Conversion of the positions to voxel coordinates.
+pub fn write_positions<W>(
+    mut writer: W,
+    properties: &database::Properties,
+    results: Vec<(&String, database::IterPositions<'_>)>,
+    calibration: &Calibration,
+) -> Result<(), Error>
+where
+    W: Write,
+{
+    let mut xyz_writer = XyzWriter::new(&mut writer, calibration)?;
+
+    xyz_writer.id(properties)?;
+    for (_, positions) in results {
+        for position in positions {
+            xyz_writer.point(&position)?;
+        }
+    }
+
+    Ok(writer.flush()?)
+}
+
+/// Write all the objects of a dataset as a XYZ file.
+///
+/// The positions are written one *identifier* at a time.
+///
+/// # Parameters
+///
+///  * `writer`:
+///      Where to write the XYZ data, it is recommended to use a
+///      buffered writer.
+///
+///  * `core`:
+///      The dataset to export.
+///
+///  * `parameters`:
+///      Search parameters, see
+///      [CoreQueryParameters](../../struct.CoreQueryParameters.html).
+///
+///  * `calibration`:
+///      Conversion of the positions to voxel coordinates.
+pub fn write_core<W>(
+    mut writer: W,
+    core: &database::Core,
+    parameters: &database::CoreQueryParameters,
+    calibration: &Calibration,
+) -> Result<(), Error>
+where
+    W: Write,
+{
+    let mut xyz_writer = XyzWriter::new(&mut writer, calibration)?;
+
+    for properties in core.keys()? {
+        xyz_writer.id(properties)?;
+        for (_, positions) in core.get_by_id(parameters, properties.id())? {
+            for position in positions {
+                xyz_writer.point(&position)?;
+            }
+        }
+    }
 
-    store(v, &fn_out)
+    Ok(writer.flush()?)
 }